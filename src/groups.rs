@@ -0,0 +1,34 @@
+//! Concrete groups.
+
+pub use permutation::*;
+pub use perm_group::*;
+pub use free_group::*;
+pub use fp_group::*;
+pub use cyclic::*;
+pub use dihedral::*;
+pub use quaternion_group::*;
+pub use klein::*;
+pub use alternating::*;
+pub use subgroup::*;
+pub use quotient_group::*;
+pub use cayley::*;
+pub use isomorphism::*;
+pub use matrix_group::*;
+pub use elliptic_curve::*;
+
+mod permutation;
+mod perm_group;
+mod free_group;
+mod fp_group;
+mod cyclic;
+mod dihedral;
+mod quaternion_group;
+mod klein;
+mod alternating;
+mod subgroup;
+mod quotient_group;
+mod cayley;
+mod isomorphism;
+mod matrix_group;
+mod elliptic_curve;
+mod point_counting;
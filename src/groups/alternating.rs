@@ -0,0 +1,173 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+
+/// The alternating group `A_n` of even permutations of `0, ..., n - 1`, a
+/// subgroup of index two in the `SymmetricGroup` for `n >= 2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AlternatingGroup {
+    n: usize,
+}
+
+/// An even permutation, an element of an `AlternatingGroup`.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EvenPermutation {
+    perm: Permutation,
+}
+
+impl AlternatingGroup {
+    /// Initialize the alternating group on `n` points.
+    pub fn init(n: usize) -> Self {
+        AlternatingGroup { n }
+    }
+
+    /// Return the number of points acted on.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.n
+    }
+
+    /// Return the symmetric group containing the group.
+    #[inline]
+    pub fn ambient(&self) -> SymmetricGroup {
+        SymmetricGroup::init(self.n)
+    }
+
+    /// Return the order `n!/2`, or `1` if `n < 2`. Panics if it does not fit
+    /// in a `u128`.
+    pub fn order(&self) -> u128 {
+        if self.n < 2 { 1 } else { self.ambient().order() / 2 }
+    }
+
+    /// Return the generators `(0,1,i)` for `2 <= i < n`.
+    pub fn gens(&self) -> Vec<EvenPermutation> {
+        let sym = self.ambient();
+        (2..self.n)
+            .map(|i| EvenPermutation { perm: sym.from_cycles(&[vec![0, 1, i]]) })
+            .collect()
+    }
+
+    /// Return a uniformly random even permutation.
+    pub fn random_element<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> EvenPermutation {
+        let mut perm = self.ambient().random_element(rng);
+        if !perm.is_even() {
+            perm = self.ambient().from_cycles(&[vec![0, 1]]).operate(&perm);
+        }
+        EvenPermutation { perm }
+    }
+}
+
+impl EvenPermutation {
+    /// Return the underlying permutation.
+    #[inline]
+    pub fn permutation(&self) -> &Permutation {
+        &self.perm
+    }
+
+    /// Return the image of the point `i`.
+    #[inline]
+    pub fn apply(&self, i: usize) -> usize {
+        self.perm.apply(i)
+    }
+
+    /// Return the order of the element.
+    #[inline]
+    pub fn order(&self) -> u64 {
+        self.perm.order()
+    }
+}
+
+impl fmt::Debug for EvenPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EvenPermutation({})", self.perm)
+    }
+}
+
+/// Even permutations are printed in cycle notation, as permutations.
+impl fmt::Display for EvenPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.perm)
+    }
+}
+
+impl Parent for AlternatingGroup {
+    type Element = EvenPermutation;
+}
+
+impl Element for EvenPermutation {
+    type Parent = AlternatingGroup;
+
+    #[inline]
+    fn parent(&self) -> AlternatingGroup {
+        AlternatingGroup { n: self.perm.degree() }
+    }
+}
+
+/// Construct an element from a permutation. Panics if the permutation is odd
+/// or has the wrong degree.
+impl NewElement<Permutation> for AlternatingGroup {
+    fn new(&self, src: Permutation) -> EvenPermutation {
+        assert_eq!(src.degree(), self.n, "permutation of the wrong degree");
+        assert!(src.is_even(), "odd permutation");
+        EvenPermutation { perm: src }
+    }
+}
+
+/// Parse an even permutation in cycle notation such as `(0,1,2)(3,4,5)`.
+/// Panics if the string is malformed or the permutation is odd.
+impl NewElement<&str> for AlternatingGroup {
+    fn new(&self, src: &str) -> EvenPermutation {
+        self.new(self.ambient().new(src))
+    }
+}
+
+impl Finite for AlternatingGroup {
+    fn elements(&self) -> Vec<EvenPermutation> {
+        self.ambient().elements()
+            .filter(|p| p.is_even())
+            .map(|perm| EvenPermutation { perm })
+            .collect()
+    }
+
+    /// Return `n!/2`. Panics if it does not fit in a `usize`.
+    #[inline]
+    fn cardinality(&self) -> usize {
+        usize::try_from(self.order()).expect("order does not fit in a usize")
+    }
+}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for EvenPermutation {
+    /// Return the permutation applying `self` and then `rhs`.
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        EvenPermutation { perm: self.perm.operate(&rhs.perm) }
+    }
+}
+
+impl Identity<Multiplicative> for AlternatingGroup {
+    #[inline]
+    fn identity(&self) -> EvenPermutation {
+        EvenPermutation { perm: self.ambient().identity() }
+    }
+}
+
+impl IsIdentity<Multiplicative> for EvenPermutation {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.perm.is_identity()
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for EvenPermutation {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        EvenPermutation { perm: self.perm.two_sided_inverse() }
+    }
+}
+
+impl Divisible<Multiplicative> for AlternatingGroup {}
+
+impl Associative<Multiplicative> for AlternatingGroup {}
@@ -0,0 +1,350 @@
+use crate::*;
+use crate::ops::*;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Return the Cayley table of a finite magma with respect to the operator `O`.
+///
+/// The entry in row `i` and column `j` is the index of `e_i ∘ e_j` in the list
+/// `e_0, e_1, ...` returned by `Finite::elements`.
+pub fn cayley_table<G, O>(_: O, parent: &G) -> Vec<Vec<usize>>
+where
+    G: AbstractMagma<O> + Finite,
+    O: Operator,
+    Elem<G>: Eq + Hash,
+{
+    let elems = parent.elements();
+    let index: HashMap<&Elem<G>, usize> = elems.iter().enumerate().map(|(i, x)| (x, i)).collect();
+    elems.iter()
+        .map(|a| elems.iter().map(|b| index[&Operation::<O>::operate(a, b)]).collect())
+        .collect()
+}
+
+/// The kind of structure a `TableMagma` is known to have, which determines at
+/// the type level which structure traits it implements.
+pub trait TableKind: Clone + Copy + fmt::Debug + Default + PartialEq + Eq {}
+
+/// A table which is not known to have any property beyond closure.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AnyTable;
+
+/// A Latin square with an identity element. The table is an `AbstractLoop`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LoopTable;
+
+/// An associative Latin square with an identity element. The table is an
+/// `AbstractGroup`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GroupTable;
+
+impl TableKind for AnyTable {}
+
+impl TableKind for LoopTable {}
+
+impl TableKind for GroupTable {}
+
+/// A finite magma on the elements `e_0, ..., e_{n-1}` given by its
+/// multiplication table, the entry in row `i` and column `j` being the index
+/// of `e_i * e_j`.
+///
+/// Any square table with entries below `n` defines a magma. Tables which are
+/// Latin squares with an identity are loops and can be converted to a
+/// `TableLoop` with `as_loop`, and associative ones to a `TableGroup` with
+/// `as_group`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TableMagma<K: TableKind = AnyTable> {
+    table: Arc<Vec<Vec<usize>>>,
+    identity: Option<usize>,
+    kind: PhantomData<K>,
+}
+
+/// A loop given by its multiplication table.
+pub type TableLoop = TableMagma<LoopTable>;
+
+/// A group given by its multiplication table.
+pub type TableGroup = TableMagma<GroupTable>;
+
+/// An element `e_i` of a `TableMagma`.
+#[derive(Clone)]
+pub struct TableElement<K: TableKind = AnyTable> {
+    parent: TableMagma<K>,
+    index: usize,
+}
+
+impl TableMagma {
+    /// Initialize the magma with the given multiplication table. Panics if the
+    /// table is empty or not square, or has an entry out of range.
+    pub fn init(table: &[Vec<usize>]) -> Self {
+        let n = table.len();
+        assert!(n > 0, "empty multiplication table");
+        for row in table.iter() {
+            assert_eq!(row.len(), n, "multiplication table is not square");
+            assert!(row.iter().all(|&k| k < n), "multiplication table entry out of range");
+        }
+        let identity = (0..n).find(|&e| {
+            (0..n).all(|i| table[e][i] == i && table[i][e] == i)
+        });
+        TableMagma { table: Arc::new(table.to_vec()), identity, kind: PhantomData }
+    }
+
+    /// Return the magma of a finite parent, with the elements in the order of
+    /// `Finite::elements`.
+    pub fn from_parent<G, O>(op: O, parent: &G) -> Self
+    where
+        G: AbstractMagma<O> + Finite,
+        O: Operator,
+        Elem<G>: Eq + Hash,
+    {
+        TableMagma::init(&cayley_table(op, parent))
+    }
+
+    /// Return the table as a loop, or `None` if it is not a Latin square with
+    /// an identity.
+    pub fn as_loop(&self) -> Option<TableLoop> {
+        if self.identity.is_some() && self.is_latin_square() {
+            Some(self.with_kind())
+        } else {
+            None
+        }
+    }
+
+    /// Return the table as a group, or `None` if it is not an associative
+    /// Latin square with an identity.
+    pub fn as_group(&self) -> Option<TableGroup> {
+        if self.identity.is_some() && self.is_latin_square() && self.is_associative() {
+            Some(self.with_kind())
+        } else {
+            None
+        }
+    }
+}
+
+impl<K: TableKind> TableMagma<K> {
+    /// Return the number of elements.
+    #[inline]
+    pub fn order(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Return the multiplication table.
+    #[inline]
+    pub fn table(&self) -> &[Vec<usize>] {
+        &self.table
+    }
+
+    /// Return the element `e_i`. Panics if `i` is out of range.
+    pub fn element(&self, i: usize) -> TableElement<K> {
+        assert!(i < self.order(), "element index out of range");
+        TableElement { parent: self.clone(), index: i }
+    }
+
+    /// Return the index of the identity element, if there is one.
+    #[inline]
+    pub fn identity_index(&self) -> Option<usize> {
+        self.identity
+    }
+
+    /// Return `true` if every row and every column is a permutation, so that
+    /// the equations `a x = b` and `y a = b` have unique solutions.
+    pub fn is_latin_square(&self) -> bool {
+        let n = self.order();
+        let mut seen = vec![false; n];
+        for i in 0..n {
+            for line in [0, 1] {
+                seen.iter_mut().for_each(|s| *s = false);
+                for j in 0..n {
+                    let k = if line == 0 { self.table[i][j] } else { self.table[j][i] };
+                    if seen[k] {
+                        return false;
+                    }
+                    seen[k] = true;
+                }
+            }
+        }
+        true
+    }
+
+    /// Return `true` if the operation is associative.
+    pub fn is_associative(&self) -> bool {
+        let t = &self.table;
+        let n = self.order();
+        (0..n).all(|a| (0..n).all(|b| (0..n).all(|c| t[t[a][b]][c] == t[a][t[b][c]])))
+    }
+
+    /// Return `true` if the operation is commutative.
+    pub fn is_commutative(&self) -> bool {
+        let n = self.order();
+        (0..n).all(|a| (0..a).all(|b| self.table[a][b] == self.table[b][a]))
+    }
+
+    /// Return the solution `x` of `a x = b`, or `None` if there is no unique
+    /// solution.
+    pub fn left_division(&self, a: &TableElement<K>, b: &TableElement<K>) -> Option<TableElement<K>> {
+        let mut xs = (0..self.order()).filter(|&x| self.table[a.index][x] == b.index);
+        match (xs.next(), xs.next()) {
+            (Some(x), None) => Some(self.element(x)),
+            _ => None,
+        }
+    }
+
+    /// Return the solution `y` of `y a = b`, or `None` if there is no unique
+    /// solution.
+    pub fn right_division(&self, b: &TableElement<K>, a: &TableElement<K>) -> Option<TableElement<K>> {
+        let mut ys = (0..self.order()).filter(|&y| self.table[y][a.index] == b.index);
+        match (ys.next(), ys.next()) {
+            (Some(y), None) => Some(self.element(y)),
+            _ => None,
+        }
+    }
+
+    /// Return the table with no known properties.
+    pub fn as_magma(&self) -> TableMagma {
+        self.with_kind()
+    }
+
+    fn with_kind<L: TableKind>(&self) -> TableMagma<L> {
+        TableMagma { table: self.table.clone(), identity: self.identity, kind: PhantomData }
+    }
+}
+
+impl<K: TableKind> TableElement<K> {
+    /// Return the index `i` of the element `e_i`.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<K: TableKind> fmt::Debug for TableMagma<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TableMagma")
+            .field("table", &self.table)
+            .field("kind", &K::default())
+            .finish()
+    }
+}
+
+impl<K: TableKind> PartialEq for TableElement<K> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<K: TableKind> Eq for TableElement<K> {}
+
+impl<K: TableKind> Hash for TableElement<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl<K: TableKind> fmt::Debug for TableElement<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TableElement({})", self)
+    }
+}
+
+/// Elements are printed as `e0`, `e1`, and so on.
+impl<K: TableKind> fmt::Display for TableElement<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "e{}", self.index)
+    }
+}
+
+impl<K: TableKind> Parent for TableMagma<K> {
+    type Element = TableElement<K>;
+}
+
+impl<K: TableKind> Element for TableElement<K> {
+    type Parent = TableMagma<K>;
+
+    #[inline]
+    fn parent(&self) -> TableMagma<K> {
+        self.parent.clone()
+    }
+}
+
+/// Construct the element `e_i`. Panics if `i` is out of range.
+impl<K: TableKind> NewElement<usize> for TableMagma<K> {
+    #[inline]
+    fn new(&self, i: usize) -> TableElement<K> {
+        self.element(i)
+    }
+}
+
+impl<K: TableKind> Finite for TableMagma<K> {
+    fn elements(&self) -> Vec<TableElement<K>> {
+        (0..self.order()).map(|index| TableElement { parent: self.clone(), index }).collect()
+    }
+
+    #[inline]
+    fn cardinality(&self) -> usize {
+        self.order()
+    }
+}
+
+// Multiplicative properties
+
+impl<K: TableKind> Operation<Multiplicative> for TableElement<K> {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        TableElement { parent: self.parent.clone(), index: self.parent.table[self.index][rhs.index] }
+    }
+}
+
+impl Identity<Multiplicative> for TableLoop {
+    #[inline]
+    fn identity(&self) -> TableElement<LoopTable> {
+        self.element(self.identity.unwrap())
+    }
+}
+
+impl Identity<Multiplicative> for TableGroup {
+    #[inline]
+    fn identity(&self) -> TableElement<GroupTable> {
+        self.element(self.identity.unwrap())
+    }
+}
+
+impl IsIdentity<Multiplicative> for TableElement<LoopTable> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.parent.identity == Some(self.index)
+    }
+}
+
+impl IsIdentity<Multiplicative> for TableElement<GroupTable> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.parent.identity == Some(self.index)
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for TableElement<LoopTable> {
+    /// Return the right inverse, the solution `x` of `a x = 1`. In a loop this
+    /// may differ from the left inverse.
+    fn two_sided_inverse(&self) -> Self {
+        let id = self.parent.identity.unwrap();
+        let x = (0..self.parent.order()).find(|&x| self.parent.table[self.index][x] == id).unwrap();
+        self.parent.element(x)
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for TableElement<GroupTable> {
+    fn two_sided_inverse(&self) -> Self {
+        let id = self.parent.identity.unwrap();
+        let x = (0..self.parent.order()).find(|&x| self.parent.table[self.index][x] == id).unwrap();
+        self.parent.element(x)
+    }
+}
+
+impl Divisible<Multiplicative> for TableLoop {}
+
+impl Divisible<Multiplicative> for TableGroup {}
+
+impl Associative<Multiplicative> for TableGroup {}
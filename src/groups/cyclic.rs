@@ -0,0 +1,134 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+
+/// The cyclic group `C_n = ⟨g | g^n⟩` of order `n`, written multiplicatively.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CyclicGroup {
+    n: u64,
+}
+
+/// The element `g^k` of a `CyclicGroup`, with `0 <= k < n`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CyclicElement {
+    n: u64,
+    k: u64,
+}
+
+impl CyclicGroup {
+    /// Initialize the cyclic group of order `n`. Panics if `n` is zero.
+    pub fn init(n: u64) -> Self {
+        assert!(n > 0, "cyclic group of order zero");
+        CyclicGroup { n }
+    }
+
+    /// Return the order `n`.
+    #[inline]
+    pub fn order(&self) -> u64 {
+        self.n
+    }
+
+    /// Return the generator `g`.
+    #[inline]
+    pub fn gen(&self) -> CyclicElement {
+        self.new(1)
+    }
+}
+
+impl CyclicElement {
+    /// Return the exponent `k` of `g^k`, with `0 <= k < n`.
+    #[inline]
+    pub fn exponent(&self) -> u64 {
+        self.k
+    }
+
+    /// Return the order of the element, `n / gcd(n, k)`.
+    pub fn order(&self) -> u64 {
+        let (mut a, mut b) = (self.n, self.k);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        self.n / a
+    }
+}
+
+/// Elements are printed as powers of the generator `g`, with the identity
+/// printed as `1`.
+impl fmt::Display for CyclicElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.k {
+            0 => write!(f, "1"),
+            1 => write!(f, "g"),
+            k => write!(f, "g^{}", k),
+        }
+    }
+}
+
+impl Parent for CyclicGroup {
+    type Element = CyclicElement;
+}
+
+impl Element for CyclicElement {
+    type Parent = CyclicGroup;
+
+    #[inline]
+    fn parent(&self) -> CyclicGroup {
+        CyclicGroup { n: self.n }
+    }
+}
+
+/// Construct `g^k`, for any integer `k`.
+impl NewElement<i64> for CyclicGroup {
+    #[inline]
+    fn new(&self, k: i64) -> CyclicElement {
+        CyclicElement { n: self.n, k: (k as i128).rem_euclid(self.n as i128) as u64 }
+    }
+}
+
+impl Finite for CyclicGroup {
+    fn elements(&self) -> Vec<CyclicElement> {
+        (0..self.n).map(|k| CyclicElement { n: self.n, k }).collect()
+    }
+
+    #[inline]
+    fn cardinality(&self) -> usize {
+        self.n as usize
+    }
+}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for CyclicElement {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        CyclicElement { n: self.n, k: ((self.k as u128 + rhs.k as u128) % self.n as u128) as u64 }
+    }
+}
+
+impl Identity<Multiplicative> for CyclicGroup {
+    #[inline]
+    fn identity(&self) -> CyclicElement {
+        CyclicElement { n: self.n, k: 0 }
+    }
+}
+
+impl IsIdentity<Multiplicative> for CyclicElement {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.k == 0
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for CyclicElement {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        CyclicElement { n: self.n, k: (self.n - self.k) % self.n }
+    }
+}
+
+impl Divisible<Multiplicative> for CyclicGroup {}
+
+impl Associative<Multiplicative> for CyclicGroup {}
+
+impl Commutative<Multiplicative> for CyclicGroup {}
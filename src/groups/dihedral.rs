@@ -0,0 +1,190 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+
+/// The dihedral group `D_n = ⟨r, s | r^n, s^2, (rs)^2⟩` of order `2n`, the
+/// symmetries of a regular `n`-gon, with `r` a rotation and `s` a reflection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DihedralGroup {
+    n: u64,
+}
+
+/// The element `r^k s^e` of a `DihedralGroup`, with `0 <= k < n` and `e` zero
+/// or one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DihedralElement {
+    n: u64,
+    k: u64,
+    reflection: bool,
+}
+
+impl DihedralGroup {
+    /// Initialize the dihedral group of order `2n`. Panics if `n` is zero.
+    pub fn init(n: u64) -> Self {
+        assert!(n > 0, "dihedral group of order zero");
+        DihedralGroup { n }
+    }
+
+    /// Return the number `n` of vertices of the polygon.
+    #[inline]
+    pub fn degree(&self) -> u64 {
+        self.n
+    }
+
+    /// Return the order `2n`.
+    #[inline]
+    pub fn order(&self) -> u64 {
+        2 * self.n
+    }
+
+    /// Return the rotation `r`.
+    #[inline]
+    pub fn rotation(&self) -> DihedralElement {
+        self.new((1, false))
+    }
+
+    /// Return the reflection `s`.
+    #[inline]
+    pub fn reflection(&self) -> DihedralElement {
+        self.new((0, true))
+    }
+
+    /// Return the generators `r` and `s`.
+    pub fn gens(&self) -> Vec<DihedralElement> {
+        vec![self.rotation(), self.reflection()]
+    }
+}
+
+impl DihedralElement {
+    /// Return `(k, e)` such that the element is `r^k s^e`.
+    #[inline]
+    pub fn parts(&self) -> (u64, bool) {
+        (self.k, self.reflection)
+    }
+
+    /// Return `true` if the element is a reflection.
+    #[inline]
+    pub fn is_reflection(&self) -> bool {
+        self.reflection
+    }
+
+    /// Return the order of the element.
+    pub fn order(&self) -> u64 {
+        if self.reflection {
+            return 2;
+        }
+        let (mut a, mut b) = (self.n, self.k);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        self.n / a
+    }
+
+    /// Return the image of the vertex `i` of the polygon, numbered
+    /// counterclockwise from `0` to `n - 1`, with `r` the rotation `i ↦ i + 1`
+    /// and `s` the reflection `i ↦ -i`. This is a left action, so
+    /// `(x * y).apply(i) == x.apply(y.apply(i))`.
+    pub fn apply(&self, i: u64) -> u64 {
+        let i = i % self.n;
+        let i = if self.reflection { (self.n - i) % self.n } else { i };
+        (i + self.k) % self.n
+    }
+}
+
+/// Elements are printed as `r^k*s`, omitting trivial factors.
+impl fmt::Display for DihedralElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let r = match self.k {
+            0 => None,
+            1 => Some("r".to_string()),
+            k => Some(format!("r^{}", k)),
+        };
+        match (r, self.reflection) {
+            (None, false) => write!(f, "1"),
+            (None, true) => write!(f, "s"),
+            (Some(r), false) => write!(f, "{}", r),
+            (Some(r), true) => write!(f, "{}*s", r),
+        }
+    }
+}
+
+impl Parent for DihedralGroup {
+    type Element = DihedralElement;
+}
+
+impl Element for DihedralElement {
+    type Parent = DihedralGroup;
+
+    #[inline]
+    fn parent(&self) -> DihedralGroup {
+        DihedralGroup { n: self.n }
+    }
+}
+
+/// Construct `r^k s^e` from `(k, e)`, for any integer `k`.
+impl NewElement<(i64, bool)> for DihedralGroup {
+    #[inline]
+    fn new(&self, src: (i64, bool)) -> DihedralElement {
+        let k = (src.0 as i128).rem_euclid(self.n as i128) as u64;
+        DihedralElement { n: self.n, k, reflection: src.1 }
+    }
+}
+
+impl Finite for DihedralGroup {
+    fn elements(&self) -> Vec<DihedralElement> {
+        [false, true].iter()
+            .flat_map(|&reflection| (0..self.n).map(move |k| DihedralElement { n: self.n, k, reflection }))
+            .collect()
+    }
+
+    #[inline]
+    fn cardinality(&self) -> usize {
+        2 * self.n as usize
+    }
+}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for DihedralElement {
+    /// Multiply using `s r = r^-1 s`, so that
+    /// `(r^a s^e)(r^b s^f) = r^(a + (-1)^e b) s^(e + f)`.
+    fn operate(&self, rhs: &Self) -> Self {
+        let n = self.n as u128;
+        let b = if self.reflection { (n - rhs.k as u128) % n } else { rhs.k as u128 };
+        DihedralElement {
+            n: self.n,
+            k: ((self.k as u128 + b) % n) as u64,
+            reflection: self.reflection != rhs.reflection,
+        }
+    }
+}
+
+impl Identity<Multiplicative> for DihedralGroup {
+    #[inline]
+    fn identity(&self) -> DihedralElement {
+        DihedralElement { n: self.n, k: 0, reflection: false }
+    }
+}
+
+impl IsIdentity<Multiplicative> for DihedralElement {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.k == 0 && !self.reflection
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for DihedralElement {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        if self.reflection {
+            *self
+        } else {
+            DihedralElement { n: self.n, k: (self.n - self.k) % self.n, reflection: false }
+        }
+    }
+}
+
+impl Divisible<Multiplicative> for DihedralGroup {}
+
+impl Associative<Multiplicative> for DihedralGroup {}
@@ -0,0 +1,414 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// An elliptic curve `y^2 = x^3 + a x + b` in short Weierstrass form over a
+/// field of characteristic other than two or three.
+///
+/// The points form an abelian group under addition with the point at infinity
+/// as identity, so the curve is an `AdditiveGroupAbelian`. Points are stored
+/// in Jacobian coordinates `(X : Y : Z)`, standing for the affine point
+/// `(X/Z^2, Y/Z^3)`, in which addition and doubling need no field inversions.
+/// The point at infinity is the point with `Z = 0`.
+#[derive(Clone, PartialEq)]
+pub struct EllipticCurve<F: Field> {
+    field: F,
+    a: Elem<F>,
+    b: Elem<F>,
+}
+
+/// A point of an `EllipticCurve`, in Jacobian coordinates.
+#[derive(Clone)]
+pub struct EllipticCurvePoint<F: Field> {
+    parent: EllipticCurve<F>,
+    x: Elem<F>,
+    y: Elem<F>,
+    z: Elem<F>,
+}
+
+#[inline]
+fn add<T: RingOps + Clone>(x: &T, y: &T) -> T {
+    let mut res = x.clone();
+    res += y;
+    res
+}
+
+#[inline]
+fn sub<T: RingOps + Clone>(x: &T, y: &T) -> T {
+    let mut res = x.clone();
+    res -= y;
+    res
+}
+
+#[inline]
+fn mul<T: RingOps + Clone>(x: &T, y: &T) -> T {
+    let mut res = x.clone();
+    res *= y;
+    res
+}
+
+/// Return `n x`, by doubling and adding.
+fn times<T: RingOps + Clone>(x: &T, n: u64) -> T {
+    let mut res = sub(x, x);
+    for i in (0..64 - n.leading_zeros()).rev() {
+        res = add(&res, &res);
+        if (n >> i) & 1 == 1 {
+            res += x;
+        }
+    }
+    res
+}
+
+impl<F: Field> EllipticCurve<F> {
+    /// Initialize the curve `y^2 = x^3 + a x + b` over `field`. Panics if the
+    /// field has characteristic two or three, or if the curve is singular, that
+    /// is `4 a^3 + 27 b^2 = 0`.
+    pub fn init(field: &F, a: &Elem<F>, b: &Elem<F>) -> Self {
+        assert!(!times(&field.one(), 6).is_zero(), "elliptic curve in characteristic two or three");
+        let res = EllipticCurve { field: field.clone(), a: a.clone(), b: b.clone() };
+        assert!(!res.discriminant().is_zero(), "singular elliptic curve");
+        res
+    }
+
+    /// Return a reference to the base field.
+    #[inline]
+    pub fn base_field(&self) -> &F {
+        &self.field
+    }
+
+    /// Return the coefficients `(a, b)`.
+    #[inline]
+    pub fn coefficients(&self) -> (&Elem<F>, &Elem<F>) {
+        (&self.a, &self.b)
+    }
+
+    /// Return the discriminant `-16 (4 a^3 + 27 b^2)`.
+    pub fn discriminant(&self) -> Elem<F> {
+        let mut d = times(&self.discriminant_factor(), 16);
+        d.neg_assign();
+        d
+    }
+
+    /// Return the `j`-invariant `1728 * 4 a^3 / (4 a^3 + 27 b^2)`, which
+    /// classifies the curve up to isomorphism over an algebraic closure.
+    pub fn j_invariant(&self) -> Elem<F> {
+        let a3 = mul(&mul(&self.a, &self.a), &self.a);
+        let mut inv = self.discriminant_factor();
+        inv.inv_assign();
+        mul(&times(&a3, 1728 * 4), &inv)
+    }
+
+    /// Return `true` if the affine point `(x, y)` lies on the curve.
+    pub fn contains(&self, x: &Elem<F>, y: &Elem<F>) -> bool {
+        mul(y, y) == self.rhs(x)
+    }
+
+    /// Return the point at infinity, the identity.
+    #[inline]
+    pub fn infinity(&self) -> EllipticCurvePoint<F> {
+        self.identity()
+    }
+
+    /// Return the affine point `(x, y)`, or `None` if it is not on the curve.
+    pub fn point(&self, x: &Elem<F>, y: &Elem<F>) -> Option<EllipticCurvePoint<F>> {
+        if self.contains(x, y) {
+            Some(self.elem(x.clone(), y.clone(), self.field.one()))
+        } else {
+            None
+        }
+    }
+
+    /// Return the point with Jacobian coordinates `(X : Y : Z)`, standing for
+    /// `(X/Z^2, Y/Z^3)`, or `None` if it is not on the curve. Coordinates with
+    /// `Z = 0` give the point at infinity if `X^3 = Y^2`, as for `(1 : 1 : 0)`.
+    pub fn from_jacobian(&self, x: &Elem<F>, y: &Elem<F>, z: &Elem<F>) -> Option<EllipticCurvePoint<F>> {
+        let p = self.elem(x.clone(), y.clone(), z.clone());
+        if p.is_on_curve() { Some(p) } else { None }
+    }
+
+    /// Return the point with homogeneous projective coordinates `(X : Y : Z)`,
+    /// standing for `(X/Z, Y/Z)`, or `None` if it is not on the curve. The point
+    /// at infinity is `(0 : 1 : 0)`.
+    pub fn from_projective(&self, x: &Elem<F>, y: &Elem<F>, z: &Elem<F>) -> Option<EllipticCurvePoint<F>> {
+        if z.is_zero() {
+            return if x.is_zero() && !y.is_zero() { Some(self.identity()) } else { None };
+        }
+        // (X/Z, Y/Z) has Jacobian coordinates (X Z : Y Z^2 : Z).
+        let p = self.elem(mul(x, z), mul(&mul(y, z), z), z.clone());
+        if p.is_on_curve() { Some(p) } else { None }
+    }
+
+    /// Return `4 a^3 + 27 b^2`, the discriminant up to the factor `-16`.
+    fn discriminant_factor(&self) -> Elem<F> {
+        let a3 = mul(&mul(&self.a, &self.a), &self.a);
+        add(&times(&a3, 4), &times(&mul(&self.b, &self.b), 27))
+    }
+
+    /// Return `x^3 + a x + b`.
+    fn rhs(&self, x: &Elem<F>) -> Elem<F> {
+        add(&mul(&add(&mul(x, x), &self.a), x), &self.b)
+    }
+
+    #[inline]
+    fn elem(&self, x: Elem<F>, y: Elem<F>, z: Elem<F>) -> EllipticCurvePoint<F> {
+        EllipticCurvePoint { parent: self.clone(), x, y, z }
+    }
+}
+
+impl<F: Field> EllipticCurvePoint<F> {
+    /// Return `true` if the point is the point at infinity.
+    #[inline]
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Return the Jacobian coordinates `(X, Y, Z)`. These are not unique:
+    /// `(λ^2 X : λ^3 Y : λ Z)` is the same point for any nonzero `λ`.
+    #[inline]
+    pub fn jacobian(&self) -> (&Elem<F>, &Elem<F>, &Elem<F>) {
+        (&self.x, &self.y, &self.z)
+    }
+
+    /// Return homogeneous projective coordinates `(X Z, Y, Z^3)`, standing for
+    /// `(X/Z^2, Y/Z^3)` like the Jacobian coordinates `(X : Y : Z)`.
+    pub fn projective(&self) -> (Elem<F>, Elem<F>, Elem<F>) {
+        if self.is_infinity() {
+            let f = &self.parent.field;
+            return (f.zero(), f.one(), f.zero());
+        }
+        (mul(&self.x, &self.z), self.y.clone(), mul(&mul(&self.z, &self.z), &self.z))
+    }
+
+    /// Return the affine coordinates `(x, y)`, or `None` for the point at
+    /// infinity.
+    pub fn affine(&self) -> Option<(Elem<F>, Elem<F>)> {
+        if self.is_infinity() {
+            return None;
+        }
+        let mut zinv = self.z.clone();
+        zinv.inv_assign();
+        let zinv2 = mul(&zinv, &zinv);
+        Some((mul(&self.x, &zinv2), mul(&mul(&self.y, &zinv2), &zinv)))
+    }
+
+    /// Return the same point with `Z = 1`, or the point at infinity as
+    /// `(1 : 1 : 0)`.
+    pub fn normalize(&self) -> Self {
+        let f = &self.parent.field;
+        match self.affine() {
+            Some((x, y)) => self.parent.elem(x, y, f.one()),
+            None => self.parent.elem(f.one(), f.one(), f.zero()),
+        }
+    }
+
+    /// Return `k P`, using `AbstractMonoid::power` for the additive group.
+    pub fn scalar_mul(&self, k: i64) -> Self {
+        let p = self.parent.power(Additive, self, k.unsigned_abs());
+        if k < 0 { p.two_sided_inverse() } else { p }
+    }
+
+    /// Return `2 P`.
+    pub fn double(&self) -> Self {
+        let c = &self.parent;
+        if self.is_infinity() || self.y.is_zero() {
+            return c.identity();
+        }
+        // The Jacobian doubling of Cohen, Miyaji and Ono (1998), with
+        // S = 4 X Y^2, M = 3 X^2 + a Z^4 and Z_3 = 2 Y Z.
+        let xx = mul(&self.x, &self.x);
+        let yy = mul(&self.y, &self.y);
+        let zz = mul(&self.z, &self.z);
+        let s = times(&mul(&self.x, &yy), 4);
+        let m = add(&times(&xx, 3), &mul(&c.a, &mul(&zz, &zz)));
+        let x3 = sub(&mul(&m, &m), &times(&s, 2));
+        let y3 = sub(&mul(&m, &sub(&s, &x3)), &times(&mul(&yy, &yy), 8));
+        let z3 = times(&mul(&self.y, &self.z), 2);
+        c.elem(x3, y3, z3)
+    }
+
+    /// Return `true` if the coordinates satisfy `Y^2 = X^3 + a X Z^4 + b Z^6`,
+    /// which for `Z = 0` requires `X^3 = Y^2` with `X` nonzero.
+    fn is_on_curve(&self) -> bool {
+        let c = &self.parent;
+        if self.z.is_zero() {
+            return !self.x.is_zero() && mul(&mul(&self.x, &self.x), &self.x) == mul(&self.y, &self.y);
+        }
+        let zz = mul(&self.z, &self.z);
+        let z4 = mul(&zz, &zz);
+        let z6 = mul(&z4, &zz);
+        let rhs = add(
+            &add(&mul(&mul(&self.x, &self.x), &self.x), &mul(&mul(&c.a, &self.x), &z4)),
+            &mul(&c.b, &z6),
+        );
+        mul(&self.y, &self.y) == rhs
+    }
+}
+
+/// Points are equal if their Jacobian coordinates are proportional, that is
+/// `X_1 Z_2^2 = X_2 Z_1^2` and `Y_1 Z_2^3 = Y_2 Z_1^3`.
+impl<F: Field> PartialEq for EllipticCurvePoint<F> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_infinity(), other.is_infinity()) {
+            (true, true) => true,
+            (false, false) => {
+                let z1z1 = mul(&self.z, &self.z);
+                let z2z2 = mul(&other.z, &other.z);
+                mul(&self.x, &z2z2) == mul(&other.x, &z1z1)
+                    && mul(&mul(&self.y, &z2z2), &other.z) == mul(&mul(&other.y, &z1z1), &self.z)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<F: Field> Eq for EllipticCurvePoint<F>
+where
+    <F as Field>::Element: Eq,
+{}
+
+/// Points are hashed by their affine coordinates, which are unique.
+impl<F: Field> Hash for EllipticCurvePoint<F>
+where
+    <F as Field>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.affine().hash(state);
+    }
+}
+
+impl<F: Field> fmt::Debug for EllipticCurve<F>
+where
+    F: fmt::Debug,
+    <F as Field>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EllipticCurve")
+            .field("field", &self.field)
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+/// Curves are printed as `y^2 = x^3 + a*x + b`.
+impl<F: Field> fmt::Display for EllipticCurve<F>
+where
+    <F as Field>::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "y^2 = x^3 + {}*x + {}", self.a, self.b)
+    }
+}
+
+impl<F: Field> fmt::Debug for EllipticCurvePoint<F>
+where
+    <F as Field>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("EllipticCurvePoint")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+
+/// Points are printed in normalized projective coordinates, `(x : y : 1)` for
+/// affine points and `(0 : 1 : 0)` for the point at infinity.
+impl<F: Field> fmt::Display for EllipticCurvePoint<F>
+where
+    <F as Field>::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.affine() {
+            Some((x, y)) => write!(f, "({} : {} : 1)", x, y),
+            None => write!(f, "(0 : 1 : 0)"),
+        }
+    }
+}
+
+impl<F: Field> Parent for EllipticCurve<F> {
+    type Element = EllipticCurvePoint<F>;
+}
+
+impl<F: Field> Element for EllipticCurvePoint<F> {
+    type Parent = EllipticCurve<F>;
+
+    #[inline]
+    fn parent(&self) -> EllipticCurve<F> {
+        self.parent.clone()
+    }
+}
+
+/// Construct the affine point `(x, y)`. Panics if it is not on the curve.
+impl<F: Field> NewElement<(Elem<F>, Elem<F>)> for EllipticCurve<F> {
+    fn new(&self, src: (Elem<F>, Elem<F>)) -> EllipticCurvePoint<F> {
+        self.point(&src.0, &src.1).expect("point is not on the curve")
+    }
+}
+
+// Additive properties
+
+impl<F: Field> Operation<Additive> for EllipticCurvePoint<F> {
+    /// Add using the chord and tangent law, with the Jacobian addition formulas
+    /// of Cohen, Miyaji and Ono (1998), where `Z_3 = Z_1 Z_2 H`.
+    fn operate(&self, rhs: &Self) -> Self {
+        if self.is_infinity() {
+            return rhs.clone();
+        }
+        if rhs.is_infinity() {
+            return self.clone();
+        }
+        let z1z1 = mul(&self.z, &self.z);
+        let z2z2 = mul(&rhs.z, &rhs.z);
+        let u1 = mul(&self.x, &z2z2);
+        let u2 = mul(&rhs.x, &z1z1);
+        let s1 = mul(&mul(&self.y, &rhs.z), &z2z2);
+        let s2 = mul(&mul(&rhs.y, &self.z), &z1z1);
+        let h = sub(&u2, &u1);
+        let r = sub(&s2, &s1);
+        if h.is_zero() {
+            return if r.is_zero() { self.double() } else { self.parent.identity() };
+        }
+        let hh = mul(&h, &h);
+        let hhh = mul(&hh, &h);
+        let v = mul(&u1, &hh);
+        let x3 = sub(&sub(&mul(&r, &r), &hhh), &times(&v, 2));
+        let y3 = sub(&mul(&r, &sub(&v, &x3)), &mul(&s1, &hhh));
+        let z3 = mul(&mul(&self.z, &rhs.z), &h);
+        self.parent.elem(x3, y3, z3)
+    }
+}
+
+impl<F: Field> Identity<Additive> for EllipticCurve<F> {
+    #[inline]
+    fn identity(&self) -> EllipticCurvePoint<F> {
+        self.elem(self.field.one(), self.field.one(), self.field.zero())
+    }
+}
+
+impl<F: Field> IsIdentity<Additive> for EllipticCurvePoint<F> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.is_infinity()
+    }
+}
+
+impl<F: Field> TwoSidedInverse<Additive> for EllipticCurvePoint<F> {
+    /// Return `-(X : Y : Z) = (X : -Y : Z)`.
+    fn two_sided_inverse(&self) -> Self {
+        let mut y = self.y.clone();
+        y.neg_assign();
+        self.parent.elem(self.x.clone(), y, self.z.clone())
+    }
+}
+
+impl<F: Field> Divisible<Additive> for EllipticCurve<F> {}
+
+impl<F: Field> Associative<Additive> for EllipticCurve<F> {}
+
+impl<F: Field> Commutative<Additive> for EllipticCurve<F> {}
+
+impl_additive_ops!([F: Field] EllipticCurvePoint<F>);
@@ -0,0 +1,469 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+/// The default bound on the number of cosets defined during an enumeration.
+pub const COSET_LIMIT: usize = 1 << 20;
+
+/// A complete coset table for a subgroup `H` of a finitely presented group,
+/// describing the action of the generators on the right cosets `H g` by right
+/// multiplication. Coset `0` is `H` itself and the cosets are numbered in the
+/// order in which they are first reached from it, so the table is standardized.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CosetTable {
+    rank: usize,
+    table: Vec<Vec<usize>>,
+}
+
+impl CosetTable {
+    /// Return the number of cosets, the index of the subgroup.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Return `true` if the table has no cosets. A completed table always
+    /// contains the subgroup itself, so this is `false`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Return the coset `c x` where `x` is the letter `l`, `2i` or `2i + 1` for
+    /// the generator `x_i` or its inverse.
+    #[inline]
+    pub fn act(&self, c: usize, l: usize) -> usize {
+        self.table[c][l]
+    }
+
+    /// Return the coset `c w`.
+    pub fn act_word(&self, c: usize, w: &FreeWord) -> usize {
+        w.letters().iter().fold(c, |c, &l| self.table[c][l])
+    }
+
+    /// Return the permutations of the cosets induced by the generators.
+    pub fn permutations(&self) -> Vec<Permutation> {
+        let sym = SymmetricGroup::init(self.len());
+        (0..self.rank)
+            .map(|i| sym.new(self.table.iter().map(|row| row[2 * i]).collect::<Vec<_>>()))
+            .collect()
+    }
+}
+
+/// The state of a Todd-Coxeter coset enumeration using the HLT strategy, with
+/// coincidences processed as they are found.
+struct Enumeration {
+    table: Vec<Vec<Option<usize>>>,
+    parent: Vec<usize>,
+    queue: Vec<usize>,
+    live: usize,
+    limit: usize,
+}
+
+impl Enumeration {
+    fn new(rank: usize, limit: usize) -> Self {
+        Enumeration {
+            table: vec![vec![None; 2 * rank]],
+            parent: vec![0],
+            queue: vec![],
+            live: 1,
+            limit,
+        }
+    }
+
+    fn rep(&mut self, c: usize) -> usize {
+        let mut r = c;
+        while self.parent[r] != r {
+            r = self.parent[r];
+        }
+        let mut c = c;
+        while self.parent[c] != r {
+            let next = self.parent[c];
+            self.parent[c] = r;
+            c = next;
+        }
+        r
+    }
+
+    #[inline]
+    fn is_live(&self, c: usize) -> bool {
+        self.parent[c] == c
+    }
+
+    /// Define a new coset `c l`. Returns `false` if the limit is exceeded.
+    fn define(&mut self, c: usize, l: usize) -> bool {
+        if self.table.len() >= self.limit {
+            return false;
+        }
+        let d = self.table.len();
+        self.table.push(vec![None; self.table[0].len()]);
+        self.parent.push(d);
+        self.live += 1;
+        self.table[c][l] = Some(d);
+        self.table[d][l ^ 1] = Some(c);
+        true
+    }
+
+    fn merge(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.rep(a), self.rep(b));
+        if a != b {
+            let (lo, hi) = (a.min(b), a.max(b));
+            self.parent[hi] = lo;
+            self.live -= 1;
+            self.queue.push(hi);
+        }
+    }
+
+    fn coincidence(&mut self, a: usize, b: usize) {
+        self.merge(a, b);
+        let mut i = 0;
+        while i < self.queue.len() {
+            let e = self.queue[i];
+            i += 1;
+            for l in 0..self.table[e].len() {
+                let Some(f) = self.table[e][l] else { continue };
+                self.table[f][l ^ 1] = None;
+                let (e1, f1) = (self.rep(e), self.rep(f));
+                if let Some(g) = self.table[e1][l] {
+                    self.merge(f1, g);
+                } else if let Some(g) = self.table[f1][l ^ 1] {
+                    self.merge(e1, g);
+                } else {
+                    self.table[e1][l] = Some(f1);
+                    self.table[f1][l ^ 1] = Some(e1);
+                }
+            }
+        }
+        self.queue.clear();
+    }
+
+    /// Trace the relator `w` from the coset `c` in both directions, defining
+    /// cosets to complete it. Returns `false` if the limit is exceeded.
+    fn scan_and_fill(&mut self, c: usize, w: &[usize]) -> bool {
+        let (mut f, mut b) = (c, c);
+        let (mut i, mut j) = (0, w.len() as isize - 1);
+        loop {
+            while i <= j {
+                match self.table[f][w[i as usize]] {
+                    Some(d) => f = d,
+                    None => break,
+                }
+                i += 1;
+            }
+            if i > j {
+                if f != b {
+                    self.coincidence(f, b);
+                }
+                return true;
+            }
+            while j >= i {
+                match self.table[b][w[j as usize] ^ 1] {
+                    Some(d) => b = d,
+                    None => break,
+                }
+                j -= 1;
+            }
+            if j < i {
+                self.coincidence(f, b);
+                return true;
+            }
+            let l = w[i as usize];
+            if i == j {
+                self.table[f][l] = Some(b);
+                self.table[b][l ^ 1] = Some(f);
+                return true;
+            }
+            if !self.define(f, l) {
+                return false;
+            }
+        }
+    }
+
+    /// Run the enumeration, returning the standardized table of live cosets
+    /// or `None` if the limit was exceeded.
+    fn run(mut self, rank: usize, relators: &[Vec<usize>], subgroup: &[Vec<usize>]) -> Option<CosetTable> {
+        for w in subgroup {
+            if !self.scan_and_fill(0, w) {
+                return None;
+            }
+        }
+        let mut c = 0;
+        while c < self.table.len() {
+            for r in relators {
+                if !self.is_live(c) {
+                    break;
+                }
+                if !self.scan_and_fill(c, r) {
+                    return None;
+                }
+            }
+            if self.is_live(c) {
+                for l in 0..2 * rank {
+                    if self.table[c][l].is_none() && !self.define(c, l) {
+                        return None;
+                    }
+                }
+            }
+            c += 1;
+        }
+        Some(self.standardize(rank))
+    }
+
+    fn standardize(mut self, rank: usize) -> CosetTable {
+        let n = self.table.len();
+        let mut number = vec![usize::MAX; n];
+        let mut order = vec![0];
+        number[0] = 0;
+        let mut k = 0;
+        while k < order.len() {
+            let c = order[k];
+            for l in 0..2 * rank {
+                let d = self.rep(self.table[c][l].unwrap());
+                if number[d] == usize::MAX {
+                    number[d] = order.len();
+                    order.push(d);
+                }
+            }
+            k += 1;
+        }
+        debug_assert_eq!(order.len(), self.live);
+        let table = order.iter()
+            .map(|&c| (0..2 * rank).map(|l| number[self.rep(self.table[c][l].unwrap())]).collect())
+            .collect();
+        CosetTable { rank, table }
+    }
+}
+
+/// Enumerate the cosets of the subgroup generated by `subgroup` in the group
+/// with the given relators, both given as lists of letters.
+fn enumerate_cosets(rank: usize, relators: &[Vec<usize>], subgroup: &[Vec<usize>], limit: usize) -> Option<CosetTable> {
+    Enumeration::new(rank, limit).run(rank, relators, subgroup)
+}
+
+/// A finitely presented group `⟨X | R⟩`, the quotient of the free group on `X`
+/// by the normal closure of the relators `R`.
+///
+/// Finite groups and subgroups of finite index are handled by Todd-Coxeter
+/// coset enumeration, which is bounded by a limit on the number of cosets and
+/// returns `None` if it is exceeded. In particular the word problem is solved
+/// through the action on the cosets of the trivial subgroup, which is computed
+/// once and cached, so comparing elements panics if the group is infinite or
+/// larger than `COSET_LIMIT`.
+#[derive(Clone)]
+pub struct FinitelyPresentedGroup {
+    free: FreeGroup,
+    relators: Vec<FreeWord>,
+    regular: Arc<OnceLock<Option<CosetTable>>>,
+}
+
+/// An element of a `FinitelyPresentedGroup`, represented by a word in the
+/// generators.
+#[derive(Clone)]
+pub struct FpGroupElement {
+    parent: FinitelyPresentedGroup,
+    word: FreeWord,
+}
+
+impl FinitelyPresentedGroup {
+    /// Initialize the quotient of `free` by the normal closure of `relators`.
+    pub fn init(free: &FreeGroup, relators: &[FreeWord]) -> Self {
+        FinitelyPresentedGroup {
+            free: free.clone(),
+            relators: relators.to_vec(),
+            regular: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// Return the free group on the generators.
+    #[inline]
+    pub fn free_group(&self) -> &FreeGroup {
+        &self.free
+    }
+
+    /// Return the relators.
+    #[inline]
+    pub fn relators(&self) -> &[FreeWord] {
+        &self.relators
+    }
+
+    /// Return the generators.
+    pub fn gens(&self) -> Vec<FpGroupElement> {
+        self.free.gens().into_iter().map(|w| self.elem(w)).collect()
+    }
+
+    /// Return the coset table of the subgroup generated by `subgroup`, or
+    /// `None` if the enumeration defines more than `limit` cosets.
+    pub fn coset_table(&self, subgroup: &[FreeWord], limit: usize) -> Option<CosetTable> {
+        let relators: Vec<Vec<usize>> = self.relators.iter().map(|r| r.letters().to_vec()).collect();
+        let subgroup: Vec<Vec<usize>> = subgroup.iter().map(|w| w.letters().to_vec()).collect();
+        enumerate_cosets(self.free.rank(), &relators, &subgroup, limit)
+    }
+
+    /// Return the index of the subgroup generated by `subgroup`, or `None` if
+    /// the enumeration exceeds `COSET_LIMIT` cosets.
+    pub fn index(&self, subgroup: &[FreeWord]) -> Option<usize> {
+        self.coset_table(subgroup, COSET_LIMIT).map(|t| t.len())
+    }
+
+    /// Return the order of the group, or `None` if the group is infinite or
+    /// its enumeration exceeds `COSET_LIMIT` cosets.
+    pub fn order(&self) -> Option<u128> {
+        self.regular_table().map(|t| t.len() as u128)
+    }
+
+    /// Return the images of the generators in the permutation representation
+    /// on the right cosets of the subgroup generated by `subgroup`, or `None`
+    /// if the enumeration exceeds `COSET_LIMIT` cosets.
+    pub fn permutation_representation(&self, subgroup: &[FreeWord]) -> Option<Vec<Permutation>> {
+        self.coset_table(subgroup, COSET_LIMIT).map(|t| t.permutations())
+    }
+
+    /// Return the table of the action on the cosets of the trivial subgroup,
+    /// computing it on first use.
+    fn regular_table(&self) -> Option<&CosetTable> {
+        self.regular.get_or_init(|| self.coset_table(&[], COSET_LIMIT)).as_ref()
+    }
+
+    #[inline]
+    fn elem(&self, word: FreeWord) -> FpGroupElement {
+        FpGroupElement { parent: self.clone(), word }
+    }
+}
+
+impl FpGroupElement {
+    /// Return the word representing the element.
+    #[inline]
+    pub fn word(&self) -> &FreeWord {
+        &self.word
+    }
+
+    /// Return the image in the regular permutation representation, the action
+    /// by right multiplication on the elements, which are numbered as the
+    /// cosets of the trivial subgroup. Returns `None` if the group is infinite
+    /// or too large to enumerate.
+    pub fn regular_permutation(&self) -> Option<Permutation> {
+        let t = self.parent.regular_table()?;
+        let sym = SymmetricGroup::init(t.len());
+        Some(sym.new((0..t.len()).map(|c| t.act_word(c, &self.word)).collect::<Vec<_>>()))
+    }
+
+    /// Return the order of the element, or `None` if the group is infinite or
+    /// too large to enumerate.
+    pub fn order(&self) -> Option<u64> {
+        self.regular_permutation().map(|p| p.order())
+    }
+
+    /// Return the index of the coset `H w` of the trivial subgroup, which
+    /// identifies the element.
+    fn coset(&self) -> usize {
+        let t = self.parent.regular_table()
+            .expect("word problem not decided: the group is infinite or too large to enumerate");
+        t.act_word(0, &self.word)
+    }
+}
+
+impl PartialEq for FinitelyPresentedGroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.free == other.free && self.relators == other.relators
+    }
+}
+
+impl fmt::Debug for FinitelyPresentedGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FinitelyPresentedGroup({})", self)
+    }
+}
+
+/// Presentations are printed as `<a, b | a^2, b^3, a*b*a*b>`.
+impl fmt::Display for FinitelyPresentedGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let relators: Vec<String> = self.relators.iter().map(|r| r.to_string()).collect();
+        write!(f, "<{} | {}>", self.free.names().join(", "), relators.join(", "))
+    }
+}
+
+/// Panics if the word problem cannot be decided, see `FinitelyPresentedGroup`.
+impl PartialEq for FpGroupElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.word == other.word || self.coset() == other.coset()
+    }
+}
+
+impl fmt::Debug for FpGroupElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FpGroupElement({})", self.word)
+    }
+}
+
+impl fmt::Display for FpGroupElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.word)
+    }
+}
+
+impl Parent for FinitelyPresentedGroup {
+    type Element = FpGroupElement;
+}
+
+impl Element for FpGroupElement {
+    type Parent = FinitelyPresentedGroup;
+
+    #[inline]
+    fn parent(&self) -> FinitelyPresentedGroup {
+        self.parent.clone()
+    }
+}
+
+/// Construct the image of a word in the free group on the generators.
+impl NewElement<&FreeWord> for FinitelyPresentedGroup {
+    #[inline]
+    fn new(&self, src: &FreeWord) -> FpGroupElement {
+        self.elem(src.clone())
+    }
+}
+
+/// Parse a word in the generators, as for `FreeGroup`.
+impl NewElement<&str> for FinitelyPresentedGroup {
+    #[inline]
+    fn new(&self, src: &str) -> FpGroupElement {
+        self.elem(self.free.new(src))
+    }
+}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for FpGroupElement {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self.parent.elem(self.word.operate(&rhs.word))
+    }
+}
+
+impl Identity<Multiplicative> for FinitelyPresentedGroup {
+    #[inline]
+    fn identity(&self) -> FpGroupElement {
+        self.elem(self.free.identity())
+    }
+}
+
+impl IsIdentity<Multiplicative> for FpGroupElement {
+    /// Panics if the word problem cannot be decided, see
+    /// `FinitelyPresentedGroup`.
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.word.is_identity() || self.coset() == 0
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for FpGroupElement {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.parent.elem(self.word.two_sided_inverse())
+    }
+}
+
+impl Divisible<Multiplicative> for FinitelyPresentedGroup {}
+
+impl Associative<Multiplicative> for FinitelyPresentedGroup {}
@@ -0,0 +1,244 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// The free group on a list of named generators.
+///
+/// Elements are freely reduced words. A letter is stored as the index `2i` for
+/// the generator `x_i` and `2i + 1` for its inverse, so that inverting a letter
+/// flips its lowest bit.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FreeGroup {
+    names: Arc<[String]>,
+}
+
+/// A freely reduced word in a `FreeGroup`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct FreeWord {
+    parent: FreeGroup,
+    letters: Vec<usize>,
+}
+
+impl FreeGroup {
+    /// Initialize the free group on generators with the given names. Panics if
+    /// a name is repeated or is not alphanumeric.
+    pub fn init<S: AsRef<str>>(names: &[S]) -> Self {
+        let names: Vec<String> = names.iter().map(|s| s.as_ref().to_string()).collect();
+        for (i, s) in names.iter().enumerate() {
+            assert!(
+                !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_'),
+                "invalid generator name {:?}", s
+            );
+            assert!(!names[..i].contains(s), "repeated generator name {:?}", s);
+        }
+        FreeGroup { names: names.into() }
+    }
+
+    /// Return the number of generators.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Return the names of the generators.
+    #[inline]
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Return the generators.
+    pub fn gens(&self) -> Vec<FreeWord> {
+        (0..self.rank()).map(|i| self.word(vec![2 * i])).collect()
+    }
+
+    /// Return the reduced word with the given letters, each `2i` or `2i + 1`
+    /// for the generator `x_i` or its inverse.
+    pub(crate) fn word(&self, letters: Vec<usize>) -> FreeWord {
+        let mut res: Vec<usize> = Vec::with_capacity(letters.len());
+        for l in letters {
+            debug_assert!(l < 2 * self.rank());
+            if res.last() == Some(&(l ^ 1)) {
+                res.pop();
+            } else {
+                res.push(l);
+            }
+        }
+        FreeWord { parent: self.clone(), letters: res }
+    }
+}
+
+impl FreeWord {
+    /// Return the letters of the word, `2i` or `2i + 1` for the generator
+    /// `x_i` or its inverse.
+    #[inline]
+    pub fn letters(&self) -> &[usize] {
+        &self.letters
+    }
+
+    /// Return the length of the word.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.letters.len()
+    }
+
+    /// Return `true` if the word is empty, that is if it is the identity.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.letters.is_empty()
+    }
+
+    /// Return the word as a list of syllables `(i, e)` standing for `x_i^e`,
+    /// with consecutive syllables in different generators.
+    pub fn syllables(&self) -> Vec<(usize, i64)> {
+        let mut res: Vec<(usize, i64)> = vec![];
+        for &l in self.letters.iter() {
+            let e = if l & 1 == 0 { 1 } else { -1 };
+            match res.last_mut() {
+                Some((g, k)) if *g == l / 2 => *k += e,
+                _ => res.push((l / 2, e)),
+            }
+        }
+        res
+    }
+
+    /// Return the sum of the exponents of each generator, the image in the
+    /// abelianization `Z^n`.
+    pub fn exponent_sums(&self) -> Vec<i64> {
+        let mut res = vec![0; self.parent.rank()];
+        for (g, e) in self.syllables() {
+            res[g] += e;
+        }
+        res
+    }
+
+    /// Return the conjugate `g^-1 * self * g`.
+    pub fn conjugate(&self, g: &Self) -> Self {
+        g.two_sided_inverse().operate(self).operate(g)
+    }
+
+    /// Return `true` if the word is cyclically reduced, that is its first
+    /// letter is not the inverse of its last.
+    pub fn is_cyclically_reduced(&self) -> bool {
+        match (self.letters.first(), self.letters.last()) {
+            (Some(&a), Some(&b)) => self.len() == 1 || a != b ^ 1,
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Debug for FreeWord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FreeWord({})", self)
+    }
+}
+
+/// Words are printed as products of powers of the generators, for example
+/// `a^2*b^-1*a`. The empty word is printed as `1`.
+impl fmt::Display for FreeWord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let syllables = self.syllables();
+        if syllables.is_empty() {
+            return write!(f, "1");
+        }
+        let terms: Vec<String> = syllables.iter()
+            .map(|&(g, e)| match e {
+                1 => self.parent.names[g].clone(),
+                e => format!("{}^{}", self.parent.names[g], e),
+            })
+            .collect();
+        write!(f, "{}", terms.join("*"))
+    }
+}
+
+impl Parent for FreeGroup {
+    type Element = FreeWord;
+}
+
+impl Element for FreeWord {
+    type Parent = FreeGroup;
+
+    #[inline]
+    fn parent(&self) -> FreeGroup {
+        self.parent.clone()
+    }
+}
+
+/// Construct the word `x_{i_1}^{e_1} ... x_{i_k}^{e_k}` from its syllables.
+/// Panics if a generator index is out of range.
+impl NewElement<&[(usize, i64)]> for FreeGroup {
+    fn new(&self, src: &[(usize, i64)]) -> FreeWord {
+        let mut letters = vec![];
+        for &(g, e) in src {
+            assert!(g < self.rank(), "generator index out of range");
+            let l = if e < 0 { 2 * g + 1 } else { 2 * g };
+            letters.extend(std::iter::repeat_n(l, e.unsigned_abs() as usize));
+        }
+        self.word(letters)
+    }
+}
+
+/// Parse a word such as `a^2*b^-1*a`, written as a product of powers of the
+/// generator names. The identity may be written as `1`. Panics if the string
+/// is malformed.
+impl NewElement<&str> for FreeGroup {
+    fn new(&self, src: &str) -> FreeWord {
+        let s: String = src.chars().filter(|c| !c.is_whitespace()).collect();
+        let mut syllables = vec![];
+        for term in s.split('*') {
+            if term == "1" {
+                continue;
+            }
+            let (name, e) = match term.split_once('^') {
+                Some((name, e)) => (name, e.parse::<i64>().expect("malformed exponent")),
+                None => (term, 1),
+            };
+            let g = self.names.iter().position(|s| s == name)
+                .unwrap_or_else(|| panic!("unknown generator {:?}", name));
+            syllables.push((g, e));
+        }
+        self.new(syllables.as_slice())
+    }
+}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for FreeWord {
+    /// Concatenate and freely reduce.
+    fn operate(&self, rhs: &Self) -> Self {
+        let mut k = 0;
+        let n = self.letters.len();
+        while k < n.min(rhs.letters.len()) && self.letters[n - 1 - k] == rhs.letters[k] ^ 1 {
+            k += 1;
+        }
+        let mut letters = self.letters[..n - k].to_vec();
+        letters.extend_from_slice(&rhs.letters[k..]);
+        FreeWord { parent: self.parent.clone(), letters }
+    }
+}
+
+impl Identity<Multiplicative> for FreeGroup {
+    #[inline]
+    fn identity(&self) -> FreeWord {
+        FreeWord { parent: self.clone(), letters: vec![] }
+    }
+}
+
+impl IsIdentity<Multiplicative> for FreeWord {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.letters.is_empty()
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for FreeWord {
+    fn two_sided_inverse(&self) -> Self {
+        let letters = self.letters.iter().rev().map(|l| l ^ 1).collect();
+        FreeWord { parent: self.parent.clone(), letters }
+    }
+}
+
+impl Divisible<Multiplicative> for FreeGroup {}
+
+impl Associative<Multiplicative> for FreeGroup {}
@@ -0,0 +1,276 @@
+use crate::*;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// An isomorphism between finite groups, stored as the list of images of the
+/// elements of the domain.
+#[derive(Clone)]
+pub struct GroupIsomorphism<G, H, O>
+where
+    G: AbstractGroup<O> + Finite,
+    H: AbstractGroup<O> + Finite,
+    O: Operator,
+{
+    domain: G,
+    codomain: H,
+    index: Arc<HashMap<Elem<G>, usize>>,
+    images: Arc<Vec<Elem<H>>>,
+    op: PhantomData<O>,
+}
+
+impl<G, H, O> GroupIsomorphism<G, H, O>
+where
+    G: AbstractGroup<O> + Finite,
+    H: AbstractGroup<O> + Finite,
+    O: Operator,
+    Elem<G>: Eq + Hash,
+    Elem<H>: Eq + Hash,
+{
+    /// Return the images of the elements of the domain, in the order of
+    /// `Finite::elements`.
+    #[inline]
+    pub fn images(&self) -> &[Elem<H>] {
+        &self.images
+    }
+
+    /// Return the inverse isomorphism.
+    pub fn inverse(&self) -> GroupIsomorphism<H, G, O> {
+        let elems = self.domain.elements();
+        let mut images = vec![None; elems.len()];
+        let index: HashMap<Elem<H>, usize> = self.codomain.elements().into_iter()
+            .enumerate()
+            .map(|(i, y)| (y, i))
+            .collect();
+        for (x, y) in elems.into_iter().zip(self.images.iter()) {
+            images[index[y]] = Some(x);
+        }
+        GroupIsomorphism {
+            domain: self.codomain.clone(),
+            codomain: self.domain.clone(),
+            index: Arc::new(index),
+            images: Arc::new(images.into_iter().map(|x| x.unwrap()).collect()),
+            op: PhantomData,
+        }
+    }
+}
+
+impl<G, H, O> Morphism<G, H> for GroupIsomorphism<G, H, O>
+where
+    G: AbstractGroup<O> + Finite,
+    H: AbstractGroup<O> + Finite,
+    O: Operator,
+    Elem<G>: Eq + Hash,
+{
+    #[inline]
+    fn domain(&self) -> &G {
+        &self.domain
+    }
+
+    #[inline]
+    fn codomain(&self) -> &H {
+        &self.codomain
+    }
+
+    /// Panics if `x` is not an element of the domain.
+    fn apply(&self, x: &Elem<G>) -> Elem<H> {
+        self.images[*self.index.get(x).expect("element not in the domain")].clone()
+    }
+}
+
+impl<G, H, O> GroupHomomorphism<G, H, O> for GroupIsomorphism<G, H, O>
+where
+    G: AbstractGroup<O> + Finite,
+    H: AbstractGroup<O> + Finite,
+    O: Operator,
+    Elem<G>: Eq + Hash,
+{}
+
+/// Return an isomorphism from `g` to `h` with respect to the operator `O`, or
+/// `None` if the groups are not isomorphic.
+///
+/// The groups are first compared by invariants of their elements: the order,
+/// the number of square roots and the order of the centralizer. A small
+/// generating set of `g` is then chosen, and images for the generators are
+/// searched by backtracking among the elements of `h` with the same
+/// invariants. Each partial assignment is extended to the subgroup it
+/// generates, and rejected as soon as the extension is not a well-defined
+/// injective map preserving invariants.
+pub fn find_isomorphism<G, H, O>(op: O, g: &G, h: &H) -> Option<GroupIsomorphism<G, H, O>>
+where
+    G: AbstractGroup<O> + Finite,
+    H: AbstractGroup<O> + Finite,
+    O: Operator,
+    Elem<G>: Eq + Hash,
+    Elem<H>: Eq + Hash,
+{
+    let elems_g = g.elements();
+    let elems_h = h.elements();
+    if elems_g.len() != elems_h.len() {
+        return None;
+    }
+    let a = cayley_table(op, g);
+    let b = cayley_table(op, h);
+    let ea = elems_g.iter().position(IsIdentity::<O>::is_identity).unwrap();
+    let eb = elems_h.iter().position(IsIdentity::<O>::is_identity).unwrap();
+    let sa = invariants(&a, ea);
+    let sb = invariants(&b, eb);
+    let (mut sorted_a, mut sorted_b) = (sa.clone(), sb.clone());
+    sorted_a.sort_unstable();
+    sorted_b.sort_unstable();
+    if sorted_a != sorted_b {
+        return None;
+    }
+
+    let gens = generating_set(&a, ea, &sa);
+    let search = Search { a: &a, b: &b, ea, eb, sa: &sa, sb: &sb, gens: &gens };
+    let map = search.backtrack(&mut vec![])?;
+
+    let index = elems_g.into_iter().enumerate().map(|(i, x)| (x, i)).collect();
+    let images = map.iter().map(|&j| elems_h[j].clone()).collect();
+    Some(GroupIsomorphism {
+        domain: g.clone(),
+        codomain: h.clone(),
+        index: Arc::new(index),
+        images: Arc::new(images),
+        op: PhantomData,
+    })
+}
+
+/// Return `true` if the groups `g` and `h` are isomorphic with respect to the
+/// operator `O`.
+pub fn is_isomorphic<G, H, O>(op: O, g: &G, h: &H) -> bool
+where
+    G: AbstractGroup<O> + Finite,
+    H: AbstractGroup<O> + Finite,
+    O: Operator,
+    Elem<G>: Eq + Hash,
+    Elem<H>: Eq + Hash,
+{
+    find_isomorphism(op, g, h).is_some()
+}
+
+/// The invariants `(order, square roots, centralizer order)` of each element of
+/// the group with Cayley table `t` and identity `e`.
+fn invariants(t: &[Vec<usize>], e: usize) -> Vec<(usize, usize, usize)> {
+    let n = t.len();
+    let mut roots = vec![0; n];
+    for x in 0..n {
+        roots[t[x][x]] += 1;
+    }
+    (0..n)
+        .map(|x| {
+            let (mut y, mut order) = (x, 1);
+            while y != e {
+                y = t[y][x];
+                order += 1;
+            }
+            let centralizer = (0..n).filter(|&y| t[x][y] == t[y][x]).count();
+            (order, roots[x], centralizer)
+        })
+        .collect()
+}
+
+/// Return the subgroup generated by `gens` in the group with Cayley table `t`,
+/// as a membership vector.
+fn closure(t: &[Vec<usize>], e: usize, gens: &[usize]) -> Vec<bool> {
+    let mut member = vec![false; t.len()];
+    member[e] = true;
+    let mut queue = vec![e];
+    while let Some(x) = queue.pop() {
+        for &s in gens {
+            let y = t[x][s];
+            if !member[y] {
+                member[y] = true;
+                queue.push(y);
+            }
+        }
+    }
+    member
+}
+
+/// Choose a small generating set greedily, preferring elements of large order
+/// with rare invariants, since these have the fewest candidate images.
+fn generating_set(t: &[Vec<usize>], e: usize, inv: &[(usize, usize, usize)]) -> Vec<usize> {
+    let mut count: HashMap<(usize, usize, usize), usize> = HashMap::new();
+    for s in inv {
+        *count.entry(*s).or_insert(0) += 1;
+    }
+    let mut candidates: Vec<usize> = (0..t.len()).collect();
+    candidates.sort_by_key(|&x| (std::cmp::Reverse(inv[x].0), count[&inv[x]]));
+    let mut gens = vec![];
+    let mut member = closure(t, e, &gens);
+    for x in candidates {
+        if !member[x] {
+            gens.push(x);
+            member = closure(t, e, &gens);
+        }
+    }
+    gens
+}
+
+struct Search<'a> {
+    a: &'a [Vec<usize>],
+    b: &'a [Vec<usize>],
+    ea: usize,
+    eb: usize,
+    sa: &'a [(usize, usize, usize)],
+    sb: &'a [(usize, usize, usize)],
+    gens: &'a [usize],
+}
+
+impl Search<'_> {
+    /// Extend an assignment of images to the first generators, returning the
+    /// complete map once all generators are assigned.
+    fn backtrack(&self, images: &mut Vec<usize>) -> Option<Vec<usize>> {
+        let k = images.len();
+        let map = self.extend(images)?;
+        if k == self.gens.len() {
+            return Some(map.into_iter().map(|y| y.unwrap()).collect());
+        }
+        let s = self.gens[k];
+        for c in 0..self.b.len() {
+            if self.sb[c] == self.sa[s] && !images.contains(&c) {
+                images.push(c);
+                if let Some(res) = self.backtrack(images) {
+                    return Some(res);
+                }
+                images.pop();
+            }
+        }
+        None
+    }
+
+    /// Extend the assignment of the first generators to a map on the subgroup
+    /// they generate, or return `None` if it is not a well-defined injective
+    /// homomorphism preserving invariants.
+    fn extend(&self, images: &[usize]) -> Option<Vec<Option<usize>>> {
+        let n = self.a.len();
+        let mut map = vec![None; n];
+        let mut used = vec![false; n];
+        map[self.ea] = Some(self.eb);
+        used[self.eb] = true;
+        let mut queue = vec![self.ea];
+        while let Some(x) = queue.pop() {
+            let fx = map[x].unwrap();
+            for (&s, &fs) in self.gens.iter().zip(images.iter()) {
+                let (y, fy) = (self.a[x][s], self.b[fx][fs]);
+                match map[y] {
+                    Some(z) if z != fy => return None,
+                    Some(_) => {}
+                    None => {
+                        if used[fy] || self.sa[y] != self.sb[fy] {
+                            return None;
+                        }
+                        map[y] = Some(fy);
+                        used[fy] = true;
+                        queue.push(y);
+                    }
+                }
+            }
+        }
+        Some(map)
+    }
+}
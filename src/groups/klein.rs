@@ -0,0 +1,121 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+
+/// The Klein four-group `V_4 = ⟨a, b | a^2, b^2, (ab)^2⟩ ≅ C_2 × C_2`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct KleinFourGroup;
+
+/// An element `a^x b^y` of the `KleinFourGroup`, stored as the bits `x` and
+/// `y` of an integer below four.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KleinFourElement(u8);
+
+impl KleinFourGroup {
+    /// Initialize the Klein four-group.
+    #[inline]
+    pub fn init() -> Self {
+        KleinFourGroup
+    }
+
+    /// Return the order `4`.
+    #[inline]
+    pub fn order(&self) -> u64 {
+        4
+    }
+
+    /// Return the generators `a` and `b`.
+    pub fn gens(&self) -> Vec<KleinFourElement> {
+        vec![KleinFourElement(1), KleinFourElement(2)]
+    }
+}
+
+impl KleinFourElement {
+    /// Return the exponents `(x, y)` of `a^x b^y`.
+    #[inline]
+    pub fn exponents(&self) -> (u8, u8) {
+        (self.0 & 1, self.0 >> 1)
+    }
+
+    /// Return the order of the element.
+    #[inline]
+    pub fn order(&self) -> u64 {
+        if self.0 == 0 { 1 } else { 2 }
+    }
+}
+
+/// Elements are printed as `1`, `a`, `b` and `a*b`.
+impl fmt::Display for KleinFourElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ["1", "a", "b", "a*b"][self.0 as usize])
+    }
+}
+
+impl Parent for KleinFourGroup {
+    type Element = KleinFourElement;
+}
+
+impl Element for KleinFourElement {
+    type Parent = KleinFourGroup;
+
+    #[inline]
+    fn parent(&self) -> KleinFourGroup {
+        KleinFourGroup
+    }
+}
+
+/// Construct `a^x b^y` from `(x, y)`, taking the exponents modulo two.
+impl NewElement<(u8, u8)> for KleinFourGroup {
+    #[inline]
+    fn new(&self, src: (u8, u8)) -> KleinFourElement {
+        KleinFourElement((src.0 & 1) | ((src.1 & 1) << 1))
+    }
+}
+
+impl Finite for KleinFourGroup {
+    fn elements(&self) -> Vec<KleinFourElement> {
+        (0..4).map(KleinFourElement).collect()
+    }
+
+    #[inline]
+    fn cardinality(&self) -> usize {
+        4
+    }
+}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for KleinFourElement {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        KleinFourElement(self.0 ^ rhs.0)
+    }
+}
+
+impl Identity<Multiplicative> for KleinFourGroup {
+    #[inline]
+    fn identity(&self) -> KleinFourElement {
+        KleinFourElement(0)
+    }
+}
+
+impl IsIdentity<Multiplicative> for KleinFourElement {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for KleinFourElement {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        *self
+    }
+}
+
+impl Divisible<Multiplicative> for KleinFourGroup {}
+
+impl Associative<Multiplicative> for KleinFourGroup {}
+
+impl Commutative<Multiplicative> for KleinFourGroup {}
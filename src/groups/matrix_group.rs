@@ -0,0 +1,330 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// The kind of a `MatrixGroup`, the condition an invertible matrix must satisfy
+/// to belong to it.
+pub trait LinearKind: Clone + Copy + fmt::Debug + Default + PartialEq + Eq {
+    /// Return `true` if the invertible square matrix `m` belongs to the group.
+    fn contains<F: Field>(m: &GenericMatrix<F>) -> bool;
+
+    /// Return the order of the group of `n × n` matrices over the field with
+    /// `q` elements, or `None` if no formula is known or it does not fit in a
+    /// `u128`.
+    fn order(n: usize, q: u128) -> Option<u128>;
+}
+
+/// All invertible matrices.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GeneralLinear;
+
+/// Matrices of determinant one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SpecialLinear;
+
+/// Matrices `m` with `m m^T = 1`, preserving the form `x_1^2 + ... + x_n^2`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Orthogonal;
+
+impl LinearKind for GeneralLinear {
+    #[inline]
+    fn contains<F: Field>(_: &GenericMatrix<F>) -> bool {
+        true
+    }
+
+    /// `|GL(n, q)| = (q^n - 1)(q^n - q) ... (q^n - q^(n-1))`.
+    fn order(n: usize, q: u128) -> Option<u128> {
+        let qn = q.checked_pow(n as u32)?;
+        (0..n).try_fold(1u128, |acc, i| acc.checked_mul(qn - q.pow(i as u32)))
+    }
+}
+
+impl LinearKind for SpecialLinear {
+    #[inline]
+    fn contains<F: Field>(m: &GenericMatrix<F>) -> bool {
+        m.det().is_one()
+    }
+
+    /// `|SL(n, q)| = |GL(n, q)| / (q - 1)`.
+    fn order(n: usize, q: u128) -> Option<u128> {
+        if n == 0 {
+            return Some(1);
+        }
+        Some(GeneralLinear::order(n, q)? / (q - 1))
+    }
+}
+
+impl LinearKind for Orthogonal {
+    #[inline]
+    fn contains<F: Field>(m: &GenericMatrix<F>) -> bool {
+        m.mul_matrix(&m.transpose()) == m.parent().scalar(&m.base_ring().one())
+    }
+
+    /// For odd `q`, `|O(2m + 1, q)| = 2 q^(m^2) (q^2 - 1)(q^4 - 1) ... (q^2m - 1)`
+    /// and `|O(2m, q)| = 2 q^(m(m-1)) (q^m - ε)(q^2 - 1) ... (q^(2m-2) - 1)`,
+    /// where `ε` is `1` if `(-1)^m` is a square and `-1` otherwise. There is
+    /// no formula for even `q`, where the form is degenerate.
+    fn order(n: usize, q: u128) -> Option<u128> {
+        if q.is_multiple_of(2) {
+            return None;
+        }
+        let m = (n / 2) as u32;
+        let prod = |k: u32| (1..=k).try_fold(1u128, |acc, i| acc.checked_mul(q.checked_pow(2 * i)? - 1));
+        if n % 2 == 1 {
+            q.checked_pow(m * m)?.checked_mul(prod(m)?)?.checked_mul(2)
+        } else if m == 0 {
+            Some(1)
+        } else {
+            let qm = q.checked_pow(m)?;
+            let t = if m.is_multiple_of(2) || q % 4 == 1 { qm - 1 } else { qm.checked_add(1)? };
+            q.checked_pow(m * (m - 1))?.checked_mul(t)?.checked_mul(prod(m - 1)?)?.checked_mul(2)
+        }
+    }
+}
+
+/// A group of invertible `n × n` matrices over a field, determined by its
+/// kind: the general linear group `GL(n, F)`, the special linear group
+/// `SL(n, F)` or the orthogonal group `O(n, F)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixGroup<F: Field, K: LinearKind> {
+    space: GenericMatrixSpace<F>,
+    kind: PhantomData<K>,
+}
+
+/// The general linear group `GL(n, F)` of invertible matrices.
+pub type GeneralLinearGroup<F> = MatrixGroup<F, GeneralLinear>;
+
+/// The special linear group `SL(n, F)` of matrices of determinant one.
+pub type SpecialLinearGroup<F> = MatrixGroup<F, SpecialLinear>;
+
+/// The orthogonal group `O(n, F)` of matrices with `m m^T = 1`.
+pub type OrthogonalGroup<F> = MatrixGroup<F, Orthogonal>;
+
+/// An element of a `MatrixGroup`.
+#[derive(Clone)]
+pub struct MatrixGroupElement<F: Field, K: LinearKind> {
+    parent: MatrixGroup<F, K>,
+    mat: GenericMatrix<F>,
+}
+
+impl<F: Field, K: LinearKind> MatrixGroup<F, K> {
+    /// Initialize the group of `n × n` matrices over `field`.
+    pub fn init(field: &F, n: usize) -> Self {
+        MatrixGroup { space: GenericMatrixSpace::init(field, n as u64, n as u64), kind: PhantomData }
+    }
+
+    /// Return a reference to the base field.
+    #[inline]
+    pub fn base_field(&self) -> &F {
+        self.space.base_ring()
+    }
+
+    /// Return the size `n` of the matrices.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.space.nrows()
+    }
+
+    /// Return a reference to the space of all `n × n` matrices.
+    #[inline]
+    pub fn matrix_space(&self) -> &GenericMatrixSpace<F> {
+        &self.space
+    }
+
+    /// Return `true` if `m` is an element of the group.
+    pub fn contains(&self, m: &GenericMatrix<F>) -> bool {
+        m.nrows() == self.degree()
+            && m.ncols() == self.degree()
+            && !m.det().is_zero()
+            && K::contains(m)
+    }
+
+    #[inline]
+    fn elem(&self, mat: GenericMatrix<F>) -> MatrixGroupElement<F, K> {
+        MatrixGroupElement { parent: self.clone(), mat }
+    }
+}
+
+impl<F: Field + Finite, K: LinearKind> MatrixGroup<F, K> {
+    /// Return the order of the group over a finite field, or `None` if no
+    /// formula is known or it does not fit in a `u128`.
+    pub fn order(&self) -> Option<u128> {
+        K::order(self.degree(), self.base_field().cardinality() as u128)
+    }
+}
+
+impl<F: Field + Finite> GeneralLinearGroup<F> {
+    /// Return a uniformly random invertible matrix over a finite field, by
+    /// rejection sampling. At least a quarter of all matrices are invertible.
+    pub fn random_element<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> MatrixGroupElement<F, GeneralLinear> {
+        let field = self.base_field().elements();
+        let n = self.degree();
+        loop {
+            let entries = (0..n * n).map(|_| field[rng.gen_range(0, field.len())].clone()).collect();
+            let m = self.space.new(entries);
+            if !m.det().is_zero() {
+                return self.elem(m);
+            }
+        }
+    }
+}
+
+impl<F: Field + Finite> SpecialLinearGroup<F> {
+    /// Return a uniformly random matrix of determinant one over a finite field,
+    /// by scaling the first row of a random invertible matrix.
+    pub fn random_element<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> MatrixGroupElement<F, SpecialLinear> {
+        let gl = GeneralLinearGroup::init(self.base_field(), self.degree());
+        let mut m = gl.random_element(rng).mat;
+        let n = self.degree();
+        if n > 0 {
+            let mut d = m.det();
+            d.inv_assign();
+            for j in 0..n {
+                let mut x = m.get_entry(0, j).unwrap();
+                x *= &d;
+                m.set_entry(0, j, x);
+            }
+        }
+        self.elem(m)
+    }
+}
+
+impl<F: Field, K: LinearKind> MatrixGroupElement<F, K> {
+    /// Return a reference to the matrix.
+    #[inline]
+    pub fn matrix(&self) -> &GenericMatrix<F> {
+        &self.mat
+    }
+
+    /// Return a reference to the entry in row `i` and column `j`.
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> &Elem<F> {
+        self.mat.entry(i, j)
+    }
+
+    /// Return the determinant.
+    #[inline]
+    pub fn det(&self) -> Elem<F> {
+        self.mat.det()
+    }
+}
+
+impl<F: Field, K: LinearKind> PartialEq for MatrixGroupElement<F, K> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.mat == other.mat
+    }
+}
+
+impl<F: Field, K: LinearKind> Eq for MatrixGroupElement<F, K>
+where
+    <F as Field>::Element: Eq,
+{}
+
+impl<F: Field, K: LinearKind> Hash for MatrixGroupElement<F, K>
+where
+    <F as Field>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.mat.entries().hash(state);
+    }
+}
+
+impl<F: Field, K: LinearKind> fmt::Debug for MatrixGroupElement<F, K>
+where
+    <F as Field>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<_> = self.mat.entries().chunks(self.parent.degree().max(1)).collect();
+        f.debug_tuple("MatrixGroupElement").field(&rows).finish()
+    }
+}
+
+impl<F: Field, K: LinearKind> Parent for MatrixGroup<F, K> {
+    type Element = MatrixGroupElement<F, K>;
+}
+
+impl<F: Field, K: LinearKind> Element for MatrixGroupElement<F, K> {
+    type Parent = MatrixGroup<F, K>;
+
+    #[inline]
+    fn parent(&self) -> MatrixGroup<F, K> {
+        self.parent.clone()
+    }
+}
+
+/// Construct an element from a matrix. Panics if it is not in the group.
+impl<F: Field, K: LinearKind> NewElement<GenericMatrix<F>> for MatrixGroup<F, K> {
+    fn new(&self, src: GenericMatrix<F>) -> MatrixGroupElement<F, K> {
+        assert!(self.contains(&src), "matrix is not in the group");
+        self.elem(src)
+    }
+}
+
+/// Construct an element from its entries in row-major order. Panics if the
+/// matrix is not in the group.
+impl<F: Field, K: LinearKind> NewElement<Vec<Elem<F>>> for MatrixGroup<F, K> {
+    fn new(&self, src: Vec<Elem<F>>) -> MatrixGroupElement<F, K> {
+        self.new(self.space.new(src))
+    }
+}
+
+/// All elements of a matrix group over a finite field, found by testing every
+/// matrix. This is only practical for very small groups.
+impl<F: Field + Finite, K: LinearKind> Finite for MatrixGroup<F, K> {
+    fn elements(&self) -> Vec<MatrixGroupElement<F, K>> {
+        let field = self.base_field().elements();
+        let (q, k) = (field.len(), self.degree() * self.degree());
+        let mut digits = vec![0; k];
+        let mut res = vec![];
+        loop {
+            let m = self.space.new(digits.iter().map(|&i| field[i].clone()).collect());
+            if self.contains(&m) {
+                res.push(self.elem(m));
+            }
+            match digits.iter().position(|&i| i + 1 < q) {
+                Some(j) => {
+                    digits[j] += 1;
+                    digits[..j].iter_mut().for_each(|i| *i = 0);
+                }
+                None => return res,
+            }
+        }
+    }
+}
+
+// Multiplicative properties
+
+impl<F: Field, K: LinearKind> Operation<Multiplicative> for MatrixGroupElement<F, K> {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self.parent.elem(self.mat.mul_matrix(&rhs.mat))
+    }
+}
+
+impl<F: Field, K: LinearKind> Identity<Multiplicative> for MatrixGroup<F, K> {
+    #[inline]
+    fn identity(&self) -> MatrixGroupElement<F, K> {
+        self.elem(self.space.scalar(&self.base_field().one()))
+    }
+}
+
+impl<F: Field, K: LinearKind> IsIdentity<Multiplicative> for MatrixGroupElement<F, K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.mat == self.parent.space.scalar(&self.parent.base_field().one())
+    }
+}
+
+impl<F: Field, K: LinearKind> TwoSidedInverse<Multiplicative> for MatrixGroupElement<F, K> {
+    fn two_sided_inverse(&self) -> Self {
+        self.parent.elem(self.mat.inverse().expect("singular matrix in a matrix group"))
+    }
+}
+
+impl<F: Field, K: LinearKind> Divisible<Multiplicative> for MatrixGroup<F, K> {}
+
+impl<F: Field, K: LinearKind> Associative<Multiplicative> for MatrixGroup<F, K> {}
@@ -0,0 +1,380 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// One level of a stabilizer chain: the base point `β_i`, the strong
+/// generators fixing `β_0, ..., β_{i-1}`, and a transversal mapping each point
+/// `b` of the orbit of `β_i` to an element `u_b` with `β_i^{u_b} = b`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Level {
+    point: usize,
+    gens: Vec<Permutation>,
+    orbit: Vec<usize>,
+    transversal: Vec<Option<Permutation>>,
+}
+
+impl Level {
+    fn new(point: usize, n: usize) -> Self {
+        Level { point, gens: vec![], orbit: vec![], transversal: vec![None; n] }
+    }
+
+    /// Recompute the orbit and transversal from the generators.
+    fn update(&mut self, id: &Permutation) {
+        self.transversal.iter_mut().for_each(|u| *u = None);
+        self.transversal[self.point] = Some(id.clone());
+        self.orbit = vec![self.point];
+        let mut k = 0;
+        while k < self.orbit.len() {
+            let b = self.orbit[k];
+            for s in self.gens.iter() {
+                let c = s.apply(b);
+                if self.transversal[c].is_none() {
+                    let u = self.transversal[b].as_ref().unwrap().operate(s);
+                    self.transversal[c] = Some(u);
+                    self.orbit.push(c);
+                }
+            }
+            k += 1;
+        }
+    }
+}
+
+/// The subgroup of a `SymmetricGroup` generated by a list of permutations.
+///
+/// A base and strong generating set are computed on initialization with the
+/// deterministic Schreier-Sims algorithm. A base is a sequence of points
+/// `β_0, ..., β_{k-1}` whose pointwise stabilizer is trivial, giving a chain of
+/// stabilizers `G = G_0 ≥ G_1 ≥ ... ≥ G_k = 1`, and a strong generating set
+/// contains generators of every `G_i`. Every element is then uniquely a product
+/// of coset representatives, one from each level, which gives the order,
+/// membership testing by sifting, and uniformly random elements.
+///
+/// The group is a parent whose elements are `GroupPermutation`s, so it can be
+/// used with `Subgroup`, `QuotientGroup` and `find_isomorphism`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PermutationGroup {
+    ambient: SymmetricGroup,
+    gens: Arc<Vec<Permutation>>,
+    levels: Arc<Vec<Level>>,
+}
+
+/// A permutation belonging to a `PermutationGroup`.
+#[derive(Clone)]
+pub struct GroupPermutation {
+    parent: PermutationGroup,
+    perm: Permutation,
+}
+
+impl PermutationGroup {
+    /// Initialize the subgroup of `ambient` generated by `gens`. Panics if a
+    /// generator has the wrong degree.
+    pub fn init(ambient: &SymmetricGroup, gens: &[Permutation]) -> Self {
+        for g in gens {
+            assert_eq!(g.degree(), ambient.degree(), "generator of the wrong degree");
+        }
+        let levels = schreier_sims(ambient, gens);
+        PermutationGroup { ambient: *ambient, gens: Arc::new(gens.to_vec()), levels: Arc::new(levels) }
+    }
+
+    /// Return the symmetric group containing the group.
+    #[inline]
+    pub fn ambient(&self) -> &SymmetricGroup {
+        &self.ambient
+    }
+
+    /// Return the generators the group was initialized with.
+    #[inline]
+    pub fn gens(&self) -> &[Permutation] {
+        &self.gens
+    }
+
+    /// Return the number of points acted on.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.ambient.degree()
+    }
+
+    /// Return the base `β_0, ..., β_{k-1}`.
+    pub fn base(&self) -> Vec<usize> {
+        self.levels.iter().map(|l| l.point).collect()
+    }
+
+    /// Return the strong generating set.
+    pub fn strong_generators(&self) -> Vec<Permutation> {
+        let mut res: Vec<Permutation> = vec![];
+        for g in self.levels.iter().flat_map(|l| l.gens.iter()) {
+            if !res.contains(g) {
+                res.push(g.clone());
+            }
+        }
+        res
+    }
+
+    /// Return the lengths of the basic orbits, the indices `[G_i : G_{i+1}]`.
+    pub fn basic_orbit_lengths(&self) -> Vec<usize> {
+        self.levels.iter().map(|l| l.orbit.len()).collect()
+    }
+
+    /// Return the order of the group. Panics if it does not fit in a `u128`.
+    pub fn order(&self) -> u128 {
+        self.levels.iter()
+            .try_fold(1u128, |acc, l| acc.checked_mul(l.orbit.len() as u128))
+            .expect("order overflow")
+    }
+
+    /// Return `true` if `g` is an element of the group.
+    pub fn contains(&self, g: &Permutation) -> bool {
+        if g.degree() != self.degree() {
+            return false;
+        }
+        let (h, level) = sift(&self.levels, g, 0);
+        level == self.levels.len() && h.is_identity()
+    }
+
+    /// Return the orbit of the point `i`, in the order the points are found.
+    pub fn orbit(&self, i: usize) -> Vec<usize> {
+        let mut level = Level::new(i, self.degree());
+        level.gens = self.gens.to_vec();
+        level.update(&self.ambient.identity());
+        level.orbit
+    }
+
+    /// Return `true` if the group acts transitively on its points.
+    pub fn is_transitive(&self) -> bool {
+        self.degree() == 0 || self.orbit(0).len() == self.degree()
+    }
+
+    /// Return a uniformly random element of the group.
+    pub fn random_element<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Permutation {
+        self.levels.iter().rev().fold(self.ambient.identity(), |acc, l| {
+            let b = l.orbit[rng.gen_range(0, l.orbit.len())];
+            acc.operate(l.transversal[b].as_ref().unwrap())
+        })
+    }
+
+    /// Return an iterator over all elements of the group, as products of coset
+    /// representatives.
+    pub fn elements(&self) -> impl Iterator<Item = Permutation> + '_ {
+        let k = self.levels.len();
+        let mut index = Some(vec![0; k]);
+        std::iter::from_fn(move || {
+            let cur = index.take()?;
+            let g = self.levels.iter().zip(cur.iter()).rev().fold(
+                self.ambient.identity(),
+                |acc, (l, &j)| acc.operate(l.transversal[l.orbit[j]].as_ref().unwrap()),
+            );
+            let mut next = cur;
+            for i in 0..k {
+                next[i] += 1;
+                if next[i] < self.levels[i].orbit.len() {
+                    index = Some(next);
+                    break;
+                }
+                next[i] = 0;
+            }
+            Some(g)
+        })
+    }
+}
+
+impl GroupPermutation {
+    /// Return the underlying permutation.
+    #[inline]
+    pub fn permutation(&self) -> &Permutation {
+        &self.perm
+    }
+
+    /// Return the image of the point `i`.
+    #[inline]
+    pub fn apply(&self, i: usize) -> usize {
+        self.perm.apply(i)
+    }
+
+    /// Return the order of the element.
+    #[inline]
+    pub fn order(&self) -> u64 {
+        self.perm.order()
+    }
+}
+
+impl PartialEq for GroupPermutation {
+    fn eq(&self, other: &Self) -> bool {
+        self.perm == other.perm
+    }
+}
+
+impl Eq for GroupPermutation {}
+
+impl Hash for GroupPermutation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.perm.hash(state);
+    }
+}
+
+impl fmt::Debug for GroupPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GroupPermutation({})", self.perm)
+    }
+}
+
+/// Elements are printed in cycle notation, as permutations.
+impl fmt::Display for GroupPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.perm)
+    }
+}
+
+impl Parent for PermutationGroup {
+    type Element = GroupPermutation;
+}
+
+impl Element for GroupPermutation {
+    type Parent = PermutationGroup;
+
+    #[inline]
+    fn parent(&self) -> PermutationGroup {
+        self.parent.clone()
+    }
+}
+
+/// Construct an element from a permutation. Panics if the permutation does not
+/// belong to the group.
+impl NewElement<Permutation> for PermutationGroup {
+    fn new(&self, src: Permutation) -> GroupPermutation {
+        assert!(self.contains(&src), "permutation not in the group");
+        GroupPermutation { parent: self.clone(), perm: src }
+    }
+}
+
+/// Parse an element in cycle notation such as `(0,1,2)(3,4)`. Panics if the
+/// string is malformed or the permutation does not belong to the group.
+impl NewElement<&str> for PermutationGroup {
+    fn new(&self, src: &str) -> GroupPermutation {
+        self.new(self.ambient.new(src))
+    }
+}
+
+impl Finite for PermutationGroup {
+    fn elements(&self) -> Vec<GroupPermutation> {
+        PermutationGroup::elements(self)
+            .map(|perm| GroupPermutation { parent: self.clone(), perm })
+            .collect()
+    }
+
+    /// Return the order computed by Schreier-Sims. Panics if it does not fit in
+    /// a `usize`.
+    #[inline]
+    fn cardinality(&self) -> usize {
+        usize::try_from(self.order()).expect("order does not fit in a usize")
+    }
+}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for GroupPermutation {
+    /// Return the permutation applying `self` and then `rhs`.
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        GroupPermutation { parent: self.parent.clone(), perm: self.perm.operate(&rhs.perm) }
+    }
+}
+
+impl Identity<Multiplicative> for PermutationGroup {
+    #[inline]
+    fn identity(&self) -> GroupPermutation {
+        GroupPermutation { parent: self.clone(), perm: self.ambient.identity() }
+    }
+}
+
+impl IsIdentity<Multiplicative> for GroupPermutation {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.perm.is_identity()
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for GroupPermutation {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        GroupPermutation { parent: self.parent.clone(), perm: self.perm.two_sided_inverse() }
+    }
+}
+
+impl Divisible<Multiplicative> for PermutationGroup {}
+
+impl Associative<Multiplicative> for PermutationGroup {}
+
+/// Sift `g` through the levels of the stabilizer chain starting at `start`,
+/// dividing by coset representatives. Returns the residue and the index of
+/// the level at which sifting stopped, which is the number of levels if it
+/// went through all of them.
+fn sift(levels: &[Level], g: &Permutation, start: usize) -> (Permutation, usize) {
+    let mut h = g.clone();
+    for (i, l) in levels.iter().enumerate().skip(start) {
+        match &l.transversal[h.apply(l.point)] {
+            Some(u) => h = h.operate(&u.two_sided_inverse()),
+            None => return (h, i),
+        }
+    }
+    (h, levels.len())
+}
+
+/// Compute a base and strong generating set from the generators, as a chain of
+/// levels.
+fn schreier_sims(ambient: &SymmetricGroup, gens: &[Permutation]) -> Vec<Level> {
+    let n = ambient.degree();
+    let id = ambient.identity();
+    let mut levels: Vec<Level> = vec![];
+    let gens: Vec<Permutation> = gens.iter().filter(|g| !g.is_identity()).cloned().collect();
+    for g in gens.iter() {
+        if levels.iter().all(|l| g.apply(l.point) == l.point) {
+            let point = (0..n).find(|&i| g.apply(i) != i).unwrap();
+            levels.push(Level::new(point, n));
+        }
+    }
+    for g in gens.iter() {
+        for l in levels.iter_mut() {
+            l.gens.push(g.clone());
+            if g.apply(l.point) != l.point {
+                break;
+            }
+        }
+    }
+    for l in levels.iter_mut() {
+        l.update(&id);
+    }
+
+    // Check that the Schreier generators of each level sift through the
+    // levels below it, working upwards from the last level.
+    let mut i = levels.len();
+    'levels: while i > 0 {
+        let level = levels[i - 1].clone();
+        for &b in level.orbit.iter() {
+            let ub = level.transversal[b].as_ref().unwrap();
+            for s in level.gens.iter() {
+                let c = s.apply(b);
+                let uc = level.transversal[c].as_ref().unwrap();
+                let h = ub.operate(s).operate(&uc.two_sided_inverse());
+                let (h, j) = sift(&levels, &h, i);
+                if j == levels.len() && h.is_identity() {
+                    continue;
+                }
+                if j == levels.len() {
+                    let point = (0..n).find(|&p| h.apply(p) != p).unwrap();
+                    levels.push(Level::new(point, n));
+                }
+                for l in levels[i..=j].iter_mut() {
+                    l.gens.push(h.clone());
+                    l.update(&id);
+                }
+                i = j + 1;
+                continue 'levels;
+            }
+        }
+        i -= 1;
+    }
+    levels
+}
@@ -0,0 +1,314 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+
+/// The symmetric group `S_n` of all permutations of the points `0, ..., n - 1`.
+///
+/// Permutations act on the right, so the product `p * q` applies `p` first and
+/// then `q`: `(p * q).apply(i) == q.apply(p.apply(i))`. This is the convention
+/// used by most computational group theory, and in particular by the
+/// Schreier-Sims algorithm of `PermutationGroup`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SymmetricGroup {
+    n: usize,
+}
+
+/// A permutation of `0, ..., n - 1`, stored as its list of images.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Permutation {
+    images: Vec<usize>,
+}
+
+impl SymmetricGroup {
+    /// Initialize the symmetric group on `n` points.
+    pub fn init(n: usize) -> Self {
+        SymmetricGroup { n }
+    }
+
+    /// Return the number of points acted on.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.n
+    }
+
+    /// Return the order `n!`. Panics if it does not fit in a `u128`.
+    pub fn order(&self) -> u128 {
+        (1..=self.n as u128).try_fold(1u128, |acc, k| acc.checked_mul(k))
+            .expect("order overflow")
+    }
+
+    /// Return the permutation with the given disjoint or overlapping cycles,
+    /// multiplied from left to right. Panics if a cycle repeats a point or
+    /// contains a point outside `0, ..., n - 1`.
+    pub fn from_cycles(&self, cycles: &[Vec<usize>]) -> Permutation {
+        cycles.iter().fold(self.identity(), |acc, c| acc.operate(&self.cycle(c)))
+    }
+
+    /// Return the standard generators, the transposition `(0,1)` and the
+    /// cycle `(0,1,...,n-1)`.
+    pub fn gens(&self) -> Vec<Permutation> {
+        match self.n {
+            0 | 1 => vec![],
+            2 => vec![self.cycle(&[0, 1])],
+            n => vec![self.cycle(&[0, 1]), self.cycle(&(0..n).collect::<Vec<_>>())],
+        }
+    }
+
+    /// Return a uniformly random permutation.
+    pub fn random_element<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Permutation {
+        let mut images: Vec<usize> = (0..self.n).collect();
+        for i in (1..self.n).rev() {
+            images.swap(i, rng.gen_range(0, i + 1));
+        }
+        Permutation { images }
+    }
+
+    /// Return an iterator over all `n!` permutations, in lexicographic order of
+    /// their image lists.
+    pub fn elements(&self) -> impl Iterator<Item = Permutation> {
+        let mut next = Some((0..self.n).collect::<Vec<usize>>());
+        std::iter::from_fn(move || {
+            let cur = next.take()?;
+            let mut a = cur.clone();
+            if let Some(i) = (1..a.len()).rev().find(|&i| a[i - 1] < a[i]) {
+                let j = (i..a.len()).rev().find(|&j| a[j] > a[i - 1]).unwrap();
+                a.swap(i - 1, j);
+                a[i..].reverse();
+                next = Some(a);
+            }
+            Some(Permutation { images: cur })
+        })
+    }
+
+    fn cycle(&self, c: &[usize]) -> Permutation {
+        let mut images: Vec<usize> = (0..self.n).collect();
+        let mut seen = vec![false; self.n];
+        for (k, &i) in c.iter().enumerate() {
+            assert!(i < self.n, "point {} out of range", i);
+            assert!(!seen[i], "point {} repeated in cycle", i);
+            seen[i] = true;
+            images[i] = c[(k + 1) % c.len()];
+        }
+        Permutation { images }
+    }
+}
+
+impl Permutation {
+    /// Return the image of the point `i`.
+    #[inline]
+    pub fn apply(&self, i: usize) -> usize {
+        self.images[i]
+    }
+
+    /// Return the list of images of `0, ..., n - 1`.
+    #[inline]
+    pub fn images(&self) -> &[usize] {
+        &self.images
+    }
+
+    /// Return the number of points acted on.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.images.len()
+    }
+
+    /// Return the cycles of length at least two, each starting with its
+    /// smallest point and ordered by that point.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.degree()];
+        let mut res = vec![];
+        for i in 0..self.degree() {
+            if seen[i] || self.images[i] == i {
+                continue;
+            }
+            let mut c = vec![];
+            let mut j = i;
+            while !seen[j] {
+                seen[j] = true;
+                c.push(j);
+                j = self.images[j];
+            }
+            res.push(c);
+        }
+        res
+    }
+
+    /// Return the lengths of all cycles, including fixed points, in
+    /// decreasing order.
+    pub fn cycle_type(&self) -> Vec<usize> {
+        let mut res: Vec<usize> = self.cycles().iter().map(|c| c.len()).collect();
+        let moved: usize = res.iter().sum();
+        res.extend(std::iter::repeat_n(1, self.degree() - moved));
+        res.sort_unstable_by(|a, b| b.cmp(a));
+        res
+    }
+
+    /// Return the points which are not fixed.
+    pub fn support(&self) -> Vec<usize> {
+        (0..self.degree()).filter(|&i| self.images[i] != i).collect()
+    }
+
+    /// Return the order, the least common multiple of the cycle lengths.
+    pub fn order(&self) -> u64 {
+        let gcd = |mut a: u64, mut b: u64| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        self.cycles().iter().fold(1, |acc, c| {
+            let l = c.len() as u64;
+            (acc / gcd(acc, l)).checked_mul(l).expect("order overflow")
+        })
+    }
+
+    /// Return the sign, `1` for even and `-1` for odd permutations.
+    pub fn sign(&self) -> i64 {
+        let transpositions: usize = self.cycles().iter().map(|c| c.len() - 1).sum();
+        if transpositions.is_multiple_of(2) { 1 } else { -1 }
+    }
+
+    /// Return `true` if the permutation is even.
+    #[inline]
+    pub fn is_even(&self) -> bool {
+        self.sign() == 1
+    }
+
+    /// Return `self^k`, with negative exponents giving powers of the inverse.
+    pub fn pow(&self, k: i64) -> Self {
+        let mut images: Vec<usize> = (0..self.degree()).collect();
+        for c in self.cycles() {
+            let l = c.len();
+            let e = k.rem_euclid(l as i64) as usize;
+            for (i, &x) in c.iter().enumerate() {
+                images[x] = c[(i + e) % l];
+            }
+        }
+        Permutation { images }
+    }
+}
+
+impl fmt::Debug for Permutation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Permutation({})", self)
+    }
+}
+
+/// Permutations are printed in cycle notation, omitting fixed points, for
+/// example `(0,2,1)(3,4)`. The identity is printed as `()`.
+impl fmt::Display for Permutation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cycles = self.cycles();
+        if cycles.is_empty() {
+            return write!(f, "()");
+        }
+        for c in cycles {
+            let points: Vec<String> = c.iter().map(|i| i.to_string()).collect();
+            write!(f, "({})", points.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+impl Finite for SymmetricGroup {
+    fn elements(&self) -> Vec<Permutation> {
+        SymmetricGroup::elements(self).collect()
+    }
+
+    /// Return `n!`. Panics if it does not fit in a `usize`.
+    #[inline]
+    fn cardinality(&self) -> usize {
+        usize::try_from(self.order()).expect("order does not fit in a usize")
+    }
+}
+
+impl Parent for SymmetricGroup {
+    type Element = Permutation;
+}
+
+impl Element for Permutation {
+    type Parent = SymmetricGroup;
+
+    #[inline]
+    fn parent(&self) -> SymmetricGroup {
+        SymmetricGroup { n: self.degree() }
+    }
+}
+
+/// Construct a permutation from its list of images. Panics if the list is not
+/// a permutation of `0, ..., n - 1`.
+impl NewElement<Vec<usize>> for SymmetricGroup {
+    fn new(&self, src: Vec<usize>) -> Permutation {
+        assert_eq!(src.len(), self.n, "wrong number of images");
+        let mut seen = vec![false; self.n];
+        for &i in src.iter() {
+            assert!(i < self.n && !seen[i], "not a permutation");
+            seen[i] = true;
+        }
+        Permutation { images: src }
+    }
+}
+
+/// Parse a permutation in cycle notation such as `(0,2,1)(3,4)`. Cycles need
+/// not be disjoint and are multiplied from left to right. Panics if the string
+/// is malformed.
+impl NewElement<&str> for SymmetricGroup {
+    fn new(&self, src: &str) -> Permutation {
+        let s: String = src.chars().filter(|c| !c.is_whitespace()).collect();
+        let mut cycles = vec![];
+        for part in s.split(')') {
+            if part.is_empty() {
+                continue;
+            }
+            let body = part.strip_prefix('(').expect("malformed cycle notation");
+            if body.is_empty() {
+                continue;
+            }
+            let cycle: Vec<usize> = body.split(',')
+                .map(|p| p.parse().expect("malformed cycle notation"))
+                .collect();
+            cycles.push(cycle);
+        }
+        self.from_cycles(&cycles)
+    }
+}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for Permutation {
+    /// Return the permutation applying `self` and then `rhs`. Panics if the
+    /// degrees differ.
+    fn operate(&self, rhs: &Self) -> Self {
+        assert_eq!(self.degree(), rhs.degree(), "permutations of different degrees");
+        Permutation { images: self.images.iter().map(|&i| rhs.images[i]).collect() }
+    }
+}
+
+impl Identity<Multiplicative> for SymmetricGroup {
+    #[inline]
+    fn identity(&self) -> Permutation {
+        Permutation { images: (0..self.n).collect() }
+    }
+}
+
+impl IsIdentity<Multiplicative> for Permutation {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.images.iter().enumerate().all(|(i, &j)| i == j)
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for Permutation {
+    fn two_sided_inverse(&self) -> Self {
+        let mut images = vec![0; self.degree()];
+        for (i, &j) in self.images.iter().enumerate() {
+            images[j] = i;
+        }
+        Permutation { images }
+    }
+}
+
+impl Divisible<Multiplicative> for SymmetricGroup {}
+
+impl Associative<Multiplicative> for SymmetricGroup {}
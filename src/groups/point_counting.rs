@@ -0,0 +1,720 @@
+use crate::*;
+use crate::ops::*;
+
+use std::collections::HashMap;
+
+type PrimeField = QuotientField<IntegerRing>;
+
+type Point = EllipticCurvePoint<PrimeField>;
+
+/// Return the Legendre symbol `(a/p)` for an odd prime `p`.
+fn legendre(a: u64, p: u64) -> i64 {
+    match pow_mod_u64(a % p, (p - 1) / 2, p) {
+        0 => 0,
+        1 => 1,
+        _ => -1,
+    }
+}
+
+/// Return a square root of `a` modulo an odd prime `p`, or `None` if `a` is not
+/// a square, using the Tonelli-Shanks algorithm.
+fn sqrt_mod(a: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    match legendre(a, p) {
+        0 => return Some(0),
+        -1 => return None,
+        _ => {}
+    }
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let z = (2..p).find(|&z| legendre(z, p) == -1).unwrap();
+    let mut m = s;
+    let mut c = pow_mod_u64(z, q, p);
+    let mut t = pow_mod_u64(a, q, p);
+    let mut r = pow_mod_u64(a, q.div_ceil(2), p);
+    while t != 1 {
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != 1 {
+            t2 = mul_mod_u64(t2, t2, p);
+            i += 1;
+        }
+        let b = pow_mod_u64(c, 1 << (m - i - 1), p);
+        m = i;
+        c = mul_mod_u64(b, b, p);
+        t = mul_mod_u64(t, c, p);
+        r = mul_mod_u64(r, b, p);
+    }
+    Some(r)
+}
+
+/// Return `(g, u)` with `g = gcd(a, b) = u a + v b` for some `v`.
+fn xgcd_i128(a: i128, b: i128) -> (i128, i128) {
+    let (mut r0, mut r1, mut u0, mut u1) = (a, b, 1, 0);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (u0, u1) = (u1, u0 - q * u1);
+    }
+    (r0, u0)
+}
+
+/// Combine `x ≡ r (mod m)` and `x ≡ s (mod d)` into a single congruence modulo
+/// `lcm(m, d)`. Panics if the congruences are inconsistent.
+fn crt(r: u128, m: u128, s: u128, d: u128) -> (u128, u128) {
+    let (g, u) = xgcd_i128(m as i128, d as i128);
+    let g = g as u128;
+    let diff = (s % d + d - r % d) % d;
+    assert!(diff.is_multiple_of(g), "inconsistent congruences");
+    let dg = d / g;
+    let k = (diff / g % dg) * (u.rem_euclid(dg as i128) as u128) % dg;
+    let l = m * dg;
+    ((r + m * k) % l, l)
+}
+
+/// Return the Hasse interval `[p + 1 - 2 sqrt(p), p + 1 + 2 sqrt(p)]` which
+/// contains the number of points of every curve over `GF(p)`.
+fn hasse_interval(p: u64) -> (u64, u64) {
+    let s = (4 * p as u128).isqrt() as u64;
+    (p + 1 - s, p + 1 + s)
+}
+
+/// Return the order of `pt`, given a positive multiple `m` of it.
+fn order_from_multiple(pt: &Point, m: u64) -> u64 {
+    let curve = pt.parent();
+    let mut n = m;
+    for (l, e) in factor_u64(m) {
+        for _ in 0..e {
+            if !curve.power(Additive, pt, n / l).is_infinity() {
+                break;
+            }
+            n /= l;
+        }
+    }
+    n
+}
+
+/// Return a positive multiple of the order of `pt`, given that one lies in
+/// `[lo, hi]`, by baby-step giant-step.
+fn find_multiple(pt: &Point, lo: u64, hi: u64) -> u64 {
+    let curve = pt.parent();
+    let m = (hi - lo).isqrt() + 1;
+    let mut baby = HashMap::new();
+    let mut q = curve.infinity();
+    for j in 0..m {
+        if j > 0 && q.is_infinity() {
+            return j;
+        }
+        baby.insert(q.clone(), j);
+        q += pt;
+    }
+    // Look for (lo + i m) P = j P, so that (lo + i m - j) P = 0.
+    let giant = curve.power(Additive, pt, m);
+    let mut q = curve.power(Additive, pt, lo);
+    for i in 0..=(hi - lo) / m + 1 {
+        if let Some(j) = baby.get(&q) {
+            return lo + i * m - j;
+        }
+        q += &giant;
+    }
+    unreachable!("no multiple of the order in the interval")
+}
+
+/// Return `d` in `[0, l)` with `d G = H` in a group of prime order `l`, or
+/// `None` if there is none, by baby-step giant-step.
+fn discrete_log_prime(g: &Point, h: &Point, l: u64) -> Option<u64> {
+    let curve = g.parent();
+    let m = l.isqrt() + 1;
+    let mut baby = HashMap::new();
+    let mut q = curve.infinity();
+    for j in 0..m {
+        baby.entry(q.clone()).or_insert(j);
+        q += g;
+    }
+    let giant = -curve.power(Additive, g, m);
+    let mut q = h.clone();
+    for i in 0..=m {
+        if let Some(j) = baby.get(&q) {
+            return Some(i * m + j).filter(|&d| d < l);
+        }
+        q += &giant;
+    }
+    None
+}
+
+/// Return `y` with `y G = H`, where `G` has order `l^a` for a prime `l`, or
+/// `None` if `H` is not a multiple of `G`, using the Pohlig-Hellman reduction to
+/// subgroups of order `l`.
+fn discrete_log_prime_power(g: &Point, h: &Point, l: u64, a: u32) -> Option<u64> {
+    let curve = g.parent();
+    let g1 = curve.power(Additive, g, l.pow(a - 1));
+    let mut y = 0;
+    for k in 0..a {
+        let rest = h - &curve.power(Additive, g, y);
+        let hk = curve.power(Additive, &rest, l.pow(a - 1 - k));
+        y += discrete_log_prime(&g1, &hk, l)? * l.pow(k);
+    }
+    Some(y).filter(|&y| curve.power(Additive, g, y) == *h)
+}
+
+/// Return the smallest `k` such that `l^k P = 0`.
+fn prime_power_order(pt: &Point, l: u64) -> u32 {
+    let curve = pt.parent();
+    let mut q = pt.clone();
+    let mut k = 0;
+    while !q.is_infinity() {
+        q = curve.power(Additive, &q, l);
+        k += 1;
+    }
+    k
+}
+
+/// Counting points and computing the group structure over a prime field.
+impl EllipticCurve<PrimeField> {
+    /// Return the characteristic `p` of the base field.
+    #[inline]
+    fn prime(&self) -> u64 {
+        *self.base_field().modulus() as u64
+    }
+
+    /// Return the coefficients `(a, b)` as integers in `[0, p)`.
+    #[inline]
+    fn integer_coefficients(&self) -> (u64, u64) {
+        let (a, b) = self.coefficients();
+        (*a.lift() as u64, *b.lift() as u64)
+    }
+
+    /// Return a point with first coordinate `x`, or `None` if there is none. Of
+    /// the points `(x, ±y)` the one with `y` in `[0, p/2]` is returned.
+    pub fn lift_x(&self, x: &Residue<IntegerRing, MaximalIdeal>) -> Option<Point> {
+        let f = self.base_field();
+        let p = self.prime();
+        let (a, b) = self.coefficients();
+        let rhs = &(&(&(x * x) + a) * x) + b;
+        let y = sqrt_mod(*rhs.lift() as u64, p)?;
+        self.point(x, &f.new(y.min(p - y) as i64))
+    }
+
+    /// Return a uniformly random point, including possibly the point at infinity.
+    pub fn random_element<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Point {
+        let f = self.base_field();
+        let p = self.prime();
+        // Each of the 2p + 2 choices of (x, sign), with x = p standing for the
+        // point at infinity, is accepted with probability one over the number of
+        // choices giving the same point.
+        loop {
+            let x = rng.gen_range(0, p + 1);
+            let sign = rng.gen::<bool>();
+            if x == p {
+                if sign {
+                    return self.infinity();
+                }
+                continue;
+            }
+            if let Some(pt) = self.lift_x(&f.new(x as i64)) {
+                if sign {
+                    return pt;
+                }
+                if !pt.affine().unwrap().1.is_zero() {
+                    return -pt;
+                }
+            }
+        }
+    }
+
+    /// Return the quadratic twist `y^2 = x^3 + a d^2 x + b d^3` by the smallest
+    /// non-square `d`. If the curve has `p + 1 - t` points, its twist has
+    /// `p + 1 + t` points.
+    pub fn quadratic_twist(&self) -> Self {
+        let f = self.base_field();
+        let p = self.prime();
+        let d = f.new((2..p).find(|&d| legendre(d, p) == -1).unwrap() as i64);
+        let (a, b) = self.coefficients();
+        let d2 = &d * &d;
+        EllipticCurve::init(f, &(a * &d2), &(&(b * &d2) * &d))
+    }
+
+    /// Return the number of points `#E(GF(p))`, including the point at infinity.
+    /// Small fields are counted with `count_points_naive` and larger ones with
+    /// `count_points_bsgs`.
+    ///
+    /// This never dispatches to `count_points_schoof`: primes are limited to 64
+    /// bits, and in that range the `O(p^(1/4))` baby-step giant-step count is
+    /// faster than Schoof's algorithm, taking well under a second even for
+    /// 62-bit primes.
+    pub fn count_points(&self) -> u64 {
+        if self.prime() < 1 << 10 {
+            self.count_points_naive()
+        } else {
+            self.count_points_bsgs()
+        }
+    }
+
+    /// Return the number of points by summing Legendre symbols,
+    ///
+    /// ```notrust
+    /// #E(GF(p)) = p + 1 + Σ_x ((x^3 + a x + b) / p)
+    /// ```
+    ///
+    /// which takes `O(p log p)` operations.
+    pub fn count_points_naive(&self) -> u64 {
+        let p = self.prime();
+        let (a, b) = self.integer_coefficients();
+        let mut n = p as i128 + 1;
+        for x in 0..p {
+            let x2a = (mul_mod_u64(x, x, p) + a) % p;
+            n += legendre((mul_mod_u64(x2a, x, p) + b) % p, p) as i128;
+        }
+        n as u64
+    }
+
+    /// Return the number of points by Mestre's baby-step giant-step method, in
+    /// `O(p^{1/4})` group operations.
+    ///
+    /// The number of points `N` lies in the Hasse interval, and the quadratic
+    /// twist has `2p + 2 - N` points. The orders of points on the curve and its
+    /// twist, each found by baby-step giant-step in the interval, determine `N`
+    /// modulo their least common multiple, until a single candidate remains. By
+    /// Mestre's theorem this happens for all `p > 229`; smaller fields are counted
+    /// naively.
+    pub fn count_points_bsgs(&self) -> u64 {
+        let p = self.prime();
+        if p <= 229 {
+            return self.count_points_naive();
+        }
+        let f = self.base_field();
+        let (lo, hi) = hasse_interval(p);
+        let twist = self.quadratic_twist();
+        let (mut r, mut m) = (0, 1);
+        for x in 0..p {
+            let x = f.new(x as i64);
+            for (curve, shift) in [(self, 0), (&twist, 2 * p as u128 + 2)] {
+                if let Some(pt) = curve.lift_x(&x) {
+                    let d = order_from_multiple(&pt, find_multiple(&pt, lo, hi)) as u128;
+                    (r, m) = crt(r, m, shift % d, d);
+                    let first = lo as u128 + (r + m - lo as u128 % m) % m;
+                    if first + m > hi as u128 {
+                        return first as u64;
+                    }
+                }
+            }
+        }
+        unreachable!("point count not determined")
+    }
+
+    /// Return the number of points by Schoof's algorithm, in time polynomial in
+    /// `log p`. Panics if `p` is 2 or 3.
+    ///
+    /// This is an alternative to `count_points` rather than a backend of it.
+    /// Like the rest of this module it is limited to primes that fit in a `u64`,
+    /// where it is slower than `count_points_bsgs`.
+    ///
+    /// The Frobenius endomorphism `π(x, y) = (x^p, y^p)` satisfies
+    /// `π^2 - t π + p = 0` with `#E(GF(p)) = p + 1 - t` and `|t| <= 2 sqrt(p)`.
+    /// For small primes `l` whose product exceeds `4 sqrt(p)`, `t mod l` is found
+    /// from the action of `π` on the `l`-torsion, computing in `GF(p)[x, y]`
+    /// modulo the curve equation and the division polynomial `ψ_l`, and `t` is
+    /// recovered by the Chinese remainder theorem.
+    pub fn count_points_schoof(&self) -> u64 {
+        let p = self.prime();
+        assert!(p > 3, "Schoof's algorithm needs characteristic at least 5");
+        let (a, b) = self.integer_coefficients();
+        let ar = PolyArith { p };
+        let rhs = vec![b, a, 0, 1];
+
+        // t is even exactly when there is a point of order two, that is when
+        // x^3 + a x + b has a root in GF(p).
+        let xp = ar.pow_mod(&[0, 1], p, &rhs);
+        let g = ar.gcd(&ar.sub(&xp, &[0, 1]), &rhs);
+        let (mut t, mut m) = (if g.len() > 1 { 0 } else { 1 }, 2);
+
+        let bound = 4 * (p as u128).isqrt() + 4;
+        let mut division = DivisionPolynomials::init(ar, a, b);
+        let mut l = 3;
+        while m <= bound {
+            if l != p && is_prime_u64(l) {
+                let tl = division.trace_mod(p, l);
+                (t, m) = crt(t, m, tl as u128, l as u128);
+            }
+            l += 2;
+        }
+        let t = if t > m / 2 { t as i128 - m as i128 } else { t as i128 };
+        (p as i128 + 1 - t) as u64
+    }
+
+    /// Return the invariants `(n1, n2)` with `n1 | n2` and `n1 | p - 1` such that
+    ///
+    /// ```notrust
+    /// E(GF(p)) ≅ Z/n1 × Z/n2
+    /// ```
+    ///
+    /// For each prime `l` with `l^2 | #E` and `l | p - 1` the `l`-part of the group
+    /// is `Z/l^a × Z/l^b`, and it is certified by finding points of orders `l^a`
+    /// and `l^b` generating subgroups with trivial intersection. The other primes
+    /// contribute only to `n2`.
+    pub fn group_structure(&self) -> (u64, u64) {
+        let p = self.prime();
+        let n = self.count_points();
+        let mut n1 = 1;
+        for (l, e) in factor_u64(n) {
+            if e >= 2 && (p - 1).is_multiple_of(l) {
+                n1 *= l.pow(self.sylow_rank_two_exponent(n, l, e));
+            }
+        }
+        (n1, n / n1)
+    }
+
+    /// Return `b` such that the Sylow `l`-subgroup, of order `l^e`, is
+    /// `Z/l^(e - b) × Z/l^b` with `b <= e - b`.
+    fn sylow_rank_two_exponent(&self, n: u64, l: u64, e: u32) -> u32 {
+        let f = self.base_field();
+        let p = self.prime();
+        let cofactor = n / l.pow(e);
+        let (mut p1, mut a) = (self.infinity(), 0);
+        // Two passes over the points, so that points seen before an element of
+        // maximal order was found can still complete a basis.
+        for x in (0..p).chain(0..p) {
+            let Some(pt) = self.lift_x(&f.new(x as i64)) else { continue };
+            let r = self.power(Additive, &pt, cofactor);
+            let k = prime_power_order(&r, l);
+            if k > a {
+                (p1, a) = (r.clone(), k);
+            }
+            if a == e {
+                return 0;
+            }
+            let b = e - a;
+            if b > a {
+                continue;
+            }
+            // Write r = y' P1 + c with c of order l^b, using l^b r = l^b y' P1.
+            let lb = l.pow(b);
+            let Some(y) = discrete_log_prime_power(&p1, &self.power(Additive, &r, lb), l, a) else { continue };
+            if !y.is_multiple_of(lb) {
+                continue;
+            }
+            let p2 = &r - &self.power(Additive, &p1, y / lb);
+            let t1 = self.power(Additive, &p1, l.pow(a - 1));
+            let t2 = self.power(Additive, &p2, l.pow(b - 1));
+            // ⟨P1⟩ ∩ ⟨P2⟩ is trivial exactly when the subgroups of order l differ,
+            // and then P1 and P2 generate a subgroup of order l^(a + b) = l^e.
+            if !t2.is_infinity() && discrete_log_prime(&t1, &t2, l).is_none() {
+                return b;
+            }
+        }
+        unreachable!("Sylow subgroup not determined")
+    }
+}
+
+/// The group order and the elements of a curve over a prime field.
+impl Finite for EllipticCurve<PrimeField> {
+    fn elements(&self) -> Vec<Point> {
+        let f = self.base_field();
+        let mut res = vec![self.infinity()];
+        for x in 0..self.prime() {
+            if let Some(pt) = self.lift_x(&f.new(x as i64)) {
+                if !pt.affine().unwrap().1.is_zero() {
+                    res.push(-&pt);
+                }
+                res.push(pt);
+            }
+        }
+        res
+    }
+
+    #[inline]
+    fn cardinality(&self) -> usize {
+        self.count_points() as usize
+    }
+}
+
+impl EllipticCurvePoint<PrimeField> {
+    /// Return the order of the point, a divisor of the number of points.
+    pub fn order(&self) -> u64 {
+        order_from_multiple(self, self.parent().count_points())
+    }
+}
+
+/// Dense polynomial arithmetic over `GF(p)` for Schoof's algorithm, with
+/// coefficients listed by increasing degree and no trailing zeros.
+#[derive(Clone, Copy)]
+struct PolyArith {
+    p: u64,
+}
+
+impl PolyArith {
+    fn trim(mut f: Vec<u64>) -> Vec<u64> {
+        while f.last() == Some(&0) {
+            f.pop();
+        }
+        f
+    }
+
+    fn add(&self, f: &[u64], g: &[u64]) -> Vec<u64> {
+        let n = f.len().max(g.len());
+        let c = |h: &[u64], i| h.get(i).copied().unwrap_or(0);
+        Self::trim((0..n).map(|i| (c(f, i) + c(g, i)) % self.p).collect())
+    }
+
+    fn neg(&self, f: &[u64]) -> Vec<u64> {
+        f.iter().map(|&c| (self.p - c) % self.p).collect()
+    }
+
+    fn sub(&self, f: &[u64], g: &[u64]) -> Vec<u64> {
+        self.add(f, &self.neg(g))
+    }
+
+    fn scale(&self, f: &[u64], c: u64) -> Vec<u64> {
+        Self::trim(f.iter().map(|&x| mul_mod_u64(x, c % self.p, self.p)).collect())
+    }
+
+    fn mul(&self, f: &[u64], g: &[u64]) -> Vec<u64> {
+        if f.is_empty() || g.is_empty() {
+            return vec![];
+        }
+        // Products are below 2^126, so sums are only reduced once they pass 2^127.
+        let mut res = vec![0u128; f.len() + g.len() - 1];
+        for (i, &x) in f.iter().enumerate() {
+            for (j, &y) in g.iter().enumerate() {
+                res[i + j] += x as u128 * y as u128;
+                if res[i + j] >> 127 != 0 {
+                    res[i + j] %= self.p as u128;
+                }
+            }
+        }
+        Self::trim(res.into_iter().map(|c| (c % self.p as u128) as u64).collect())
+    }
+
+    /// Return `f mod h` for a nonzero `h`.
+    fn rem(&self, f: &[u64], h: &[u64]) -> Vec<u64> {
+        let mut f = f.to_vec();
+        let dh = h.len() - 1;
+        let inv = pow_mod_u64(h[dh], self.p - 2, self.p);
+        while f.len() > dh {
+            let c = mul_mod_u64(f.pop().unwrap(), inv, self.p);
+            let k = f.len() - dh;
+            for (i, &y) in h[..dh].iter().enumerate() {
+                f[k + i] = (f[k + i] + self.p - mul_mod_u64(c, y, self.p)) % self.p;
+            }
+        }
+        Self::trim(f)
+    }
+
+    fn mul_mod(&self, f: &[u64], g: &[u64], h: &[u64]) -> Vec<u64> {
+        self.rem(&self.mul(f, g), h)
+    }
+
+    fn pow_mod(&self, f: &[u64], mut e: u64, h: &[u64]) -> Vec<u64> {
+        let mut res = self.rem(&[1], h);
+        let mut base = self.rem(f, h);
+        while e > 0 {
+            if e & 1 == 1 {
+                res = self.mul_mod(&res, &base, h);
+            }
+            e >>= 1;
+            if e > 0 {
+                base = self.mul_mod(&base, &base, h);
+            }
+        }
+        res
+    }
+
+    fn monic(&self, f: &[u64]) -> Vec<u64> {
+        self.scale(f, pow_mod_u64(*f.last().unwrap(), self.p - 2, self.p))
+    }
+
+    /// Return the monic greatest common divisor.
+    fn gcd(&self, f: &[u64], g: &[u64]) -> Vec<u64> {
+        let (mut f, mut g) = (f.to_vec(), g.to_vec());
+        while !g.is_empty() {
+            (f, g) = (g.clone(), self.rem(&f, &g));
+        }
+        self.monic(&f)
+    }
+
+    /// Return the inverse of `f` modulo `h`, or the monic `gcd(f, h)` if it is
+    /// not one.
+    fn inverse_mod(&self, f: &[u64], h: &[u64]) -> Result<Vec<u64>, Vec<u64>> {
+        let (mut r0, mut r1) = (h.to_vec(), self.rem(f, h));
+        let (mut s0, mut s1) = (vec![], vec![1]);
+        while !r1.is_empty() {
+            let (q, r) = self.div_rem(&r0, &r1);
+            (r0, r1) = (r1, r);
+            let s = self.sub(&s0, &self.mul(&q, &s1));
+            (s0, s1) = (s1, s);
+        }
+        if r0.len() == 1 {
+            Ok(self.rem(&self.scale(&s0, pow_mod_u64(r0[0], self.p - 2, self.p)), h))
+        } else {
+            Err(self.monic(&r0))
+        }
+    }
+
+    fn div_rem(&self, f: &[u64], g: &[u64]) -> (Vec<u64>, Vec<u64>) {
+        let mut r = f.to_vec();
+        let dg = g.len() - 1;
+        if r.len() <= dg {
+            return (vec![], r);
+        }
+        let inv = pow_mod_u64(g[dg], self.p - 2, self.p);
+        let mut q = vec![0; r.len() - dg];
+        while r.len() > dg {
+            let c = mul_mod_u64(r.pop().unwrap(), inv, self.p);
+            let k = r.len() - dg;
+            q[k] = c;
+            for (i, &y) in g[..dg].iter().enumerate() {
+                r[k + i] = (r[k + i] + self.p - mul_mod_u64(c, y, self.p)) % self.p;
+            }
+        }
+        (Self::trim(q), Self::trim(r))
+    }
+}
+
+/// The division polynomials of `y^2 = x^3 + a x + b`, as polynomials `f_n` in
+/// `x` with `ψ_n = f_n` for odd `n` and `ψ_n = y f_n` for even `n`.
+struct DivisionPolynomials {
+    ar: PolyArith,
+    a: u64,
+    rhs: Vec<u64>,
+    polys: Vec<Vec<u64>>,
+}
+
+/// A point `(X(x), y Y(x))` over `GF(p)[x]/(h)`, or `None` for the point at
+/// infinity.
+type TorsionPoint = Option<(Vec<u64>, Vec<u64>)>;
+
+impl DivisionPolynomials {
+    fn init(ar: PolyArith, a: u64, b: u64) -> Self {
+        let p = ar.p;
+        let m = |x: u64, y: u64| mul_mod_u64(x, y, p);
+        let (a2, ab, b2) = (m(a, a), m(a, b), m(b, b));
+        let f3 = vec![(p - a2) % p, m(12, b), m(6, a), 0, 3 % p];
+        let f4 = ar.scale(
+            &[(2 * p - m(8, b2) - m(a2, a)) % p, (p - m(4, ab)) % p, (p - m(5, a2)) % p, m(20, b), m(5, a), 0, 1],
+            4,
+        );
+        let polys = vec![vec![], vec![1], vec![2 % p], PolyArith::trim(f3), f4];
+        DivisionPolynomials { ar, a, rhs: vec![b, a, 0, 1], polys }
+    }
+
+    /// Return `f_n`, extending the table with the doubling recurrences.
+    fn get(&mut self, n: usize) -> &[u64] {
+        let ar = self.ar;
+        let inv2 = pow_mod_u64(2, ar.p - 2, ar.p);
+        let rhs2 = ar.mul(&self.rhs, &self.rhs);
+        while self.polys.len() <= n {
+            let k = self.polys.len();
+            let mm = k / 2;
+            let f = &self.polys;
+            let cube = |g: &[u64]| ar.mul(&ar.mul(g, g), g);
+            let next = if k % 2 == 1 {
+                let mut u = ar.mul(&f[mm + 2], &cube(&f[mm]));
+                let mut v = ar.mul(&f[mm - 1], &cube(&f[mm + 1]));
+                if mm.is_multiple_of(2) {
+                    u = ar.mul(&u, &rhs2);
+                } else {
+                    v = ar.mul(&v, &rhs2);
+                }
+                ar.sub(&u, &v)
+            } else {
+                let u = ar.mul(&f[mm + 2], &ar.mul(&f[mm - 1], &f[mm - 1]));
+                let v = ar.mul(&f[mm - 2], &ar.mul(&f[mm + 1], &f[mm + 1]));
+                ar.scale(&ar.mul(&f[mm], &ar.sub(&u, &v)), inv2)
+            };
+            self.polys.push(next);
+        }
+        &self.polys[n]
+    }
+
+    /// Return the trace of Frobenius modulo an odd prime `l` different from `p`.
+    fn trace_mod(&mut self, p: u64, l: u64) -> u64 {
+        let ar = self.ar;
+        let mut h = ar.monic(self.get(l as usize));
+        loop {
+            match self.trace_mod_factor(p, l, &h) {
+                Ok(t) => return t,
+                Err(g) => h = g,
+            }
+        }
+    }
+
+    /// Return `t mod l`, working on the `l`-torsion points whose `x` coordinates
+    /// are roots of `h`, or a proper factor of `h` met as a non-invertible
+    /// denominator. The relation `π^2 + p = t π` determines `t mod l` on any
+    /// nonzero subgroup stable under `π`.
+    fn trace_mod_factor(&self, p: u64, l: u64, h: &[u64]) -> Result<u64, Vec<u64>> {
+        let ar = self.ar;
+        let xp = ar.pow_mod(&[0, 1], p, h);
+        let yp = ar.pow_mod(&self.rhs, (p - 1) / 2, h);
+        let xp2 = ar.pow_mod(&xp, p, h);
+        let yp2 = ar.mul_mod(&ar.pow_mod(&yp, p, h), &yp, h);
+
+        let generic = Some((ar.rem(&[0, 1], h), ar.rem(&[1], h)));
+        let q = self.mul(&generic, p % l, h)?;
+        let s = self.add(&Some((xp2, yp2)), &q, h)?;
+        if s.is_none() {
+            return Ok(0);
+        }
+        let frobenius = Some((xp, yp));
+        let mut t = frobenius.clone();
+        for tau in 1..l {
+            if t == s {
+                return Ok(tau);
+            }
+            t = self.add(&t, &frobenius, h)?;
+        }
+        unreachable!("trace of Frobenius not found")
+    }
+
+    fn add(&self, u: &TorsionPoint, v: &TorsionPoint, h: &[u64]) -> Result<TorsionPoint, Vec<u64>> {
+        let ar = self.ar;
+        let ((x1, y1), (x2, y2)) = match (u, v) {
+            (None, _) => return Ok(v.clone()),
+            (_, None) => return Ok(u.clone()),
+            (Some(u), Some(v)) => (u, v),
+        };
+        if x1 == x2 {
+            if y1 == y2 {
+                return self.double(u, h);
+            }
+            if *y1 == ar.neg(y2) {
+                return Ok(None);
+            }
+            // The points are equal for some roots of h and opposite for others.
+            return Err(ar.gcd(&ar.sub(y1, y2), h));
+        }
+        let lambda = ar.mul_mod(&ar.sub(y2, y1), &ar.inverse_mod(&ar.sub(x2, x1), h)?, h);
+        Ok(Some(self.chord(&lambda, x1, y1, x2, h)))
+    }
+
+    fn double(&self, u: &TorsionPoint, h: &[u64]) -> Result<TorsionPoint, Vec<u64>> {
+        let ar = self.ar;
+        let Some((x, y)) = u else { return Ok(None) };
+        // The slope is (3 x^2 + a) / (2 y Y) = y (3 x^2 + a) / (2 (x^3 + a x + b) Y).
+        let num = ar.add(&ar.scale(&ar.mul(x, x), 3), &[self.a]);
+        let den = ar.scale(&ar.mul_mod(&self.rhs, y, h), 2);
+        let lambda = ar.mul_mod(&num, &ar.inverse_mod(&den, h)?, h);
+        Ok(Some(self.chord(&lambda, x, y, x, h)))
+    }
+
+    /// Return the third point on the line of slope `y λ` through `(x1, y y1)`
+    /// and `(x2, _)`, negated.
+    fn chord(&self, lambda: &[u64], x1: &[u64], y1: &[u64], x2: &[u64], h: &[u64]) -> (Vec<u64>, Vec<u64>) {
+        let ar = self.ar;
+        let l2 = ar.mul_mod(&ar.mul_mod(lambda, lambda, h), &self.rhs, h);
+        let x3 = ar.sub(&ar.sub(&l2, x1), x2);
+        let y3 = ar.sub(&ar.mul_mod(lambda, &ar.sub(x1, &x3), h), y1);
+        (ar.rem(&x3, h), ar.rem(&y3, h))
+    }
+
+    fn mul(&self, u: &TorsionPoint, k: u64, h: &[u64]) -> Result<TorsionPoint, Vec<u64>> {
+        let mut res = None;
+        for i in (0..64 - k.leading_zeros()).rev() {
+            res = self.double(&res, h)?;
+            if (k >> i) & 1 == 1 {
+                res = self.add(&res, u, h)?;
+            }
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,154 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+
+/// The quaternion group `Q_8 = {±1, ±i, ±j, ±k}` of order eight, with
+/// `i^2 = j^2 = k^2 = ijk = -1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct QuaternionGroup;
+
+/// An element `±u` of the `QuaternionGroup`, where `u` is one of `1, i, j, k`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct QuaternionUnit {
+    negative: bool,
+    unit: u8,
+}
+
+impl QuaternionGroup {
+    /// Initialize the quaternion group.
+    #[inline]
+    pub fn init() -> Self {
+        QuaternionGroup
+    }
+
+    /// Return the order `8`.
+    #[inline]
+    pub fn order(&self) -> u64 {
+        8
+    }
+
+    /// Return the generators `i` and `j`.
+    pub fn gens(&self) -> Vec<QuaternionUnit> {
+        vec![self.new("i"), self.new("j")]
+    }
+}
+
+impl QuaternionUnit {
+    /// Return `true` if the element is `-1`, `-i`, `-j` or `-k`.
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Return `0`, `1`, `2` or `3` if the element is `±1`, `±i`, `±j` or `±k`.
+    #[inline]
+    pub fn unit(&self) -> u8 {
+        self.unit
+    }
+
+    /// Return the order of the element.
+    pub fn order(&self) -> u64 {
+        match (self.negative, self.unit) {
+            (false, 0) => 1,
+            (true, 0) => 2,
+            _ => 4,
+        }
+    }
+}
+
+/// Elements are printed as `1`, `-1`, `i`, `-i` and so on.
+impl fmt::Display for QuaternionUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.negative { "-" } else { "" };
+        write!(f, "{}{}", sign, ["1", "i", "j", "k"][self.unit as usize])
+    }
+}
+
+impl Parent for QuaternionGroup {
+    type Element = QuaternionUnit;
+}
+
+impl Element for QuaternionUnit {
+    type Parent = QuaternionGroup;
+
+    #[inline]
+    fn parent(&self) -> QuaternionGroup {
+        QuaternionGroup
+    }
+}
+
+/// Parse one of `1, i, j, k`, optionally preceded by a sign. Panics if the
+/// string is malformed.
+impl NewElement<&str> for QuaternionGroup {
+    fn new(&self, src: &str) -> QuaternionUnit {
+        let s = src.trim();
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let unit = match s.trim() {
+            "1" => 0,
+            "i" => 1,
+            "j" => 2,
+            "k" => 3,
+            _ => panic!("malformed quaternion unit {:?}", src),
+        };
+        QuaternionUnit { negative, unit }
+    }
+}
+
+impl Finite for QuaternionGroup {
+    fn elements(&self) -> Vec<QuaternionUnit> {
+        [false, true].iter()
+            .flat_map(|&negative| (0..4).map(move |unit| QuaternionUnit { negative, unit }))
+            .collect()
+    }
+
+    #[inline]
+    fn cardinality(&self) -> usize {
+        8
+    }
+}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for QuaternionUnit {
+    /// Multiply using `i^2 = j^2 = k^2 = -1` and `ij = k`, `jk = i`, `ki = j`.
+    fn operate(&self, rhs: &Self) -> Self {
+        let (u, v) = (self.unit, rhs.unit);
+        let (negative, unit) = if u == 0 || v == 0 {
+            (false, u + v)
+        } else if u == v {
+            (true, 0)
+        } else {
+            ((v + 3 - u) % 3 != 1, 6 - u - v)
+        };
+        QuaternionUnit { negative: negative ^ self.negative ^ rhs.negative, unit }
+    }
+}
+
+impl Identity<Multiplicative> for QuaternionGroup {
+    #[inline]
+    fn identity(&self) -> QuaternionUnit {
+        QuaternionUnit { negative: false, unit: 0 }
+    }
+}
+
+impl IsIdentity<Multiplicative> for QuaternionUnit {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        !self.negative && self.unit == 0
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for QuaternionUnit {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        QuaternionUnit { negative: self.negative ^ (self.unit != 0), unit: self.unit }
+    }
+}
+
+impl Divisible<Multiplicative> for QuaternionGroup {}
+
+impl Associative<Multiplicative> for QuaternionGroup {}
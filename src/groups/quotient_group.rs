@@ -0,0 +1,259 @@
+use crate::*;
+use crate::ops::*;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// The quotient `G/N` of a finite group by a normal subgroup, whose elements
+/// are the cosets `xN = Nx`.
+///
+/// Each coset is represented by its first element in the enumeration of `G`,
+/// with the identity representing `N` itself. The table sending every
+/// element of `G` to its coset is computed on initialization, so the
+/// operation costs one product in `G` and one lookup.
+#[derive(Clone)]
+pub struct QuotientGroup<G: AbstractGroup<O> + Finite, O: Operator> {
+    normal: Subgroup<G, O>,
+    reps: Arc<Vec<Elem<G>>>,
+    cosets: Arc<HashMap<Elem<G>, usize>>,
+}
+
+/// An element of a `QuotientGroup`, a coset of the normal subgroup.
+#[derive(Clone)]
+pub struct Coset<G: AbstractGroup<O> + Finite, O: Operator> {
+    parent: QuotientGroup<G, O>,
+    index: usize,
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> QuotientGroup<G, O>
+where
+    Elem<G>: Eq + Hash,
+{
+    /// Initialize the quotient of a group by the normal subgroup `normal`.
+    /// Panics if the subgroup is not normal.
+    pub fn init(normal: &Subgroup<G, O>) -> Self {
+        assert!(normal.is_normal(), "quotient by a subgroup which is not normal");
+        let mut reps = vec![];
+        let mut cosets = HashMap::new();
+        for c in normal.left_cosets() {
+            for x in c.iter() {
+                cosets.insert(x.clone(), reps.len());
+            }
+            reps.push(c[0].clone());
+        }
+        QuotientGroup { normal: normal.clone(), reps: Arc::new(reps), cosets: Arc::new(cosets) }
+    }
+
+    /// Return a reference to the group being divided.
+    #[inline]
+    pub fn group(&self) -> &G {
+        self.normal.group()
+    }
+
+    /// Return a reference to the normal subgroup.
+    #[inline]
+    pub fn subgroup(&self) -> &Subgroup<G, O> {
+        &self.normal
+    }
+
+    /// Return the order `[G : N]`.
+    #[inline]
+    pub fn order(&self) -> usize {
+        self.reps.len()
+    }
+
+    /// Return the coset representatives, starting with the identity.
+    #[inline]
+    pub fn representatives(&self) -> &[Elem<G>] {
+        &self.reps
+    }
+
+    /// Return the image of `x` under the natural projection `G → G/N`.
+    pub fn project(&self, x: &Elem<G>) -> Coset<G, O> {
+        let index = *self.cosets.get(x).expect("element not in the group");
+        self.coset(index)
+    }
+
+    #[inline]
+    fn coset(&self, index: usize) -> Coset<G, O> {
+        Coset { parent: self.clone(), index }
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Coset<G, O>
+where
+    Elem<G>: Eq + Hash,
+{
+    /// Return the representative of the coset.
+    #[inline]
+    pub fn lift(&self) -> &Elem<G> {
+        &self.parent.reps[self.index]
+    }
+
+    /// Return the index of the coset in the list of representatives.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Return the elements of the coset.
+    pub fn elements(&self) -> Vec<Elem<G>> {
+        self.parent.normal.left_coset(self.lift())
+    }
+
+    /// Return `true` if the coset contains `x`.
+    pub fn contains(&self, x: &Elem<G>) -> bool {
+        self.parent.cosets.get(x) == Some(&self.index)
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> PartialEq for QuotientGroup<G, O>
+where
+    G: PartialEq,
+    Elem<G>: Eq + Hash,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.normal == other.normal
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> PartialEq for Coset<G, O> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Eq for Coset<G, O> {}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Hash for Coset<G, O> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> fmt::Debug for QuotientGroup<G, O>
+where
+    G: fmt::Debug,
+    Elem<G>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QuotientGroup")
+            .field("subgroup", &self.normal)
+            .field("representatives", &self.reps)
+            .finish()
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> fmt::Debug for Coset<G, O>
+where
+    Elem<G>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Coset({:?})", self.parent.reps[self.index])
+    }
+}
+
+/// Cosets are printed as their representative in brackets, for example
+/// `[(0,1)]`.
+impl<G: AbstractGroup<O> + Finite, O: Operator> fmt::Display for Coset<G, O>
+where
+    Elem<G>: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}]", self.parent.reps[self.index])
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Parent for QuotientGroup<G, O> {
+    type Element = Coset<G, O>;
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Element for Coset<G, O> {
+    type Parent = QuotientGroup<G, O>;
+
+    #[inline]
+    fn parent(&self) -> QuotientGroup<G, O> {
+        self.parent.clone()
+    }
+}
+
+/// Construct the coset of an element of the group.
+impl<G: AbstractGroup<O> + Finite, O: Operator> NewElement<Elem<G>> for QuotientGroup<G, O>
+where
+    Elem<G>: Eq + Hash,
+{
+    #[inline]
+    fn new(&self, src: Elem<G>) -> Coset<G, O> {
+        self.project(&src)
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Finite for QuotientGroup<G, O> {
+    fn elements(&self) -> Vec<Coset<G, O>> {
+        (0..self.reps.len()).map(|index| Coset { parent: self.clone(), index }).collect()
+    }
+
+    #[inline]
+    fn cardinality(&self) -> usize {
+        self.reps.len()
+    }
+}
+
+// Group properties
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Operation<O> for Coset<G, O>
+where
+    Elem<G>: Eq + Hash,
+{
+    /// Return `(xN)(yN) = (xy)N`.
+    fn operate(&self, rhs: &Self) -> Self {
+        self.parent.project(&Operation::<O>::operate(self.lift(), rhs.lift()))
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Identity<O> for QuotientGroup<G, O>
+where
+    Elem<G>: Eq + Hash,
+{
+    #[inline]
+    fn identity(&self) -> Coset<G, O> {
+        self.coset(0)
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> IsIdentity<O> for Coset<G, O>
+where
+    Elem<G>: Eq + Hash,
+{
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.index == 0
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> TwoSidedInverse<O> for Coset<G, O>
+where
+    Elem<G>: Eq + Hash,
+{
+    fn two_sided_inverse(&self) -> Self {
+        self.parent.project(&TwoSidedInverse::<O>::two_sided_inverse(self.lift()))
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Divisible<O> for QuotientGroup<G, O>
+where
+    Elem<G>: Eq + Hash,
+{}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Associative<O> for QuotientGroup<G, O>
+where
+    Elem<G>: Eq + Hash,
+{}
+
+impl<G: AbstractGroupAbelian<O> + Finite, O: Operator> Commutative<O> for QuotientGroup<G, O>
+where
+    Elem<G>: Eq + Hash,
+{}
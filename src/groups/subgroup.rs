@@ -0,0 +1,243 @@
+use crate::*;
+
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// The subgroup of a finite group generated by a list of elements, with
+/// respect to the operator `O`.
+///
+/// The elements are enumerated on initialization by closing the generators
+/// under the operation, so the subgroup must be finite.
+#[derive(Clone)]
+pub struct Subgroup<G: AbstractGroup<O>, O: Operator> {
+    group: G,
+    gens: Vec<Elem<G>>,
+    elements: Arc<Vec<Elem<G>>>,
+    members: Arc<HashSet<Elem<G>>>,
+    op: PhantomData<O>,
+}
+
+impl<G: AbstractGroup<O>, O: Operator> Subgroup<G, O>
+where
+    Elem<G>: Eq + Hash,
+{
+    /// Initialize the subgroup of `group` generated by `gens` under the
+    /// operator `O`.
+    pub fn init(_: O, group: &G, gens: &[Elem<G>]) -> Self {
+        let id = <G as Identity<O>>::identity(group);
+        let mut elements = vec![id.clone()];
+        let mut members: HashSet<Elem<G>> = [id].into_iter().collect();
+        let mut k = 0;
+        while k < elements.len() {
+            for s in gens.iter() {
+                let x = Operation::<O>::operate(&elements[k], s);
+                if members.insert(x.clone()) {
+                    elements.push(x);
+                }
+            }
+            k += 1;
+        }
+        Subgroup {
+            group: group.clone(),
+            gens: gens.to_vec(),
+            elements: Arc::new(elements),
+            members: Arc::new(members),
+            op: PhantomData,
+        }
+    }
+
+    /// Return the trivial subgroup.
+    pub fn trivial(op: O, group: &G) -> Self {
+        Subgroup::init(op, group, &[])
+    }
+
+    /// Return a reference to the group containing the subgroup.
+    #[inline]
+    pub fn group(&self) -> &G {
+        &self.group
+    }
+
+    /// Return the generators the subgroup was initialized with.
+    #[inline]
+    pub fn gens(&self) -> &[Elem<G>] {
+        &self.gens
+    }
+
+    /// Return the elements, in the order they were found starting from the
+    /// identity.
+    #[inline]
+    pub fn elements(&self) -> &[Elem<G>] {
+        &self.elements
+    }
+
+    /// Return the order of the subgroup.
+    #[inline]
+    pub fn order(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Return `true` if `x` is an element of the subgroup.
+    #[inline]
+    pub fn contains(&self, x: &Elem<G>) -> bool {
+        self.members.contains(x)
+    }
+
+    /// Return `true` if every element of the subgroup is contained in `other`.
+    pub fn is_subgroup_of(&self, other: &Self) -> bool {
+        self.gens.iter().all(|x| other.contains(x))
+    }
+
+    /// Return `true` if the subgroup is commutative.
+    pub fn is_abelian(&self) -> bool {
+        self.gens.iter().enumerate().all(|(i, x)| {
+            self.gens[..i].iter()
+                .all(|y| Operation::<O>::operate(x, y) == Operation::<O>::operate(y, x))
+        })
+    }
+
+    /// Return the conjugate subgroup `x^-1 H x`.
+    pub fn conjugate(&self, x: &Elem<G>) -> Self {
+        let gens: Vec<Elem<G>> = self.gens.iter().map(|s| self.conjugate_element(s, x)).collect();
+        Subgroup::init(O::operator_token(), &self.group, &gens)
+    }
+
+    /// Return the left coset `x H`, in the order of the elements of `H`.
+    pub fn left_coset(&self, x: &Elem<G>) -> Vec<Elem<G>> {
+        self.elements.iter().map(|h| Operation::<O>::operate(x, h)).collect()
+    }
+
+    /// Return the right coset `H x`, in the order of the elements of `H`.
+    pub fn right_coset(&self, x: &Elem<G>) -> Vec<Elem<G>> {
+        self.elements.iter().map(|h| Operation::<O>::operate(h, x)).collect()
+    }
+
+    /// Return `true` if `x` and `y` lie in the same left coset, that is
+    /// `x^-1 y` is in the subgroup.
+    pub fn same_left_coset(&self, x: &Elem<G>, y: &Elem<G>) -> bool {
+        self.contains(&Operation::<O>::operate(&TwoSidedInverse::<O>::two_sided_inverse(x), y))
+    }
+
+    /// Return `true` if `x` and `y` lie in the same right coset, that is
+    /// `x y^-1` is in the subgroup.
+    pub fn same_right_coset(&self, x: &Elem<G>, y: &Elem<G>) -> bool {
+        self.contains(&Operation::<O>::operate(x, &TwoSidedInverse::<O>::two_sided_inverse(y)))
+    }
+
+    fn conjugate_element(&self, s: &Elem<G>, x: &Elem<G>) -> Elem<G> {
+        let xinv = TwoSidedInverse::<O>::two_sided_inverse(x);
+        Operation::<O>::operate(&Operation::<O>::operate(&xinv, s), x)
+    }
+}
+
+impl<G: AbstractGroup<O> + Finite, O: Operator> Subgroup<G, O>
+where
+    Elem<G>: Eq + Hash,
+{
+    /// Return the whole group as a subgroup of itself.
+    pub fn whole(op: O, group: &G) -> Self {
+        Subgroup::init(op, group, &group.elements())
+    }
+
+    /// Return the index `[G : H]`.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.group.cardinality() / self.order()
+    }
+
+    /// Return the left cosets, starting with the subgroup itself, each with the
+    /// first element of the group it contains as representative.
+    pub fn left_cosets(&self) -> Vec<Vec<Elem<G>>> {
+        self.cosets(|x| self.left_coset(x))
+    }
+
+    /// Return the right cosets, starting with the subgroup itself, each with
+    /// the first element of the group it contains as representative.
+    pub fn right_cosets(&self) -> Vec<Vec<Elem<G>>> {
+        self.cosets(|x| self.right_coset(x))
+    }
+
+    /// Return representatives of the left cosets, starting with the identity.
+    pub fn left_transversal(&self) -> Vec<Elem<G>> {
+        self.left_cosets().into_iter().map(|c| c[0].clone()).collect()
+    }
+
+    /// Return representatives of the right cosets, starting with the identity.
+    pub fn right_transversal(&self) -> Vec<Elem<G>> {
+        self.right_cosets().into_iter().map(|c| c[0].clone()).collect()
+    }
+
+    /// Return `true` if the subgroup is normal, that is `x^-1 H x = H` for all
+    /// `x` in the group. It suffices to check the conjugates of the generators.
+    pub fn is_normal(&self) -> bool {
+        self.group.elements().iter().all(|x| {
+            self.gens.iter().all(|s| self.contains(&self.conjugate_element(s, x)))
+        })
+    }
+
+    /// Return the smallest normal subgroup containing the subgroup, generated
+    /// by all conjugates of its generators.
+    pub fn normal_closure(&self) -> Self {
+        let mut gens: Vec<Elem<G>> = vec![];
+        let mut seen = HashSet::new();
+        for x in self.group.elements().iter() {
+            for s in self.gens.iter() {
+                let c = self.conjugate_element(s, x);
+                if seen.insert(c.clone()) {
+                    gens.push(c);
+                }
+            }
+        }
+        Subgroup::init(O::operator_token(), &self.group, &gens)
+    }
+
+    /// Return the quotient group `G/H`. Panics if the subgroup is not normal.
+    pub fn quotient(&self) -> QuotientGroup<G, O> {
+        QuotientGroup::init(self)
+    }
+
+    fn cosets<F>(&self, coset: F) -> Vec<Vec<Elem<G>>>
+    where
+        F: Fn(&Elem<G>) -> Vec<Elem<G>>,
+    {
+        let mut seen = HashSet::new();
+        let mut res = vec![self.elements.to_vec()];
+        seen.extend(self.elements.iter().cloned());
+        for x in self.group.elements() {
+            if !seen.contains(&x) {
+                let mut c = coset(&x);
+                let i = c.iter().position(|y| *y == x).unwrap();
+                c.swap(0, i);
+                seen.extend(c.iter().cloned());
+                res.push(c);
+            }
+        }
+        res
+    }
+}
+
+impl<G: AbstractGroup<O>, O: Operator> PartialEq for Subgroup<G, O>
+where
+    G: PartialEq,
+    Elem<G>: Eq + Hash,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.group == other.group && self.members == other.members
+    }
+}
+
+impl<G: AbstractGroup<O>, O: Operator> fmt::Debug for Subgroup<G, O>
+where
+    G: fmt::Debug,
+    Elem<G>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Subgroup")
+            .field("group", &self.group)
+            .field("gens", &self.gens)
+            .field("order", &self.elements.len())
+            .finish()
+    }
+}
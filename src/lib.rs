@@ -30,6 +30,9 @@ extern crate approx;
 #[cfg(not(feature = "std"))]
 extern crate core as std;
 
+#[macro_use]
+mod macros;
+
 pub mod ops;
 
 #[cfg(feature = "structures")]
@@ -47,6 +50,26 @@ pub mod structures;
 #[cfg(feature = "structures")]
 pub use structures::*;
 
+#[cfg(feature = "structures")]
+pub mod rings;
+#[cfg(feature = "structures")]
+pub use rings::*;
+
+#[cfg(feature = "structures")]
+pub mod groups;
+#[cfg(feature = "structures")]
+pub use groups::*;
+
+#[cfg(feature = "structures")]
+pub mod morphism;
+#[cfg(feature = "structures")]
+pub use morphism::*;
+
+#[cfg(feature = "structures")]
+pub mod linalg;
+#[cfg(feature = "structures")]
+pub use linalg::*;
+
 #[doc(hidden)]
 #[cfg(feature = "structures")]
 pub mod wrapper;
@@ -0,0 +1,8 @@
+//! Concrete matrix spaces and linear algebra over them.
+
+pub use mat::*;
+pub(crate) use multimodular::*;
+
+mod mat;
+mod multimodular;
+mod normal_form;
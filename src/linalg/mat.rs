@@ -0,0 +1,760 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Dense matrices of a fixed shape over an arbitrary ring.
+///
+/// A matrix space is only an additive group, since matrices of most shapes
+/// cannot be multiplied with each other; use `mul_matrix` to multiply matrices
+/// of compatible shapes. The ring of square matrices is `MatrixAlgebra`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericMatrixSpace<T: Ring> {
+    ring: T,
+    nrows: usize,
+    ncols: usize,
+}
+
+/// An element of a `GenericMatrixSpace`, with entries stored in row-major order.
+#[derive(Clone)]
+pub struct GenericMatrix<T: Ring> {
+    parent: GenericMatrixSpace<T>,
+    entries: Vec<Elem<T>>,
+}
+
+impl<T: Ring> GenericMatrixSpace<T> {
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.nrows == self.ncols
+    }
+
+    /// Return the matrix with `x` on the diagonal and zeros elsewhere.
+    pub fn scalar(&self, x: &Elem<T>) -> GenericMatrix<T> {
+        let mut res = self.zero();
+        for i in 0..self.nrows.min(self.ncols) {
+            res.entries[i * self.ncols + i] = x.clone();
+        }
+        res
+    }
+}
+
+impl<T: Ring> GenericMatrix<T> {
+    /// Return a reference to the entries in row-major order.
+    #[inline]
+    pub fn entries(&self) -> &[Elem<T>] {
+        &self.entries
+    }
+
+    /// Return a reference to the entry in row `i` and column `j`.
+    ///
+    /// Panics if the indices are out of bounds.
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> &Elem<T> {
+        assert!(i < self.parent.nrows && j < self.parent.ncols, "index out of bounds");
+        &self.entries[i * self.parent.ncols + j]
+    }
+
+    /// Return the product of `self` and `rhs`, which must have as many rows as
+    /// `self` has columns.
+    pub fn mul_matrix(&self, rhs: &GenericMatrix<T>) -> GenericMatrix<T> {
+        let (m, n, p) = (self.parent.nrows, self.parent.ncols, rhs.parent.ncols);
+        assert_eq!(n, rhs.parent.nrows, "incompatible matrix dimensions");
+
+        let ring = &self.parent.ring;
+        let parent = GenericMatrixSpace::init(ring, m as u64, p as u64);
+        let mut entries = vec![ring.zero(); m * p];
+        for i in 0..m {
+            for k in 0..n {
+                let a = &self.entries[i * n + k];
+                if a.is_zero() {
+                    continue;
+                }
+                for j in 0..p {
+                    let mut t = a.clone();
+                    t *= &rhs.entries[k * p + j];
+                    entries[i * p + j] += &t;
+                }
+            }
+        }
+        GenericMatrix { parent, entries }
+    }
+
+    /// Return the transpose.
+    pub fn transpose(&self) -> GenericMatrix<T> {
+        let (m, n) = (self.parent.nrows, self.parent.ncols);
+        let parent = GenericMatrixSpace::init(&self.parent.ring, n as u64, m as u64);
+        let entries = (0..n * m).map(|k| self.entries[(k % m) * n + k / m].clone()).collect();
+        GenericMatrix { parent, entries }
+    }
+}
+
+impl<F: Field> GenericMatrix<F> {
+    /// Return the reduced row echelon form and its pivot columns, computed by
+    /// Gauss-Jordan elimination.
+    pub fn rref(&self) -> (GenericMatrix<F>, Vec<usize>) {
+        let mut res = self.clone();
+        let (m, n) = (self.parent.nrows, self.parent.ncols);
+        let (pivots, _) = rref_in_place(&self.parent.ring, &mut res.entries, m, n);
+        (res, pivots)
+    }
+
+    /// Return the determinant, the signed product of the pivots found by
+    /// Gauss-Jordan elimination. Panics if the matrix is not square.
+    pub fn det(&self) -> Elem<F> {
+        assert!(self.parent.is_square(), "matrix is not square");
+        let n = self.parent.nrows;
+        let field = &self.parent.ring;
+        let mut a = self.entries.clone();
+        match rref_in_place(field, &mut a, n, n) {
+            (pivots, det) if pivots.len() == n => det,
+            _ => field.zero(),
+        }
+    }
+
+    /// Return the inverse, computed by Gauss-Jordan elimination of `(A | I)`, or
+    /// `None` if the matrix is singular. Panics if the matrix is not square.
+    pub fn inverse(&self) -> Option<GenericMatrix<F>> {
+        assert!(self.parent.is_square(), "matrix is not square");
+        let n = self.parent.nrows;
+        let field = &self.parent.ring;
+        let mut a: Vec<_> = (0..n)
+            .flat_map(|i| {
+                let unit = (0..n).map(move |j| if i == j { field.one() } else { field.zero() });
+                self.entries[i * n..(i + 1) * n].iter().cloned().chain(unit)
+            })
+            .collect();
+        let (pivots, _) = rref_in_place(field, &mut a, n, 2 * n);
+        if pivots.len() < n || pivots.last().is_some_and(|&j| j >= n) {
+            return None;
+        }
+        let entries = (0..n).flat_map(|i| a[(2 * i + 1) * n..(2 * i + 2) * n].iter().cloned()).collect();
+        Some(GenericMatrix { parent: self.parent.clone(), entries })
+    }
+
+    /// Return the rank, the dimension of the row and of the column space.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.rref().1.len()
+    }
+
+    /// Return a solution `x` of `self * x = b`, or `None` if there is none. The
+    /// right hand side may have any number of columns, and each column of `x`
+    /// solves for the corresponding column of `b`, with free variables set to
+    /// zero. Panics if `b` does not have as many rows as `self`.
+    pub fn solve(&self, b: &GenericMatrix<F>) -> Option<GenericMatrix<F>> {
+        let (m, n, k) = (self.parent.nrows, self.parent.ncols, b.parent.ncols);
+        assert_eq!(m, b.parent.nrows, "incompatible matrix dimensions");
+        let field = &self.parent.ring;
+        let mut a: Vec<_> = (0..m)
+            .flat_map(|i| {
+                let row = self.entries[i * n..(i + 1) * n].iter();
+                row.chain(b.entries[i * k..(i + 1) * k].iter()).cloned()
+            })
+            .collect();
+        let (pivots, _) = rref_in_place(field, &mut a, m, n + k);
+        if pivots.last().is_some_and(|&j| j >= n) {
+            return None;
+        }
+        let mut x = vec![field.zero(); n * k];
+        for (r, &j) in pivots.iter().enumerate() {
+            x[j * k..(j + 1) * k].clone_from_slice(&a[r * (n + k) + n..(r + 1) * (n + k)]);
+        }
+        let parent = GenericMatrixSpace::init(field, n as u64, k as u64);
+        Some(GenericMatrix { parent, entries: x })
+    }
+
+    /// Return a matrix whose columns are a basis of the nullspace, the vectors `x`
+    /// with `self * x = 0`. It has one column for each non-pivot column of the
+    /// reduced row echelon form, where that column's variable is one.
+    pub fn nullspace(&self) -> GenericMatrix<F> {
+        let n = self.parent.ncols;
+        let field = &self.parent.ring;
+        let (r, pivots) = self.rref();
+        let free: Vec<_> = (0..n).filter(|j| !pivots.contains(j)).collect();
+        let k = free.len();
+        let mut x = vec![field.zero(); n * k];
+        for (c, &f) in free.iter().enumerate() {
+            x[f * k + c] = field.one();
+            for (i, &j) in pivots.iter().enumerate() {
+                let mut t = r.entries[i * n + f].clone();
+                t.neg_assign();
+                x[j * k + c] = t;
+            }
+        }
+        let parent = GenericMatrixSpace::init(field, n as u64, k as u64);
+        GenericMatrix { parent, entries: x }
+    }
+}
+
+/// Reduce the `m × n` matrix with row-major entries `a` to reduced row echelon
+/// form, returning the pivot columns and the product of the pivots, negated
+/// for each row swap. If the first `m` columns are pivots, this product is the
+/// determinant of the leading `m × m` block.
+fn rref_in_place<F: Field>(field: &F, a: &mut [<F as Field>::Element], m: usize, n: usize) -> (Vec<usize>, <F as Field>::Element) {
+    let mut pivots = vec![];
+    let mut det = field.one();
+    for j in 0..n {
+        let r = pivots.len();
+        if r == m {
+            break;
+        }
+        let p = match (r..m).find(|&i| !a[i * n + j].is_zero()) {
+            Some(p) => p,
+            None => continue,
+        };
+        if p != r {
+            for k in 0..n {
+                a.swap(p * n + k, r * n + k);
+            }
+            det.neg_assign();
+        }
+        let mut inv = a[r * n + j].clone();
+        det *= &inv;
+        inv.inv_assign();
+        for k in j..n {
+            a[r * n + k] *= &inv;
+        }
+        for i in 0..m {
+            if i == r || a[i * n + j].is_zero() {
+                continue;
+            }
+            let c = a[i * n + j].clone();
+            for k in j..n {
+                let mut t = a[r * n + k].clone();
+                t *= &c;
+                a[i * n + k] -= &t;
+            }
+        }
+        pivots.push(j);
+    }
+    (pivots, det)
+}
+
+/// Reduce the `m × n` matrix with row-major entries `a` to row echelon form by
+/// Bareiss' fraction-free elimination, returning the pivot columns and whether
+/// an odd number of rows were swapped.
+///
+/// Eliminating below the pivot `a_rj` replaces each entry by
+///
+/// ```notrust
+/// a_ik = (a_rj a_ik - a_ij a_rk) / p
+/// ```
+///
+/// where `p` is the previous pivot. The division is exact, and every entry is a
+/// minor of the original matrix, so the entries are bounded by Hadamard's bound
+/// and their size grows only linearly with the dimension. The products before
+/// the division are products of two minors, about the square of the final
+/// entries, and are computed with `EuclideanDomain::mul_sub_divexact` so that
+/// the integers can use 128-bit intermediates.
+fn bareiss_in_place<R: EuclideanDomain>(ring: &R, a: &mut [<R as Ring>::Element], m: usize, n: usize) -> (Vec<usize>, bool) {
+    let mut pivots = vec![];
+    let mut odd = false;
+    let mut prev = ring.one();
+    for j in 0..n {
+        let r = pivots.len();
+        if r == m {
+            break;
+        }
+        let p = match (r..m).find(|&i| !a[i * n + j].is_zero()) {
+            Some(p) => p,
+            None => continue,
+        };
+        if p != r {
+            for k in 0..n {
+                a.swap(p * n + k, r * n + k);
+            }
+            odd = !odd;
+        }
+        for i in r + 1..m {
+            for k in j + 1..n {
+                let (rj, ik, ij, rk) = (&a[r * n + j], &a[i * n + k], &a[i * n + j], &a[r * n + k]);
+                a[i * n + k] = ring.mul_sub_divexact(rj, ik, ij, rk, &prev);
+            }
+            a[i * n + j] = ring.zero();
+        }
+        prev = a[r * n + j].clone();
+        pivots.push(j);
+    }
+    (pivots, odd)
+}
+
+impl<R: EuclideanDomain> GenericMatrix<R> {
+    /// Return a row echelon form and its pivot columns, computed by Bareiss'
+    /// fraction-free elimination. The pivots are nonzero, and the rank over the
+    /// field of fractions is their number.
+    pub fn fraction_free_echelon(&self) -> (GenericMatrix<R>, Vec<usize>) {
+        let mut res = self.clone();
+        let (m, n) = (self.parent.nrows, self.parent.ncols);
+        let (pivots, _) = bareiss_in_place(&self.parent.ring, &mut res.entries, m, n);
+        (res, pivots)
+    }
+
+    /// Return the determinant, computed without division by Bareiss' algorithm.
+    /// Panics if the matrix is not square.
+    pub fn det_bareiss(&self) -> <R as Ring>::Element {
+        assert!(self.parent.is_square(), "matrix is not square");
+        let n = self.parent.nrows;
+        let ring = &self.parent.ring;
+        if n == 0 {
+            return ring.one();
+        }
+        let mut a = self.entries.clone();
+        let (pivots, odd) = bareiss_in_place(ring, &mut a, n, n);
+        if pivots.len() < n {
+            return ring.zero();
+        }
+        let mut det = a[n * n - 1].clone();
+        if odd {
+            det.neg_assign();
+        }
+        det
+    }
+
+    /// Return the rank over the field of fractions.
+    #[inline]
+    pub fn rank_bareiss(&self) -> usize {
+        self.fraction_free_echelon().1.len()
+    }
+
+    /// Return the inverse over the ring, or `None` if the determinant is not a
+    /// unit. Panics if the matrix is not square.
+    pub fn inverse_bareiss(&self) -> Option<GenericMatrix<R>> {
+        let ring = &self.parent.ring;
+        let (d, mut x) = self.scaled_inverse_bareiss(true)?;
+        let dinv = ring.divexact(&ring.one(), &d);
+        for e in x.entries.iter_mut() {
+            *e *= &dinv;
+        }
+        Some(x)
+    }
+
+    /// Return `d` and `d A^-1`, where `d = ±det(A)`, or `None` if the matrix is
+    /// singular or if `unit` is set and `d` is not a unit. Panics if the matrix
+    /// is not square.
+    ///
+    /// Fraction-free elimination of `(A | I)` followed by back substitution gives
+    /// `d A^-1`, where `d` is the last pivot, and this has entries in the ring.
+    /// The back substitution sums products of two minors, which must fit in the
+    /// ring's elements.
+    pub(crate) fn scaled_inverse_bareiss(&self, unit: bool) -> Option<(<R as Ring>::Element, GenericMatrix<R>)> {
+        assert!(self.parent.is_square(), "matrix is not square");
+        let n = self.parent.nrows;
+        let ring = &self.parent.ring;
+        if n == 0 {
+            return Some((ring.one(), self.clone()));
+        }
+        let w = 2 * n;
+        let mut a: Vec<_> = (0..n)
+            .flat_map(|i| {
+                let unit = (0..n).map(move |j| if i == j { ring.one() } else { ring.zero() });
+                self.entries[i * n..(i + 1) * n].iter().cloned().chain(unit)
+            })
+            .collect();
+        let (pivots, _) = bareiss_in_place(ring, &mut a, n, w);
+        if pivots.len() < n || pivots[n - 1] >= n {
+            return None;
+        }
+        let d = a[(n - 1) * w + n - 1].clone();
+        if unit && !ring.is_unit(&d) {
+            return None;
+        }
+        let mut x = vec![ring.zero(); n * n];
+        for c in 0..n {
+            for i in (0..n).rev() {
+                let mut t = d.clone();
+                t *= &a[i * w + n + c];
+                for k in i + 1..n {
+                    let mut u = a[i * w + k].clone();
+                    u *= &x[k * n + c];
+                    t -= &u;
+                }
+                x[i * n + c] = ring.divexact(&t, &a[i * w + i]);
+            }
+        }
+        Some((d, GenericMatrix { parent: self.parent.clone(), entries: x }))
+    }
+}
+
+impl<T: Ring> PartialEq for GenericMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.parent.nrows == other.parent.nrows
+            && self.parent.ncols == other.parent.ncols
+            && self.entries == other.entries
+    }
+}
+
+impl<T: Ring> Eq for GenericMatrix<T>
+where
+    <T as Ring>::Element: Eq,
+{}
+
+impl<T: Ring> Hash for GenericMatrix<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parent.nrows.hash(state);
+        self.parent.ncols.hash(state);
+        self.entries.hash(state);
+    }
+}
+
+impl<T: Ring> fmt::Debug for GenericMatrix<T>
+where
+    <T as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<_> = self.entries.chunks(self.parent.ncols.max(1)).collect();
+        f.debug_struct("GenericMatrix")
+            .field("nrows", &self.parent.nrows)
+            .field("ncols", &self.parent.ncols)
+            .field("rows", &rows)
+            .finish()
+    }
+}
+
+impl<T: Ring> Parent for GenericMatrixSpace<T> {
+    type Element = GenericMatrix<T>;
+}
+
+impl<T: Ring> Element for GenericMatrix<T> {
+    type Parent = GenericMatrixSpace<T>;
+
+    #[inline]
+    fn parent(&self) -> GenericMatrixSpace<T> {
+        self.parent.clone()
+    }
+}
+
+/// Construct a matrix from its entries in row-major order.
+impl<T: Ring> NewElement<Vec<Elem<T>>> for GenericMatrixSpace<T> {
+    fn new(&self, src: Vec<Elem<T>>) -> GenericMatrix<T> {
+        assert_eq!(src.len(), self.nrows * self.ncols, "wrong number of entries");
+        GenericMatrix { parent: self.clone(), entries: src }
+    }
+}
+
+// Additive properties
+
+impl<T: Ring> Operation<Additive> for GenericMatrix<T> {
+    fn operate(&self, rhs: &Self) -> Self {
+        assert_eq!(self.entries.len(), rhs.entries.len(), "incompatible matrix dimensions");
+        let mut res = self.clone();
+        for (a, b) in res.entries.iter_mut().zip(rhs.entries.iter()) {
+            *a += b;
+        }
+        res
+    }
+}
+
+impl<T: Ring> Identity<Additive> for GenericMatrixSpace<T> {
+    fn identity(&self) -> GenericMatrix<T> {
+        GenericMatrix {
+            parent: self.clone(),
+            entries: vec![self.ring.zero(); self.nrows * self.ncols],
+        }
+    }
+}
+
+impl<T: Ring> IsIdentity<Additive> for GenericMatrix<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.entries.iter().all(|x| x.is_zero())
+    }
+}
+
+impl<T: Ring> TwoSidedInverse<Additive> for GenericMatrix<T> {
+    fn two_sided_inverse(&self) -> Self {
+        let mut res = self.clone();
+        for a in res.entries.iter_mut() {
+            a.neg_assign();
+        }
+        res
+    }
+}
+
+impl<T: Ring> Divisible<Additive> for GenericMatrixSpace<T> {}
+
+impl<T: Ring> Associative<Additive> for GenericMatrixSpace<T> {}
+
+impl<T: Ring> Commutative<Additive> for GenericMatrixSpace<T> {}
+
+impl_additive_ops!([T: Ring] GenericMatrix<T>);
+
+impl<T: Ring> MatrixSpace<T> for GenericMatrixSpace<T> {
+    type Element = GenericMatrix<T>;
+
+    fn init<D: Into<u64>>(ring: &T, nrows: D, ncols: D) -> Self {
+        GenericMatrixSpace {
+            ring: ring.clone(),
+            nrows: nrows.into().try_into().expect("too many rows"),
+            ncols: ncols.into().try_into().expect("too many columns"),
+        }
+    }
+
+    #[inline]
+    fn base_ring(&self) -> &T {
+        &self.ring
+    }
+
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    #[inline]
+    fn is_generic(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Ring> MatrixSpaceElement<T> for GenericMatrix<T> {
+    type Parent = GenericMatrixSpace<T>;
+
+    #[inline]
+    fn base_ring(&self) -> &T {
+        &self.parent.ring
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.parent.nrows
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.parent.ncols
+    }
+
+    fn get_entry(&self, i: usize, j: usize) -> Option<Elem<T>> {
+        if i < self.parent.nrows && j < self.parent.ncols {
+            Some(self.entries[i * self.parent.ncols + j].clone())
+        } else {
+            None
+        }
+    }
+
+    fn set_entry(&mut self, i: usize, j: usize, entry: Elem<T>) -> Option<Elem<T>> {
+        if i < self.parent.nrows && j < self.parent.ncols {
+            let k = i * self.parent.ncols + j;
+            Some(std::mem::replace(&mut self.entries[k], entry))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn get_entries(&self) -> Vec<Elem<T>> {
+        self.entries.clone()
+    }
+
+    #[inline]
+    fn is_generic(&self) -> bool {
+        true
+    }
+}
+
+/// The ring of `n × n` matrices over a ring, a noncommutative algebra over the
+/// base ring via scalar matrices.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixAlgebra<T: Ring> {
+    space: GenericMatrixSpace<T>,
+}
+
+/// An element of a `MatrixAlgebra`.
+#[derive(Clone)]
+pub struct SquareMatrix<T: Ring> {
+    parent: MatrixAlgebra<T>,
+    mat: GenericMatrix<T>,
+}
+
+impl<T: Ring> MatrixAlgebra<T> {
+    /// Initialize the ring of `n × n` matrices over `ring`.
+    pub fn init(ring: &T, n: usize) -> Self {
+        MatrixAlgebra { space: GenericMatrixSpace::init(ring, n as u64, n as u64) }
+    }
+
+    /// Return a reference to the base ring.
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        &self.space.ring
+    }
+
+    /// Return the size `n` of the matrices.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.space.nrows
+    }
+
+    /// Return a reference to the space of all `n × n` matrices.
+    #[inline]
+    pub fn matrix_space(&self) -> &GenericMatrixSpace<T> {
+        &self.space
+    }
+
+    #[inline]
+    fn elem(&self, mat: GenericMatrix<T>) -> SquareMatrix<T> {
+        SquareMatrix { parent: self.clone(), mat }
+    }
+}
+
+impl<T: Ring> SquareMatrix<T> {
+    /// Return a reference to the matrix.
+    #[inline]
+    pub fn matrix(&self) -> &GenericMatrix<T> {
+        &self.mat
+    }
+
+    /// Return a reference to the entry in row `i` and column `j`.
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> &Elem<T> {
+        self.mat.entry(i, j)
+    }
+}
+
+impl<T: Ring> PartialEq for SquareMatrix<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.mat == other.mat
+    }
+}
+
+impl<T: Ring> Eq for SquareMatrix<T>
+where
+    <T as Ring>::Element: Eq,
+{}
+
+impl<T: Ring> Hash for SquareMatrix<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.mat.hash(state);
+    }
+}
+
+impl<T: Ring> fmt::Debug for SquareMatrix<T>
+where
+    <T as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<_> = self.mat.entries.chunks(self.parent.degree().max(1)).collect();
+        f.debug_tuple("SquareMatrix").field(&rows).finish()
+    }
+}
+
+impl<T: Ring> Parent for MatrixAlgebra<T> {
+    type Element = SquareMatrix<T>;
+}
+
+impl<T: Ring> Element for SquareMatrix<T> {
+    type Parent = MatrixAlgebra<T>;
+
+    #[inline]
+    fn parent(&self) -> MatrixAlgebra<T> {
+        self.parent.clone()
+    }
+}
+
+/// Construct an element from an `n × n` matrix. Panics if the shape differs.
+impl<T: Ring> NewElement<GenericMatrix<T>> for MatrixAlgebra<T> {
+    fn new(&self, src: GenericMatrix<T>) -> SquareMatrix<T> {
+        assert!(src.nrows() == self.degree() && src.ncols() == self.degree(), "wrong matrix dimensions");
+        self.elem(src)
+    }
+}
+
+/// Construct an element from its entries in row-major order.
+impl<T: Ring> NewElement<Vec<Elem<T>>> for MatrixAlgebra<T> {
+    #[inline]
+    fn new(&self, src: Vec<Elem<T>>) -> SquareMatrix<T> {
+        self.elem(self.space.new(src))
+    }
+}
+
+// Additive properties
+
+impl<T: Ring> Operation<Additive> for SquareMatrix<T> {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self.parent.elem(self.mat.operate(&rhs.mat))
+    }
+}
+
+impl<T: Ring> Identity<Additive> for MatrixAlgebra<T> {
+    #[inline]
+    fn identity(&self) -> SquareMatrix<T> {
+        self.elem(self.space.zero())
+    }
+}
+
+impl<T: Ring> IsIdentity<Additive> for SquareMatrix<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.mat.is_zero()
+    }
+}
+
+impl<T: Ring> TwoSidedInverse<Additive> for SquareMatrix<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.parent.elem(-&self.mat)
+    }
+}
+
+impl<T: Ring> Divisible<Additive> for MatrixAlgebra<T> {}
+
+impl<T: Ring> Associative<Additive> for MatrixAlgebra<T> {}
+
+impl<T: Ring> Commutative<Additive> for MatrixAlgebra<T> {}
+
+// Multiplicative properties
+
+impl<T: Ring> Operation<Multiplicative> for SquareMatrix<T> {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self.parent.elem(self.mat.mul_matrix(&rhs.mat))
+    }
+}
+
+impl<T: Ring> Identity<Multiplicative> for MatrixAlgebra<T> {
+    #[inline]
+    fn identity(&self) -> SquareMatrix<T> {
+        self.elem(self.space.scalar(&self.space.ring.one()))
+    }
+}
+
+impl<T: Ring> IsIdentity<Multiplicative> for SquareMatrix<T> {
+    fn is_identity(&self) -> bool {
+        let n = self.parent.degree();
+        self.mat.entries.iter().enumerate().all(|(k, x)| {
+            if k / n == k % n { x.is_one() } else { x.is_zero() }
+        })
+    }
+}
+
+impl<T: Ring> Associative<Multiplicative> for MatrixAlgebra<T> {}
+
+// Ring-like properties
+
+impl<T: Ring> Distributive for MatrixAlgebra<T> {}
+
+impl_ring_ops!([T: Ring] SquareMatrix<T>);
+
+impl<T: Ring> Algebra<T> for MatrixAlgebra<T> {
+    #[inline]
+    fn embed_base(&self, x: &Elem<T>) -> SquareMatrix<T> {
+        self.elem(self.space.scalar(x))
+    }
+}
@@ -0,0 +1,161 @@
+//! Multimodular linear algebra over the integers. Results are computed modulo
+//! two primes near `2^62` and recovered by the Chinese remainder theorem, so the
+//! intermediate entries never grow and only the result has to fit in an `i64`.
+
+use crate::*;
+
+/// Determinants are recovered when Hadamard's bound is below `2^MAX_BITS`, since
+/// twice the bound is then less than the product of two primes near `2^62`.
+const MAX_BITS: f64 = 122.0;
+
+/// Return the primes below `2^62` in decreasing order.
+fn primes() -> impl Iterator<Item = u64> {
+    (0..).map(|k| (1u64 << 62) - 1 - 2 * k).filter(|&p| is_prime_u64(p))
+}
+
+fn residues(a: &[i64], p: u64) -> Vec<u64> {
+    a.iter().map(|x| x.rem_euclid(p as i64) as u64).collect()
+}
+
+#[inline]
+fn sub_mod(a: u64, b: u64, p: u64) -> u64 {
+    if a >= b { a - b } else { a + (p - b) }
+}
+
+#[inline]
+fn inv_mod(a: u64, p: u64) -> u64 {
+    pow_mod_u64(a, p - 2, p)
+}
+
+/// Return the base 2 logarithm of the Euclidean norm of `v`.
+fn log2_norm(v: &[i64]) -> f64 {
+    0.5 * v.iter().map(|&x| x as f64 * x as f64).sum::<f64>().log2()
+}
+
+/// Return the integer of least absolute value congruent to `r` modulo `p` and
+/// to `s` modulo `q`.
+fn crt(r: u64, p: u64, s: u64, q: u64) -> i128 {
+    let t = mul_mod_u64(sub_mod(s, r % q, q), inv_mod(p % q, q), q);
+    let m = p as u128 * q as u128;
+    let x = r as u128 + p as u128 * t as u128;
+    if x > m / 2 { x as i128 - m as i128 } else { x as i128 }
+}
+
+/// Return the indices of the first rows of the `m × n` matrix `a` over `Z/p`
+/// which are independent of the rows before them, stopping at `n`.
+fn independent_rows(a: &[u64], m: usize, n: usize, p: u64) -> Vec<usize> {
+    // Each basis row is monic at its pivot and vanishes at the earlier pivots.
+    let mut basis: Vec<(usize, Vec<u64>)> = vec![];
+    let mut rows = vec![];
+    for i in 0..m {
+        if rows.len() == n {
+            break;
+        }
+        let mut v = a[i * n..(i + 1) * n].to_vec();
+        for (c, b) in &basis {
+            let f = v[*c];
+            if f != 0 {
+                for (x, y) in v.iter_mut().zip(b) {
+                    *x = sub_mod(*x, mul_mod_u64(f, *y, p), p);
+                }
+            }
+        }
+        if let Some(c) = v.iter().position(|&x| x != 0) {
+            let s = inv_mod(v[c], p);
+            for x in v.iter_mut() {
+                *x = mul_mod_u64(*x, s, p);
+            }
+            basis.push((c, v));
+            rows.push(i);
+        }
+    }
+    rows
+}
+
+/// Return the inverse and the determinant of the `n × n` matrix `a` over `Z/p`
+/// by Gauss-Jordan elimination, or `None` if it is singular.
+fn inverse_det_mod(mut a: Vec<u64>, n: usize, p: u64) -> Option<(Vec<u64>, u64)> {
+    let mut x: Vec<u64> = (0..n * n).map(|k| (k / n == k % n) as u64).collect();
+    let mut det = 1;
+    for j in 0..n {
+        let r = (j..n).find(|&i| a[i * n + j] != 0)?;
+        if r != j {
+            for k in 0..n {
+                a.swap(r * n + k, j * n + k);
+                x.swap(r * n + k, j * n + k);
+            }
+            det = p - det;
+        }
+        det = mul_mod_u64(det, a[j * n + j], p);
+        let s = inv_mod(a[j * n + j], p);
+        for k in 0..n {
+            a[j * n + k] = mul_mod_u64(a[j * n + k], s, p);
+            x[j * n + k] = mul_mod_u64(x[j * n + k], s, p);
+        }
+        for i in (0..n).filter(|&i| i != j) {
+            let f = a[i * n + j];
+            if f == 0 {
+                continue;
+            }
+            for k in 0..n {
+                a[i * n + k] = sub_mod(a[i * n + k], mul_mod_u64(f, a[j * n + k], p), p);
+                x[i * n + k] = sub_mod(x[i * n + k], mul_mod_u64(f, x[j * n + k], p), p);
+            }
+        }
+    }
+    Some((x, det))
+}
+
+/// Return the `k × n` product of `b` and the `n × n` matrix `a` over `Z/p`.
+fn mul_mod(b: &[u64], a: &[u64], n: usize, p: u64) -> Vec<u64> {
+    b.chunks(n)
+        .flat_map(|row| {
+            (0..n).map(move |j| {
+                let s: u128 = row.iter().enumerate().map(|(l, &x)| mul_mod_u64(x, a[l * n + j], p) as u128).sum();
+                (s % p as u128) as u64
+            })
+        })
+        .collect()
+}
+
+/// Return the determinant of a nonsingular matrix formed by `n` rows of the
+/// `m × n` matrix `a`, or `None` if no such rows are found modulo the primes,
+/// if Hadamard's bound for the rows exceeds `2^122` or if the determinant does
+/// not fit in an `i64`.
+///
+/// Rows independent modulo a prime are independent over the rationals, so a
+/// `Some` result is always correct.
+pub(crate) fn lattice_det_multimodular(a: &[i64], m: usize, n: usize) -> Option<i64> {
+    let primes: Vec<u64> = primes().take(2).collect();
+    let rows = primes.iter().map(|&p| independent_rows(&residues(a, p), m, n, p)).find(|rows| rows.len() == n)?;
+    let sub: Vec<i64> = rows.iter().flat_map(|&i| a[i * n..(i + 1) * n].iter().copied()).collect();
+    if sub.chunks(n).map(log2_norm).sum::<f64>() > MAX_BITS {
+        return None;
+    }
+    let dets: Vec<u64> = primes.iter().map(|&p| inverse_det_mod(residues(&sub, p), n, p).map_or(0, |(_, d)| d)).collect();
+    i64::try_from(crt(dets[0], primes[0], dets[1], primes[1])).ok()
+}
+
+/// Return the `k × n` matrix `X` with `X A = B` for the nonsingular `n × n`
+/// matrix `a` and the `k × n` matrix `b`, or `None` if its entries do not fit in
+/// an `i64`. Panics if `X` does not have integer entries.
+///
+/// An `X` which fits is recovered exactly from its residues, since the product
+/// of the primes exceeds `2^123`. Conversely the candidate is checked by
+/// computing `X A` with wrapping 128-bit arithmetic: it is then known modulo
+/// `2^128` and the two primes, which is more than enough to determine it.
+pub(crate) fn solve_left_multimodular(a: &[i64], b: &[i64], n: usize) -> Option<Vec<i64>> {
+    let mut inverses = primes().filter_map(|p| inverse_det_mod(residues(a, p), n, p).map(|(x, _)| (p, x)));
+    let (p, ap) = inverses.next()?;
+    let (q, aq) = inverses.next()?;
+    let (xp, xq) = (mul_mod(&residues(b, p), &ap, n, p), mul_mod(&residues(b, q), &aq, n, q));
+    let x: Vec<i64> = xp.iter().zip(&xq).map(|(&s, &t)| i64::try_from(crt(s, p, t, q)).ok()).collect::<Option<_>>()?;
+    let exact = x.chunks(n).zip(b.chunks(n)).all(|(row, rhs)| {
+        (0..n).all(|j| {
+            let dot = row.iter().enumerate().fold(0i128, |s, (l, &y)| s.wrapping_add(y as i128 * a[l * n + j] as i128));
+            dot == rhs[j] as i128
+        })
+    });
+    assert!(exact, "inexact division");
+    Some(x)
+}
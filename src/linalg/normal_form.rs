@@ -0,0 +1,523 @@
+use crate::*;
+use crate::ops::*;
+
+type Entries<R> = Vec<<R as Ring>::Element>;
+type Transforms<'a, R> = Option<(&'a mut [<R as Ring>::Element], &'a mut [<R as Ring>::Element])>;
+
+fn matrix<R: EuclideanDomain>(ring: &R, m: usize, n: usize, entries: Entries<R>) -> GenericMatrix<R> {
+    GenericMatrixSpace::init(ring, m as u64, n as u64).new(entries)
+}
+
+fn identity<R: EuclideanDomain>(ring: &R, n: usize) -> Entries<R> {
+    (0..n * n).map(|k| if k / n == k % n { ring.one() } else { ring.zero() }).collect()
+}
+
+/// Return the coefficients `[s, t, -b/g, a/g]` of the unimodular transformation
+/// taking `(a, b)` to `(g, 0)`, where `g = s a + t b` is the gcd. The second
+/// argument must be nonzero. If `a` divides `b`, `a` is kept as it is, so that
+/// elimination makes progress.
+fn gcd_step<R: EuclideanDomain>(ring: &R, a: &<R as Ring>::Element, b: &<R as Ring>::Element) -> [<R as Ring>::Element; 4] {
+    if ring.divides(a, b) {
+        let mut q = ring.divexact(b, a);
+        q.neg_assign();
+        return [ring.one(), ring.zero(), q, ring.one()];
+    }
+    let (g, s, t) = ring.xgcd(a, b);
+    let mut y = ring.divexact(b, &g);
+    y.neg_assign();
+    [s, t, y, ring.divexact(a, &g)]
+}
+
+/// Return `q` such that `a - q p` is the canonical remainder of `a` modulo `p`.
+fn reduction_quotient<R: EuclideanDomain>(ring: &R, a: &<R as Ring>::Element, p: &<R as Ring>::Element) -> <R as Ring>::Element {
+    let mut t = a.clone();
+    t -= &ring.reduce(a, p);
+    ring.divexact(&t, p)
+}
+
+/// Replace rows `r` and `i` of the matrix with `n` columns by `c0 r + c1 i` and
+/// `c2 r + c3 i`.
+fn row_op<E: RingOps + Clone>(a: &mut [E], n: usize, r: usize, i: usize, c: &[E; 4]) {
+    for k in 0..n {
+        let (x, y) = (a[r * n + k].clone(), a[i * n + k].clone());
+        a[r * n + k] = lin(&c[0], &x, &c[1], &y);
+        a[i * n + k] = lin(&c[2], &x, &c[3], &y);
+    }
+}
+
+/// Replace columns `j` and `l` of the `m × n` matrix by `c0 j + c1 l` and
+/// `c2 j + c3 l`.
+fn col_op<E: RingOps + Clone>(a: &mut [E], m: usize, n: usize, j: usize, l: usize, c: &[E; 4]) {
+    for k in 0..m {
+        let (x, y) = (a[k * n + j].clone(), a[k * n + l].clone());
+        a[k * n + j] = lin(&c[0], &x, &c[1], &y);
+        a[k * n + l] = lin(&c[2], &x, &c[3], &y);
+    }
+}
+
+/// Return `a x + b y`.
+fn lin<E: RingOps + Clone>(a: &E, x: &E, b: &E, y: &E) -> E {
+    let mut s = a.clone();
+    s *= x;
+    let mut t = b.clone();
+    t *= y;
+    s += &t;
+    s
+}
+
+/// Replace rows `r` and `i` by `c0 r + c1 i` and `c2 r + c3 i` in the columns
+/// from `j` on, reducing modulo `d`. Products are only formed by
+/// `EuclideanDomain::mul_mod`, so entries below `d` never overflow.
+#[allow(clippy::too_many_arguments)]
+fn row_op_mod<R: EuclideanDomain>(ring: &R, a: &mut [<R as Ring>::Element], n: usize, j: usize, r: usize, i: usize, c: &[<R as Ring>::Element; 4], d: &<R as Ring>::Element) {
+    for k in j..n {
+        let (x, y) = (a[r * n + k].clone(), a[i * n + k].clone());
+        a[r * n + k] = lin_mod(ring, &c[0], &x, &c[1], &y, d);
+        a[i * n + k] = lin_mod(ring, &c[2], &x, &c[3], &y, d);
+    }
+}
+
+/// Replace columns `j` and `l` of the `m × n` matrix by `c0 j + c1 l` and
+/// `c2 j + c3 l`, reducing modulo `d`.
+#[allow(clippy::too_many_arguments)]
+fn col_op_mod<R: EuclideanDomain>(ring: &R, a: &mut [<R as Ring>::Element], m: usize, n: usize, j: usize, l: usize, c: &[<R as Ring>::Element; 4], d: &<R as Ring>::Element) {
+    for k in 0..m {
+        let (x, y) = (a[k * n + j].clone(), a[k * n + l].clone());
+        a[k * n + j] = lin_mod(ring, &c[0], &x, &c[1], &y, d);
+        a[k * n + l] = lin_mod(ring, &c[2], &x, &c[3], &y, d);
+    }
+}
+
+/// Return `a x + b y` modulo `d`.
+fn lin_mod<R: EuclideanDomain>(ring: &R, a: &<R as Ring>::Element, x: &<R as Ring>::Element, b: &<R as Ring>::Element, y: &<R as Ring>::Element, d: &<R as Ring>::Element) -> <R as Ring>::Element {
+    ring.add_mod(&ring.mul_mod(a, x, d), &ring.mul_mod(b, y, d), d)
+}
+
+/// Subtract `q` times row `r` from row `i` in the columns from `j` on, reducing
+/// modulo `d`.
+#[allow(clippy::too_many_arguments)]
+fn row_sub_mod<R: EuclideanDomain>(ring: &R, a: &mut [<R as Ring>::Element], n: usize, j: usize, i: usize, r: usize, q: &<R as Ring>::Element, d: &<R as Ring>::Element) {
+    for k in j..n {
+        let mut t = ring.mul_mod(q, &a[r * n + k], d);
+        t.neg_assign();
+        a[i * n + k] = ring.add_mod(&a[i * n + k], &t, d);
+    }
+}
+
+/// Subtract `q` times row `r` from row `i`.
+fn row_sub<E: RingOps + Clone>(a: &mut [E], n: usize, i: usize, r: usize, q: &E) {
+    for k in 0..n {
+        let mut t = a[r * n + k].clone();
+        t *= q;
+        a[i * n + k] -= &t;
+    }
+}
+
+fn scale_row<E: RingOps + Clone>(a: &mut [E], n: usize, r: usize, u: &E) {
+    for x in a[r * n..(r + 1) * n].iter_mut() {
+        *x *= u;
+    }
+}
+
+fn swap_rows<E>(a: &mut [E], n: usize, r: usize, i: usize) {
+    for k in 0..n {
+        a.swap(r * n + k, i * n + k);
+    }
+}
+
+fn swap_cols<E>(a: &mut [E], m: usize, n: usize, j: usize, l: usize) {
+    for k in 0..m {
+        a.swap(k * n + j, k * n + l);
+    }
+}
+
+/// Reduce the `m × n` matrix `a` to Hermite normal form by unimodular row
+/// operations, which are also applied to the `m × m` matrix `u` if given.
+/// Entries above each pivot are reduced as soon as the pivot is found, which
+/// keeps them bounded.
+fn hermite_in_place<R: EuclideanDomain>(ring: &R, a: &mut [<R as Ring>::Element], m: usize, n: usize, mut u: Option<&mut [<R as Ring>::Element]>) {
+    let mut r = 0;
+    for j in 0..n {
+        if r == m {
+            break;
+        }
+        for i in r + 1..m {
+            if a[i * n + j].is_zero() {
+                continue;
+            }
+            let c = gcd_step(ring, &a[r * n + j], &a[i * n + j]);
+            row_op(a, n, r, i, &c);
+            if let Some(u) = u.as_deref_mut() {
+                row_op(u, m, r, i, &c);
+            }
+        }
+        if a[r * n + j].is_zero() {
+            continue;
+        }
+        let unit = ring.canonical_unit(&a[r * n + j]);
+        scale_row(a, n, r, &unit);
+        if let Some(u) = u.as_deref_mut() {
+            scale_row(u, m, r, &unit);
+        }
+        for i in 0..r {
+            let q = reduction_quotient(ring, &a[i * n + j], &a[r * n + j]);
+            row_sub(a, n, i, r, &q);
+            if let Some(u) = u.as_deref_mut() {
+                row_sub(u, m, i, r, &q);
+            }
+        }
+        r += 1;
+    }
+}
+
+/// Return the Hermite normal form of the `m × n` matrix `a` of rank `n`, whose
+/// rows generate a lattice `L` of full rank, given a nonzero multiple `d` of its
+/// determinant.
+///
+/// Since `L` contains `d R^n`, rows may be reduced modulo `d`. Working through
+/// the columns, the sublattice `L_j` of vectors vanishing in the first `j`
+/// coordinates contains `d_j R^(n - j)` with `d_j = d / (h_0 ... h_(j-1))`, so
+/// the working rows are reduced modulo `d_j`, and the pivot `h_j` is the gcd of
+/// `d_j` with the gcd of column `j`.
+fn hermite_modular<R: EuclideanDomain>(ring: &R, a: &mut [<R as Ring>::Element], m: usize, n: usize, d: &<R as Ring>::Element) {
+    let mut dj = d.clone();
+    for x in a.iter_mut() {
+        *x = ring.reduce(x, &dj);
+    }
+    for j in 0..n {
+        for i in j + 1..m {
+            if a[i * n + j].is_zero() {
+                continue;
+            }
+            let c = gcd_step(ring, &a[j * n + j], &a[i * n + j]);
+            row_op_mod(ring, a, n, j, j, i, &c, &dj);
+        }
+        let (h, s, _) = ring.xgcd(&a[j * n + j], &dj);
+        for k in j + 1..n {
+            a[j * n + k] = ring.mul_mod(&a[j * n + k], &s, &dj);
+        }
+        a[j * n + j] = h.clone();
+        dj = ring.divexact(&dj, &h);
+        for x in a[(j + 1) * n..].iter_mut() {
+            *x = ring.reduce(x, &dj);
+        }
+    }
+    // Reducing the later entries of a row modulo d adds a vector of L, and the
+    // result is still the unique Hermite basis once they are reduced in turn.
+    for j in 0..n {
+        for i in 0..j {
+            let q = reduction_quotient(ring, &a[i * n + j], &a[j * n + j]);
+            row_sub_mod(ring, a, n, j, i, j, &q, d);
+        }
+    }
+}
+
+/// Return the Hermite normal form of the `m × n` matrix `a` computed modulo its
+/// determinant, if `a` is square and nonsingular.
+fn hermite_nonsingular<R: EuclideanDomain>(ring: &R, a: &[<R as Ring>::Element], m: usize, n: usize) -> Option<Entries<R>> {
+    if m != n || n == 0 {
+        return None;
+    }
+    let d = ring.lattice_det(a, n, n)?;
+    let mut h = a.to_vec();
+    hermite_modular(ring, &mut h, n, n, &d);
+    Some(h)
+}
+
+/// Return the Smith normal form `S` of the square matrix `a` and transforms with
+/// `U A V = S`, if `a` is nonsingular, every invariant factor but the last is
+/// one, and the transforms can be computed.
+///
+/// The kernel of `A` modulo the last invariant factor `d` is cyclic, and its
+/// generators are the vectors whose entries are coprime to `d`. One is found as
+/// a combination of the columns of `d A^-1`, which span the kernel. Adding small
+/// multiples of its other coordinates to the first, a unimodular change of
+/// coordinates `T`, makes the first a unit modulo `d`, and scaling gives a
+/// generator `z` with `z_0 = 1`. Then `V = T^-1 (e_1, ..., e_(n-1), z)` is
+/// unimodular and `A` maps its last column into `d R^n`, so `A V S^-1` is
+/// unimodular and `U` is its inverse, recovered exactly as `S V^-1 A^-1`.
+fn smith_cyclic<R: EuclideanDomain>(a: &GenericMatrix<R>) -> Option<(GenericMatrix<R>, GenericMatrix<R>, GenericMatrix<R>)> {
+    let (m, n) = (a.nrows(), a.ncols());
+    let ring = a.base_ring();
+    if m != n || n == 0 {
+        return None;
+    }
+    let s = a.smith_form();
+    let d = s.entry(n - 1, n - 1).clone();
+    if d.is_zero() || !(0..n - 1).all(|i| s.entry(i, i).is_one()) {
+        return None;
+    }
+    let mut v = identity(ring, n);
+    if !ring.is_unit(&d) {
+        let scalar: Entries<R> = (0..n * n).map(|k| if k / n == k % n { d.clone() } else { ring.zero() }).collect();
+        let x = ring.solve_left_exact(a.entries(), &scalar, n)?;
+        let column = |c: usize| -> Entries<R> { (0..n).map(|r| x[r * n + c].clone()).collect() };
+        let mut y = column(0);
+        for c in 1..n {
+            y = stabilize(ring, &y, &column(c), &d)?.1;
+        }
+        let mut t = vec![ring.zero(); n];
+        for k in 1..n {
+            let (tk, y0) = stabilize(ring, &y[..1], &y[k..k + 1], &d)?;
+            y[0] = y0[0].clone();
+            t[k] = tk;
+        }
+        let w = ring.inverse_mod(&y[0], &d)?;
+        let z: Entries<R> = y.iter().map(|x| ring.mul_mod(x, &w, &d)).collect();
+        // The columns of V are e_k - t_k e_0, then z with z_0 - sum t_k z_k as
+        // its first entry.
+        let mut z0 = z[0].clone();
+        v = vec![ring.zero(); n * n];
+        for k in 1..n {
+            let mut u = t[k].clone();
+            u *= &z[k];
+            z0 -= &u;
+            let mut u = t[k].clone();
+            u.neg_assign();
+            v[k - 1] = u;
+            v[k * n + k - 1] = ring.one();
+            v[k * n + n - 1] = z[k].clone();
+        }
+        v[n - 1] = z0;
+    }
+    // U = S V^-1 A^-1, where S V^-1 has entries of the size of those of V.
+    let mut b = ring.solve_left_exact(&v, &identity(ring, n), n)?;
+    for (k, x) in b.iter_mut().enumerate() {
+        *x *= s.entry(k / n, k / n);
+    }
+    let u = ring.solve_left_exact(a.entries(), &b, n)?;
+    Some((s, matrix(ring, n, n, u), matrix(ring, n, n, v)))
+}
+
+/// Return `t` and `y + t x`, reduced modulo `d`, for the first `t = 0, 1, 2, ...`
+/// such that the gcd of `d` with the entries is the gcd of `d` with those of `x`
+/// and `y`, or `None` if none of the first few works. Each prime factor of `d`
+/// excludes at most one residue of `t`, so over the integers a small `t` exists.
+fn stabilize<R: EuclideanDomain>(ring: &R, y: &[<R as Ring>::Element], x: &[<R as Ring>::Element], d: &<R as Ring>::Element) -> Option<(<R as Ring>::Element, Entries<R>)> {
+    let content = |v: &[<R as Ring>::Element]| v.iter().fold(d.clone(), |g, e| ring.gcd(&g, e));
+    let target = ring.gcd(&content(y), &content(x));
+    let mut t = ring.zero();
+    for _ in 0..256 {
+        let z: Entries<R> = y.iter().zip(x).map(|(a, b)| ring.add_mod(a, &ring.mul_mod(&t, b, d), d)).collect();
+        if content(&z) == target {
+            return Some((t, z));
+        }
+        t += &ring.one();
+    }
+    None
+}
+
+/// Reduce the `m × n` matrix `a` to Smith normal form by unimodular row and
+/// column operations, which are also applied to `u` and `v` if given, and
+/// return the diagonal.
+///
+/// If `d` is given it must be a nonzero multiple of every invariant factor, and
+/// entries are kept reduced modulo `d` with `EuclideanDomain::mul_mod` and
+/// `add_mod`, so that they stay below `d`. This computes the Smith form over `R/(d)`,
+/// whose diagonal entries have gcds with `d` equal to the invariant factors.
+fn smith_in_place<R: EuclideanDomain>(
+    ring: &R,
+    a: &mut [<R as Ring>::Element],
+    m: usize,
+    n: usize,
+    mut uv: Transforms<R>,
+    d: Option<&<R as Ring>::Element>,
+) -> Entries<R> {
+    let reduce = |a: &mut [<R as Ring>::Element]| {
+        if let Some(d) = d {
+            for x in a.iter_mut() {
+                *x = ring.reduce(x, d);
+            }
+        }
+    };
+    let row = |a: &mut [<R as Ring>::Element], r: usize, i: usize, c: &[<R as Ring>::Element; 4]| match d {
+        Some(d) => row_op_mod(ring, a, n, 0, r, i, c, d),
+        None => row_op(a, n, r, i, c),
+    };
+    let col = |a: &mut [<R as Ring>::Element], j: usize, l: usize, c: &[<R as Ring>::Element; 4]| match d {
+        Some(d) => col_op_mod(ring, a, m, n, j, l, c, d),
+        None => col_op(a, m, n, j, l, c),
+    };
+    reduce(a);
+    let mut diag = vec![];
+    for t in 0..m.min(n) {
+        loop {
+            if a[t * n + t].is_zero() {
+                let pos = (t..m).flat_map(|i| (t..n).map(move |j| (i, j))).find(|&(i, j)| !a[i * n + j].is_zero());
+                let Some((i, j)) = pos else { break };
+                swap_rows(a, n, t, i);
+                swap_cols(a, m, n, t, j);
+                if let Some((u, v)) = uv.as_mut() {
+                    swap_rows(u, m, t, i);
+                    swap_cols(v, n, n, t, j);
+                }
+            }
+            for i in t + 1..m {
+                if !a[i * n + t].is_zero() {
+                    let c = gcd_step(ring, &a[t * n + t], &a[i * n + t]);
+                    row(a, t, i, &c);
+                    if let Some((u, _)) = uv.as_mut() {
+                        row_op(u, m, t, i, &c);
+                    }
+                }
+            }
+            for j in t + 1..n {
+                if !a[t * n + j].is_zero() {
+                    let c = gcd_step(ring, &a[t * n + t], &a[t * n + j]);
+                    col(a, t, j, &c);
+                    if let Some((_, v)) = uv.as_mut() {
+                        col_op(v, n, n, t, j, &c);
+                    }
+                }
+            }
+            reduce(a);
+            if (t + 1..m).any(|i| !a[i * n + t].is_zero()) {
+                continue;
+            }
+            // The pivot must divide the rest of the matrix, or the row holding a
+            // multiple it does not divide is added to the pivot row.
+            let p = &a[t * n + t];
+            let bad = (t + 1..m).find(|&i| (t + 1..n).any(|j| !ring.divides(p, &a[i * n + j])));
+            match bad {
+                Some(i) => {
+                    let one = [ring.one(), ring.one(), ring.zero(), ring.one()];
+                    row(a, t, i, &one);
+                    if let Some((u, _)) = uv.as_mut() {
+                        row_op(u, m, t, i, &one);
+                    }
+                }
+                None => break,
+            }
+        }
+        let p = &a[t * n + t];
+        if p.is_zero() {
+            break;
+        }
+        let unit = ring.canonical_unit(p);
+        scale_row(a, n, t, &unit);
+        if let Some((u, _)) = uv.as_mut() {
+            scale_row(u, m, t, &unit);
+        }
+        diag.push(a[t * n + t].clone());
+    }
+    if let Some(d) = d {
+        // Entries divisible by d, including zeros, stand for d itself.
+        let mut res: Entries<R> = diag.iter().map(|x| ring.gcd(x, d)).collect();
+        res.resize(m.min(n), ring.normalize(d));
+        return res;
+    }
+    diag
+}
+
+/// Hermite and Smith normal forms over Euclidean domains.
+impl<R: EuclideanDomain> GenericMatrix<R> {
+    /// Return the Hermite normal form `H`, the unique matrix in row echelon form
+    /// whose rows span the same module as those of `self`, with canonical pivots
+    /// and entries above each pivot reduced modulo it. Over the integers the
+    /// pivots are positive and the entries above them lie in `[0, pivot)`.
+    ///
+    /// If the matrix has full column rank, the computation is done modulo the
+    /// determinant of a maximal nonsingular set of rows, given by
+    /// `EuclideanDomain::lattice_det`, which bounds every intermediate entry by
+    /// the determinant. Over the integers this determinant is computed by a
+    /// multimodular method, so only it has to fit in an `i64`.
+    pub fn hermite_form(&self) -> GenericMatrix<R> {
+        let (m, n) = (self.nrows(), self.ncols());
+        let ring = self.base_ring();
+        let mut a = self.entries().to_vec();
+        match ring.lattice_det(&a, m, n).filter(|_| n > 0) {
+            Some(d) => hermite_modular(ring, &mut a, m, n, &d),
+            None => hermite_in_place(ring, &mut a, m, n, None),
+        }
+        matrix(ring, m, n, a)
+    }
+
+    /// Return the Hermite normal form `H` and a unimodular matrix `U`, invertible
+    /// over the ring, with `U * self = H`.
+    ///
+    /// If the matrix is square and nonsingular, `H` is computed modulo the
+    /// determinant as in `hermite_form`, and `U = H A^-1` is the unique solution,
+    /// recovered exactly by `EuclideanDomain::solve_left_exact`. Its entries are
+    /// bounded by Cramer's rule, and over the integers it is computed by a
+    /// multimodular method. Otherwise the elimination is unbounded: intermediate
+    /// entries and the entries of `U` may grow exponentially with the dimension,
+    /// and for `i64` entries they can overflow.
+    pub fn hermite_form_with_transform(&self) -> (GenericMatrix<R>, GenericMatrix<R>) {
+        let (m, n) = (self.nrows(), self.ncols());
+        let ring = self.base_ring();
+        if let Some(h) = hermite_nonsingular(ring, self.entries(), m, n) {
+            if let Some(u) = ring.solve_left_exact(self.entries(), &h, n) {
+                return (matrix(ring, n, n, h), matrix(ring, n, n, u));
+            }
+        }
+        let mut a = self.entries().to_vec();
+        let mut u = identity(ring, m);
+        hermite_in_place(ring, &mut a, m, n, Some(&mut u));
+        (matrix(ring, m, n, a), matrix(ring, m, m, u))
+    }
+
+    /// Return the Smith normal form `S`, the diagonal matrix of invariant factors
+    /// `d_1 | d_2 | ... | d_r` followed by zeros, equivalent to `self` under
+    /// unimodular row and column operations.
+    ///
+    /// The nonzero rows of the Hermite normal form are transposed and brought
+    /// to Hermite normal form again, which has full column rank and so uses the
+    /// modular method. The resulting nonsingular triangular matrix of determinant
+    /// `d` is diagonalized modulo `d`.
+    pub fn smith_form(&self) -> GenericMatrix<R> {
+        let (m, n) = (self.nrows(), self.ncols());
+        let ring = self.base_ring();
+        let h = self.hermite_form();
+        let r = (0..m).take_while(|&i| (0..n).any(|j| !h.entry(i, j).is_zero())).count();
+        let mut s = vec![ring.zero(); m * n];
+        if r > 0 {
+            let top = matrix(ring, r, n, h.entries()[..r * n].to_vec());
+            let t = top.transpose().hermite_form();
+            let mut a = t.entries()[..r * r].to_vec();
+            let mut d = ring.one();
+            for i in 0..r {
+                d *= &a[i * r + i];
+            }
+            for (i, x) in smith_in_place(ring, &mut a, r, r, None, Some(&d)).into_iter().enumerate() {
+                s[i * n + i] = x;
+            }
+        }
+        matrix(ring, m, n, s)
+    }
+
+    /// Return the Smith normal form `S` and unimodular matrices `U` and `V` with
+    /// `U * self * V = S`.
+    ///
+    /// If the matrix is square and nonsingular and all invariant factors but
+    /// the last are one, which is the typical case, `S` is computed as in
+    /// `smith_form`. Then `V` is built from a generator of the kernel of `A`
+    /// modulo the last invariant factor, whose entries are bounded by it, and
+    /// `U = S V^-1 A^-1` is recovered exactly as in `hermite_form_with_transform`.
+    /// Otherwise the elimination runs on the matrix itself without a modulus, so
+    /// entries may grow exponentially and can overflow for `i64` entries. Use
+    /// `smith_form` or `invariant_factors` when the transforms are not needed.
+    pub fn smith_form_with_transform(&self) -> (GenericMatrix<R>, GenericMatrix<R>, GenericMatrix<R>) {
+        let (m, n) = (self.nrows(), self.ncols());
+        let ring = self.base_ring();
+        if let Some((s, u, v)) = smith_cyclic(self) {
+            return (s, u, v);
+        }
+        let mut a = self.entries().to_vec();
+        let mut u = identity(ring, m);
+        let mut v = identity(ring, n);
+        smith_in_place(ring, &mut a, m, n, Some((&mut u, &mut v)), None);
+        (matrix(ring, m, n, a), matrix(ring, m, m, u), matrix(ring, n, n, v))
+    }
+
+    /// Return the nonzero invariant factors `d_1 | d_2 | ... | d_r`. The quotient
+    /// of `R^n` by the row module is `R/(d_1) × ... × R/(d_r) × R^(n - r)`, which
+    /// for integer matrices is the structure of a finitely generated abelian
+    /// group given by generators and relations.
+    pub fn invariant_factors(&self) -> Vec<<R as Ring>::Element> {
+        let s = self.smith_form();
+        (0..self.nrows().min(self.ncols()))
+            .map(|i| s.entry(i, i).clone())
+            .take_while(|x| !x.is_zero())
+            .collect()
+    }
+}
@@ -0,0 +1,173 @@
+// Helper macros used by the concrete parents in this crate.
+
+/// Implement the assignment operators making up `AddQuasigroupOps`, along with
+/// the corresponding `std::ops` operators on owned values and references, in
+/// terms of `Operation<Additive>` and `TwoSidedInverse<Additive>`.
+///
+/// Usage: `impl_additive_ops!([F: Field] Point<F>);`
+macro_rules! impl_additive_ops {
+    ([$($gen:tt)*] $t:ty) => {
+        impl<'a, $($gen)*> $crate::ops::AddAssign<&'a $t> for $t {
+            #[inline]
+            fn add_assign(&mut self, rhs: &'a $t) {
+                *self = $crate::Operation::<$crate::Additive>::operate(self, rhs);
+            }
+        }
+
+        impl<'a, $($gen)*> $crate::ops::AddFrom<&'a $t> for $t {
+            #[inline]
+            fn add_from(&mut self, lhs: &'a $t) {
+                *self = $crate::Operation::<$crate::Additive>::operate(lhs, self);
+            }
+        }
+
+        impl<'a, $($gen)*> $crate::ops::SubAssign<&'a $t> for $t {
+            #[inline]
+            fn sub_assign(&mut self, rhs: &'a $t) {
+                let neg = $crate::TwoSidedInverse::<$crate::Additive>::two_sided_inverse(rhs);
+                *self = $crate::Operation::<$crate::Additive>::operate(self, &neg);
+            }
+        }
+
+        impl<'a, $($gen)*> $crate::ops::SubFrom<&'a $t> for $t {
+            #[inline]
+            fn sub_from(&mut self, lhs: &'a $t) {
+                $crate::TwoSidedInverse::<$crate::Additive>::two_sided_inverse_mut(self);
+                *self = $crate::Operation::<$crate::Additive>::operate(lhs, self);
+            }
+        }
+
+        impl<$($gen)*> $crate::ops::NegAssign for $t {
+            #[inline]
+            fn neg_assign(&mut self) {
+                $crate::TwoSidedInverse::<$crate::Additive>::two_sided_inverse_mut(self);
+            }
+        }
+
+        impl<$($gen)*> $crate::ops::Neg for $t {
+            type Output = $t;
+            #[inline]
+            fn neg(self) -> $t {
+                $crate::TwoSidedInverse::<$crate::Additive>::two_sided_inverse(&self)
+            }
+        }
+
+        impl<'a, $($gen)*> $crate::ops::Neg for &'a $t {
+            type Output = $t;
+            #[inline]
+            fn neg(self) -> $t {
+                $crate::TwoSidedInverse::<$crate::Additive>::two_sided_inverse(self)
+            }
+        }
+
+        impl_ring_ops!(@binop [$($gen)*] $t, Add, add, AddAssign, add_assign);
+        impl_ring_ops!(@binop [$($gen)*] $t, Sub, sub, SubAssign, sub_assign);
+    };
+}
+
+/// Implement the assignment operators making up `RingOps`, along with the
+/// corresponding `std::ops` operators on owned values and references, in terms of
+/// `Operation<Additive>`, `TwoSidedInverse<Additive>` and
+/// `Operation<Multiplicative>`.
+///
+/// Usage: `impl_ring_ops!([T: Ring] GenericPoly<T>);`
+macro_rules! impl_ring_ops {
+    ([$($gen:tt)*] $t:ty) => {
+        impl_additive_ops!([$($gen)*] $t);
+
+        impl<'a, $($gen)*> $crate::ops::MulAssign<&'a $t> for $t {
+            #[inline]
+            fn mul_assign(&mut self, rhs: &'a $t) {
+                *self = $crate::Operation::<$crate::Multiplicative>::operate(self, rhs);
+            }
+        }
+
+        impl<'a, $($gen)*> $crate::ops::MulFrom<&'a $t> for $t {
+            #[inline]
+            fn mul_from(&mut self, lhs: &'a $t) {
+                *self = $crate::Operation::<$crate::Multiplicative>::operate(lhs, self);
+            }
+        }
+
+        impl_ring_ops!(@binop [$($gen)*] $t, Mul, mul, MulAssign, mul_assign);
+    };
+    (@binop [$($gen:tt)*] $t:ty, $tr:ident, $meth:ident, $atr:ident, $assign:ident) => {
+        impl<$($gen)*> $crate::ops::$tr<$t> for $t {
+            type Output = $t;
+            #[inline]
+            fn $meth(mut self, rhs: $t) -> $t {
+                $crate::ops::$atr::$assign(&mut self, &rhs);
+                self
+            }
+        }
+
+        impl<'a, $($gen)*> $crate::ops::$tr<&'a $t> for $t {
+            type Output = $t;
+            #[inline]
+            fn $meth(mut self, rhs: &'a $t) -> $t {
+                $crate::ops::$atr::$assign(&mut self, rhs);
+                self
+            }
+        }
+
+        impl<'a, 'b, $($gen)*> $crate::ops::$tr<&'b $t> for &'a $t {
+            type Output = $t;
+            #[inline]
+            fn $meth(self, rhs: &'b $t) -> $t {
+                let mut res = self.clone();
+                $crate::ops::$atr::$assign(&mut res, rhs);
+                res
+            }
+        }
+    };
+}
+
+/// Implement the operators making up `InvOps` and `DivOps`, along with `Inv` and
+/// the `std::ops` division operators, in terms of `Operation<Multiplicative>`
+/// and `TwoSidedInverse<Multiplicative>`.
+///
+/// Usage: `impl_field_ops!([] Rational);`
+macro_rules! impl_field_ops {
+    ([$($gen:tt)*] $t:ty) => {
+        impl<$($gen)*> $crate::ops::InvAssign for $t {
+            #[inline]
+            fn inv_assign(&mut self) {
+                $crate::TwoSidedInverse::<$crate::Multiplicative>::two_sided_inverse_mut(self);
+            }
+        }
+
+        impl<$($gen)*> $crate::ops::Inv for $t {
+            type Output = $t;
+            #[inline]
+            fn inv(self) -> $t {
+                $crate::TwoSidedInverse::<$crate::Multiplicative>::two_sided_inverse(&self)
+            }
+        }
+
+        impl<'a, $($gen)*> $crate::ops::Inv for &'a $t {
+            type Output = $t;
+            #[inline]
+            fn inv(self) -> $t {
+                $crate::TwoSidedInverse::<$crate::Multiplicative>::two_sided_inverse(self)
+            }
+        }
+
+        impl<'a, $($gen)*> $crate::ops::DivAssign<&'a $t> for $t {
+            #[inline]
+            fn div_assign(&mut self, rhs: &'a $t) {
+                let inv = $crate::TwoSidedInverse::<$crate::Multiplicative>::two_sided_inverse(rhs);
+                *self = $crate::Operation::<$crate::Multiplicative>::operate(self, &inv);
+            }
+        }
+
+        impl<'a, $($gen)*> $crate::ops::DivFrom<&'a $t> for $t {
+            #[inline]
+            fn div_from(&mut self, lhs: &'a $t) {
+                $crate::TwoSidedInverse::<$crate::Multiplicative>::two_sided_inverse_mut(self);
+                *self = $crate::Operation::<$crate::Multiplicative>::operate(lhs, self);
+            }
+        }
+
+        impl_ring_ops!(@binop [$($gen)*] $t, Div, div, DivAssign, div_assign);
+    };
+}
@@ -0,0 +1,378 @@
+//! Maps between parents.
+
+use crate::*;
+
+use approx::RelativeEq;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// A map from the elements of the parent `D` to those of the parent `C`.
+pub trait Morphism<D: Parent, C: Parent> {
+    /// The domain of the map.
+    fn domain(&self) -> &D;
+
+    /// The codomain of the map.
+    fn codomain(&self) -> &C;
+
+    /// The image of `x`.
+    fn apply(&self, x: &Elem<D>) -> Elem<C>;
+
+    /// The composition applying `self` and then `g`, written `g ∘ self`.
+    #[inline]
+    fn then<E: Parent, G: Morphism<C, E>>(self, g: G) -> Composition<Self, G, C>
+    where
+        Self: Sized,
+    {
+        Composition::init(self, g)
+    }
+}
+
+/// A map between groups preserving the operation `O`.
+///
+/// ```notrust
+/// ∀ a, b ∈ D, f(a ∘ b) = f(a) ∘ f(b)
+/// ```
+pub trait GroupHomomorphism<D: AbstractGroup<O>, C: AbstractGroup<O>, O: Operator>:
+    Morphism<D, C>
+{
+    /// Returns `true` if the map preserves the operation for the given arguments.
+    /// Approximate equality is used for verifications.
+    fn prop_preserves_operation_approx(&self, args: (Elem<D>, Elem<D>)) -> bool
+    where
+        Elem<C>: RelativeEq,
+    {
+        let (a, b) = args;
+        relative_eq!(
+            self.apply(&Operation::<O>::operate(&a, &b)),
+            Operation::<O>::operate(&self.apply(&a), &self.apply(&b))
+        )
+    }
+
+    /// Returns `true` if the map preserves the operation for the given arguments.
+    fn prop_preserves_operation(&self, args: (Elem<D>, Elem<D>)) -> bool
+    where
+        Elem<C>: Eq,
+    {
+        let (a, b) = args;
+        self.apply(&Operation::<O>::operate(&a, &b))
+            == Operation::<O>::operate(&self.apply(&a), &self.apply(&b))
+    }
+
+    /// Returns `true` if the map sends the identity to the identity.
+    fn prop_preserves_identity(&self) -> bool
+    where
+        Elem<C>: Eq,
+    {
+        IsIdentity::<O>::is_identity(&self.apply(&Identity::<O>::identity(self.domain())))
+    }
+
+    /// Returns `true` if the map sends the inverse of the given argument to the
+    /// inverse of its image.
+    fn prop_preserves_inverse(&self, args: (Elem<D>,)) -> bool
+    where
+        Elem<C>: Eq,
+    {
+        let (a,) = args;
+        self.apply(&TwoSidedInverse::<O>::two_sided_inverse(&a))
+            == TwoSidedInverse::<O>::two_sided_inverse(&self.apply(&a))
+    }
+
+    /// The kernel, the subgroup of elements sent to the identity.
+    fn kernel(&self) -> Subgroup<D, O>
+    where
+        D: Finite,
+        Elem<D>: Eq + Hash,
+    {
+        let gens: Vec<Elem<D>> = self.domain().elements().into_iter()
+            .filter(|x| IsIdentity::<O>::is_identity(&self.apply(x)))
+            .collect();
+        Subgroup::init(O::operator_token(), self.domain(), &gens)
+    }
+
+    /// The image, the subgroup of the codomain of all values of the map.
+    fn image(&self) -> Subgroup<C, O>
+    where
+        D: Finite,
+        Elem<C>: Eq + Hash,
+    {
+        let mut gens: Vec<Elem<C>> = vec![];
+        for x in self.domain().elements() {
+            let y = self.apply(&x);
+            if !gens.contains(&y) {
+                gens.push(y);
+            }
+        }
+        Subgroup::init(O::operator_token(), self.codomain(), &gens)
+    }
+
+    /// Returns `true` if the map is injective, that is its kernel is trivial.
+    fn is_injective(&self) -> bool
+    where
+        D: Finite,
+    {
+        self.domain().elements().iter()
+            .filter(|x| IsIdentity::<O>::is_identity(&self.apply(x)))
+            .count() == 1
+    }
+
+    /// Returns `true` if the map is surjective.
+    fn is_surjective(&self) -> bool
+    where
+        D: Finite,
+        C: Finite,
+        Elem<C>: Eq + Hash,
+    {
+        self.image().order() == self.codomain().cardinality()
+    }
+
+    /// Returns `true` if the map is bijective.
+    fn is_isomorphism(&self) -> bool
+    where
+        D: Finite,
+        C: Finite,
+        Elem<C>: Eq + Hash,
+    {
+        self.domain().cardinality() == self.codomain().cardinality() && self.is_injective()
+    }
+}
+
+/// A map between rings preserving addition, multiplication and the unit.
+///
+/// ```notrust
+/// ∀ a, b ∈ D, f(a + b) = f(a) + f(b), f(a * b) = f(a) * f(b), f(1) = 1
+/// ```
+pub trait RingHomomorphism<D: NCRing, C: NCRing>: GroupHomomorphism<D, C, Additive> {
+    /// Returns `true` if the map preserves multiplication for the given
+    /// arguments. Approximate equality is used for verifications.
+    fn prop_preserves_multiplication_approx(&self, args: (<D as NCRing>::Element, <D as NCRing>::Element)) -> bool
+    where
+        <C as NCRing>::Element: RelativeEq,
+    {
+        let (a, b) = args;
+        relative_eq!(
+            self.apply(&Operation::<Multiplicative>::operate(&a, &b)),
+            Operation::<Multiplicative>::operate(&self.apply(&a), &self.apply(&b))
+        )
+    }
+
+    /// Returns `true` if the map preserves multiplication for the given
+    /// arguments.
+    fn prop_preserves_multiplication(&self, args: (<D as NCRing>::Element, <D as NCRing>::Element)) -> bool
+    where
+        <C as NCRing>::Element: Eq,
+    {
+        let (a, b) = args;
+        self.apply(&Operation::<Multiplicative>::operate(&a, &b))
+            == Operation::<Multiplicative>::operate(&self.apply(&a), &self.apply(&b))
+    }
+
+    /// Returns `true` if the map sends one to one.
+    fn prop_preserves_one(&self) -> bool
+    where
+        <C as NCRing>::Element: Eq,
+    {
+        let one = Identity::<Multiplicative>::identity(self.domain());
+        IsIdentity::<Multiplicative>::is_identity(&self.apply(&one))
+    }
+}
+
+/// The identity map of a parent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdentityMorphism<P: Parent> {
+    parent: P,
+}
+
+impl<P: Parent> IdentityMorphism<P> {
+    /// Initialize the identity map of `parent`.
+    pub fn init(parent: &P) -> Self {
+        IdentityMorphism { parent: parent.clone() }
+    }
+}
+
+impl<P: Parent> Morphism<P, P> for IdentityMorphism<P> {
+    #[inline]
+    fn domain(&self) -> &P {
+        &self.parent
+    }
+
+    #[inline]
+    fn codomain(&self) -> &P {
+        &self.parent
+    }
+
+    #[inline]
+    fn apply(&self, x: &Elem<P>) -> Elem<P> {
+        x.clone()
+    }
+}
+
+impl<P: AbstractGroup<O>, O: Operator> GroupHomomorphism<P, P, O> for IdentityMorphism<P> {}
+
+impl<P: NCRing> RingHomomorphism<P, P> for IdentityMorphism<P> {}
+
+/// The composition `g ∘ f` of maps `f: D → M` and `g: M → C`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Composition<F, G, M> {
+    first: F,
+    second: G,
+    middle: PhantomData<M>,
+}
+
+impl<F, G, M> Composition<F, G, M> {
+    /// Initialize the composition applying `first` and then `second`.
+    pub fn init(first: F, second: G) -> Self {
+        Composition { first, second, middle: PhantomData }
+    }
+
+    /// Return the map applied first.
+    #[inline]
+    pub fn first(&self) -> &F {
+        &self.first
+    }
+
+    /// Return the map applied second.
+    #[inline]
+    pub fn second(&self) -> &G {
+        &self.second
+    }
+}
+
+impl<D, M, C, F, G> Morphism<D, C> for Composition<F, G, M>
+where
+    D: Parent,
+    M: Parent,
+    C: Parent,
+    F: Morphism<D, M>,
+    G: Morphism<M, C>,
+{
+    #[inline]
+    fn domain(&self) -> &D {
+        self.first.domain()
+    }
+
+    #[inline]
+    fn codomain(&self) -> &C {
+        self.second.codomain()
+    }
+
+    #[inline]
+    fn apply(&self, x: &Elem<D>) -> Elem<C> {
+        self.second.apply(&self.first.apply(x))
+    }
+}
+
+impl<D, M, C, F, G, O> GroupHomomorphism<D, C, O> for Composition<F, G, M>
+where
+    D: AbstractGroup<O>,
+    M: AbstractGroup<O>,
+    C: AbstractGroup<O>,
+    O: Operator,
+    F: GroupHomomorphism<D, M, O>,
+    G: GroupHomomorphism<M, C, O>,
+{}
+
+impl<D, M, C, F, G> RingHomomorphism<D, C> for Composition<F, G, M>
+where
+    D: NCRing,
+    M: NCRing,
+    C: NCRing,
+    F: RingHomomorphism<D, M>,
+    G: RingHomomorphism<M, C>,
+{}
+
+/// A map given by a function or closure.
+///
+/// The parameter `K` records which structure the map is declared to preserve:
+/// `()` for none, an operator `O` for a `GroupHomomorphism<O>`, and
+/// `(Additive, Multiplicative)` for a `RingHomomorphism`. The declaration is
+/// not checked, but can be tested with the `prop_*` functions.
+pub struct FnMorphism<D: Parent, C: Parent, F, K = ()> {
+    domain: D,
+    codomain: C,
+    map: F,
+    kind: PhantomData<K>,
+}
+
+impl<D: Parent, C: Parent, F> FnMorphism<D, C, F>
+where
+    F: Fn(&Elem<D>) -> Elem<C>,
+{
+    /// Initialize the map from `domain` to `codomain` given by `map`.
+    pub fn init(domain: &D, codomain: &C, map: F) -> Self {
+        FnMorphism { domain: domain.clone(), codomain: codomain.clone(), map, kind: PhantomData }
+    }
+
+    /// Initialize the map given by `map`, declared to be a homomorphism of
+    /// groups with respect to the operator `O`.
+    pub fn group_homomorphism<O: Operator>(
+        _: O,
+        domain: &D,
+        codomain: &C,
+        map: F
+    ) -> FnMorphism<D, C, F, O> {
+        FnMorphism { domain: domain.clone(), codomain: codomain.clone(), map, kind: PhantomData }
+    }
+
+    /// Initialize the map given by `map`, declared to be a homomorphism of
+    /// rings.
+    pub fn ring_homomorphism(
+        domain: &D,
+        codomain: &C,
+        map: F
+    ) -> FnMorphism<D, C, F, (Additive, Multiplicative)> {
+        FnMorphism { domain: domain.clone(), codomain: codomain.clone(), map, kind: PhantomData }
+    }
+}
+
+impl<D: Parent, C: Parent, F: Clone, K> Clone for FnMorphism<D, C, F, K> {
+    fn clone(&self) -> Self {
+        FnMorphism {
+            domain: self.domain.clone(),
+            codomain: self.codomain.clone(),
+            map: self.map.clone(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<D: Parent, C: Parent, F, K> Morphism<D, C> for FnMorphism<D, C, F, K>
+where
+    F: Fn(&Elem<D>) -> Elem<C>,
+{
+    #[inline]
+    fn domain(&self) -> &D {
+        &self.domain
+    }
+
+    #[inline]
+    fn codomain(&self) -> &C {
+        &self.codomain
+    }
+
+    #[inline]
+    fn apply(&self, x: &Elem<D>) -> Elem<C> {
+        (self.map)(x)
+    }
+}
+
+impl<D, C, F, O> GroupHomomorphism<D, C, O> for FnMorphism<D, C, F, O>
+where
+    D: AbstractGroup<O>,
+    C: AbstractGroup<O>,
+    O: Operator,
+    F: Fn(&Elem<D>) -> Elem<C>,
+{}
+
+impl<D, C, F> GroupHomomorphism<D, C, Additive> for FnMorphism<D, C, F, (Additive, Multiplicative)>
+where
+    D: NCRing,
+    C: NCRing,
+    F: Fn(&<D as NCRing>::Element) -> <C as NCRing>::Element,
+{}
+
+impl<D, C, F> RingHomomorphism<D, C> for FnMorphism<D, C, F, (Additive, Multiplicative)>
+where
+    D: NCRing,
+    C: NCRing,
+    F: Fn(&<D as NCRing>::Element) -> <C as NCRing>::Element,
+{}
@@ -0,0 +1,37 @@
+//! Concrete rings.
+
+pub use integer::*;
+pub use rational::*;
+pub use poly::*;
+pub use sparse_poly::*;
+pub use power_series::*;
+pub use laurent_series::*;
+pub use mpoly::*;
+pub use quotient::*;
+pub use groebner::*;
+pub use ideal::*;
+pub use product::*;
+pub use quadratic::*;
+pub use number_field::*;
+pub use padic::*;
+pub use quaternion::*;
+pub use cayley_dickson::*;
+pub use clifford::*;
+
+mod integer;
+mod rational;
+mod poly;
+mod sparse_poly;
+mod power_series;
+mod laurent_series;
+mod mpoly;
+mod quotient;
+mod groebner;
+mod ideal;
+mod product;
+mod quadratic;
+mod number_field;
+mod padic;
+mod quaternion;
+mod cayley_dickson;
+mod clifford;
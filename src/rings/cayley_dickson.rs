@@ -0,0 +1,336 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// The Cayley-Dickson double of a star algebra `A` over `F` with parameter `γ`:
+/// pairs `(a, b)` of elements of `A`, thought of as `a + b ℓ` with `ℓ² = γ`,
+/// multiplied by
+///
+/// ```notrust
+/// (a, b)(c, d) = (ac + γ d̄ b, da + b c̄)
+/// ```
+///
+/// with conjugate `(ā, -b)` and norm `N(a) - γ N(b)`. Doubling a field gives a
+/// quadratic extension, doubling a quadratic extension gives a quaternion
+/// algebra, and doubling a quaternion algebra gives an octonion algebra.
+///
+/// Each doubling loses a property of the multiplication: octonions are not
+/// associative, so the double is only an `AbstractLoop` under multiplication
+/// and never an `NCRing`. The inverse of `x` is `x̄ / N(x)`, and inverting an
+/// element of norm zero panics. As for quaternion algebras, only doubles of kind
+/// `DivisionAlgebra` are `Divisible` under multiplication. The double is again a
+/// `StarAlgebra`, so the construction can be iterated, but from the sedenions
+/// on it always has zero divisors.
+#[derive(Clone, PartialEq)]
+pub struct CayleyDickson<F: Field, A: StarAlgebra<F>, K: AlgebraKind = AnyAlgebra> {
+    base: A,
+    gamma: Elem<F>,
+    kind: PhantomData<K>,
+}
+
+/// The octonion algebras, doubles of quaternion algebras.
+pub type OctonionAlgebra<F, K = AnyAlgebra> = CayleyDickson<F, QuaternionAlgebra<F>, K>;
+
+/// An element `a + b ℓ` of a `CayleyDickson` algebra.
+#[derive(Clone)]
+pub struct CayleyDicksonElement<F: Field, A: StarAlgebra<F>, K: AlgebraKind = AnyAlgebra> {
+    parent: CayleyDickson<F, A, K>,
+    re: Elem<A>,
+    im: Elem<A>,
+}
+
+/// An element of an `OctonionAlgebra`.
+pub type Octonion<F, K = AnyAlgebra> = CayleyDicksonElement<F, QuaternionAlgebra<F>, K>;
+
+/// Return `x * y` for elements of `A`.
+#[inline]
+fn mul<A: Parent<Element: RingOps>>(x: &Elem<A>, y: &Elem<A>) -> Elem<A> {
+    let mut res = x.clone();
+    res *= y;
+    res
+}
+
+impl<F: Field, A: StarAlgebra<F>> CayleyDickson<F, A> {
+    /// Initialize the double of `base` with parameter `gamma`. Panics if `gamma`
+    /// is zero.
+    pub fn init(base: &A, gamma: &Elem<F>) -> Self {
+        assert!(!gamma.is_zero(), "Cayley-Dickson parameter must be nonzero");
+        CayleyDickson { base: base.clone(), gamma: gamma.clone(), kind: PhantomData }
+    }
+
+    /// Return the double as a division algebra without checking that it is one.
+    /// If it is not, inverting a zero divisor panics.
+    pub fn as_division_algebra_unchecked(&self) -> CayleyDickson<F, A, DivisionAlgebra> {
+        CayleyDickson { base: self.base.clone(), gamma: self.gamma.clone(), kind: PhantomData }
+    }
+}
+
+impl OctonionAlgebra<RationalField> {
+    /// Return `true` if the octonion algebra is a division algebra. Its norm
+    /// form is an 8-dimensional Pfister form, which is isotropic over every
+    /// `Q_p`, so by Hasse-Minkowski this holds exactly when the form is definite,
+    /// that is when `a`, `b` and `γ` are all negative.
+    pub fn is_division_algebra(&self) -> bool {
+        let (a, b) = self.base.parameters();
+        let zero = Rational::from(0);
+        *a < zero && *b < zero && self.gamma < zero
+    }
+
+    /// Return the algebra as a division algebra, or `None` if it is split.
+    pub fn as_division_algebra(&self) -> Option<OctonionAlgebra<RationalField, DivisionAlgebra>> {
+        self.is_division_algebra().then(|| self.as_division_algebra_unchecked())
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> CayleyDickson<F, A, K> {
+
+    /// Return a reference to the algebra which was doubled.
+    #[inline]
+    pub fn base(&self) -> &A {
+        &self.base
+    }
+
+    /// Return the parameter `γ`, the square of the new generator `ℓ`.
+    #[inline]
+    pub fn parameter(&self) -> &Elem<F> {
+        &self.gamma
+    }
+
+    /// Return the new generator `ℓ = (0, 1)`.
+    pub fn gen(&self) -> CayleyDicksonElement<F, A, K> {
+        let f = self.base.base_field();
+        self.elem(self.base.embed_base(&f.zero()), self.base.embed_base(&f.one()))
+    }
+
+    /// Return the embedding `a ↦ (a, 0)` of the base algebra.
+    pub fn embed(&self, a: &Elem<A>) -> CayleyDicksonElement<F, A, K> {
+        self.elem(a.clone(), self.base_zero())
+    }
+
+    #[inline]
+    fn base_zero(&self) -> Elem<A> {
+        self.base.embed_base(&self.base.base_field().zero())
+    }
+
+    #[inline]
+    fn elem(&self, re: Elem<A>, im: Elem<A>) -> CayleyDicksonElement<F, A, K> {
+        CayleyDicksonElement { parent: self.clone(), re, im }
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> CayleyDicksonElement<F, A, K> {
+    /// Return the components `(a, b)` of `a + b ℓ`.
+    #[inline]
+    pub fn parts(&self) -> (&Elem<A>, &Elem<A>) {
+        (&self.re, &self.im)
+    }
+
+    /// Return the conjugate `ā - b ℓ`.
+    pub fn conjugate(&self) -> Self {
+        let mut im = self.im.clone();
+        im.neg_assign();
+        self.parent.elem(self.parent.base.conjugate(&self.re), im)
+    }
+
+    /// Return the norm `x x̄ = N(a) - γ N(b)`.
+    pub fn norm(&self) -> Elem<F> {
+        let base = &self.parent.base;
+        let mut res = self.parent.gamma.clone();
+        res *= &base.norm(&self.im);
+        res.neg_assign();
+        res += &base.norm(&self.re);
+        res
+    }
+
+    /// Return the inverse `x̄ / N(x)`, or `None` if the norm of `self` is zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let mut n = self.norm();
+        if n.is_zero() {
+            return None;
+        }
+        n.inv_assign();
+        let n = self.parent.base.embed_base(&n);
+        let mut res = self.conjugate();
+        res.re *= &n;
+        res.im *= &n;
+        Some(res)
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> PartialEq for CayleyDicksonElement<F, A, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re && self.im == other.im
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> fmt::Debug for CayleyDickson<F, A, K>
+where
+    A: fmt::Debug,
+    <F as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CayleyDickson")
+            .field("base", &self.base)
+            .field("gamma", &self.gamma)
+            .finish()
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> fmt::Debug for CayleyDicksonElement<F, A, K>
+where
+    Elem<A>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CayleyDicksonElement")
+            .field("re", &self.re)
+            .field("im", &self.im)
+            .finish()
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> fmt::Display for CayleyDicksonElement<F, A, K>
+where
+    Elem<A>: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.re, self.im)
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Parent for CayleyDickson<F, A, K> {
+    type Element = CayleyDicksonElement<F, A, K>;
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Element for CayleyDicksonElement<F, A, K> {
+    type Parent = CayleyDickson<F, A, K>;
+
+    #[inline]
+    fn parent(&self) -> CayleyDickson<F, A, K> {
+        self.parent.clone()
+    }
+}
+
+/// Construct `a + b ℓ` from the pair `(a, b)`.
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> NewElement<(Elem<A>, Elem<A>)> for CayleyDickson<F, A, K> {
+    #[inline]
+    fn new(&self, src: (Elem<A>, Elem<A>)) -> CayleyDicksonElement<F, A, K> {
+        self.elem(src.0, src.1)
+    }
+}
+
+// Additive properties
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Operation<Additive> for CayleyDicksonElement<F, A, K> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let mut res = self.clone();
+        res.re += &rhs.re;
+        res.im += &rhs.im;
+        res
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Identity<Additive> for CayleyDickson<F, A, K> {
+    #[inline]
+    fn identity(&self) -> CayleyDicksonElement<F, A, K> {
+        self.elem(self.base_zero(), self.base_zero())
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> IsIdentity<Additive> for CayleyDicksonElement<F, A, K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        let zero = self.parent.base_zero();
+        self.re == zero && self.im == zero
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> TwoSidedInverse<Additive> for CayleyDicksonElement<F, A, K> {
+    fn two_sided_inverse(&self) -> Self {
+        let mut res = self.clone();
+        res.re.neg_assign();
+        res.im.neg_assign();
+        res
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Divisible<Additive> for CayleyDickson<F, A, K> {}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Associative<Additive> for CayleyDickson<F, A, K> {}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Commutative<Additive> for CayleyDickson<F, A, K> {}
+
+// Multiplicative properties
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Operation<Multiplicative> for CayleyDicksonElement<F, A, K> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let p = &self.parent;
+        let (a, b) = (&self.re, &self.im);
+        let (c, d) = (&rhs.re, &rhs.im);
+
+        let mut re = p.base.embed_base(&p.gamma);
+        re *= &mul::<A>(&p.base.conjugate(d), b);
+        re += &mul::<A>(a, c);
+
+        let mut im = mul::<A>(d, a);
+        im += &mul::<A>(b, &p.base.conjugate(c));
+
+        p.elem(re, im)
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Identity<Multiplicative> for CayleyDickson<F, A, K> {
+    #[inline]
+    fn identity(&self) -> CayleyDicksonElement<F, A, K> {
+        self.embed_base(&self.base.base_field().one())
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> IsIdentity<Multiplicative> for CayleyDicksonElement<F, A, K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        let base = &self.parent.base;
+        self.re == base.embed_base(&base.base_field().one()) && self.im == self.parent.base_zero()
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> TwoSidedInverse<Multiplicative> for CayleyDicksonElement<F, A, K> {
+    /// Panics if the norm of `self` is zero.
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.inverse().expect("division by an element of norm zero")
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>> Divisible<Multiplicative> for CayleyDickson<F, A, DivisionAlgebra> {}
+
+// Ring-like properties
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Distributive for CayleyDickson<F, A, K> {}
+
+impl_ring_ops!([F: Field, A: StarAlgebra<F>, K: AlgebraKind] CayleyDicksonElement<F, A, K>);
+impl_field_ops!([F: Field, A: StarAlgebra<F>, K: AlgebraKind] CayleyDicksonElement<F, A, K>);
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> Algebra<F> for CayleyDickson<F, A, K> {
+    fn embed_base(&self, x: &Elem<F>) -> CayleyDicksonElement<F, A, K> {
+        self.elem(self.base.embed_base(x), self.base_zero())
+    }
+}
+
+impl<F: Field, A: StarAlgebra<F>, K: AlgebraKind> StarAlgebra<F> for CayleyDickson<F, A, K> {
+    #[inline]
+    fn base_field(&self) -> &F {
+        self.base.base_field()
+    }
+
+    #[inline]
+    fn conjugate(&self, x: &CayleyDicksonElement<F, A, K>) -> CayleyDicksonElement<F, A, K> {
+        x.conjugate()
+    }
+
+    #[inline]
+    fn norm(&self, x: &CayleyDicksonElement<F, A, K>) -> Elem<F> {
+        x.norm()
+    }
+}
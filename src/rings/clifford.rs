@@ -0,0 +1,440 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+
+/// The Clifford algebra `Cl(V, q)` of a vector space `V = F^n` with a diagonal
+/// quadratic form `q(x) = q_1 x_1² + ... + q_n x_n²`. It is generated by the
+/// orthogonal basis vectors `e_1, ..., e_n` subject to
+///
+/// ```notrust
+/// e_i² = q_i,  e_i e_j = -e_j e_i  (i ≠ j)
+/// ```
+///
+/// Elements are multivectors, linear combinations of the `2^n` basis blades
+/// `e_A = e_{i_1} ... e_{i_k}` for `i_1 < ... < i_k`. A blade is indexed by the
+/// bitmask of `A`, bit `i` standing for `e_{i+1}`. The form may be degenerate;
+/// if it is zero the algebra is the exterior algebra of `V`.
+///
+/// The algebra is an `NCRing` and a `VectorSpace` over `F` of dimension `2^n`.
+#[derive(Clone, PartialEq)]
+pub struct CliffordAlgebra<F: Field> {
+    field: F,
+    form: Vec<Elem<F>>,
+}
+
+/// A multivector in a `CliffordAlgebra`, stored densely by blade bitmask.
+#[derive(Clone)]
+pub struct Multivector<F: Field> {
+    parent: CliffordAlgebra<F>,
+    coeffs: Vec<Elem<F>>,
+}
+
+/// Return `true` if reordering the product `e_a e_b` of two blades into
+/// increasing order takes an odd number of transpositions.
+fn reordering_is_odd(a: usize, b: usize) -> bool {
+    let mut a = a >> 1;
+    let mut swaps = 0;
+    while a != 0 {
+        swaps += (a & b).count_ones();
+        a >>= 1;
+    }
+    swaps % 2 == 1
+}
+
+impl<F: Field> CliffordAlgebra<F> {
+    /// Initialize the Clifford algebra of the diagonal quadratic form with
+    /// coefficients `form`. Panics if there are more than 16 generators.
+    pub fn init(field: &F, form: &[Elem<F>]) -> Self {
+        assert!(form.len() <= 16, "too many generators");
+        CliffordAlgebra { field: field.clone(), form: form.to_vec() }
+    }
+
+    /// Initialize the exterior algebra of `F^n`, the Clifford algebra of the
+    /// zero form.
+    pub fn exterior(field: &F, n: usize) -> Self {
+        Self::init(field, &vec![field.zero(); n])
+    }
+
+    /// Return the coefficients `q_i` of the quadratic form.
+    #[inline]
+    pub fn form(&self) -> &[Elem<F>] {
+        &self.form
+    }
+
+    /// Return the dimension `n` of the underlying space `V`.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.form.len()
+    }
+
+    /// Return the generators `e_1, ..., e_n`.
+    pub fn gens(&self) -> Vec<Multivector<F>> {
+        (0..self.rank()).map(|i| self.blade(1 << i)).collect()
+    }
+
+    /// Return the basis blade `e_A` with bitmask `mask`. Panics if the mask
+    /// involves more than `n` generators.
+    pub fn blade(&self, mask: usize) -> Multivector<F> {
+        let mut coeffs = vec![self.field.zero(); 1 << self.rank()];
+        coeffs[mask] = self.field.one();
+        self.elem(coeffs)
+    }
+
+    /// Return the vector `v_1 e_1 + ... + v_n e_n`. Panics if the number of
+    /// coordinates is not `n`.
+    pub fn vector(&self, v: &[Elem<F>]) -> Multivector<F> {
+        assert_eq!(v.len(), self.rank(), "wrong number of coordinates");
+        let mut coeffs = vec![self.field.zero(); 1 << self.rank()];
+        for (i, c) in v.iter().enumerate() {
+            coeffs[1 << i] = c.clone();
+        }
+        self.elem(coeffs)
+    }
+
+    /// Return `±∏ q_i e_{a △ b}`, the geometric product `e_a e_b` of two basis
+    /// blades, as a sign and a scalar factor. The factor is zero if the blades
+    /// share a null generator.
+    fn blade_product(&self, a: usize, b: usize) -> (bool, Elem<F>) {
+        let mut c = self.field.one();
+        for (i, q) in self.form.iter().enumerate() {
+            if a & b & (1 << i) != 0 {
+                c *= q;
+            }
+        }
+        (reordering_is_odd(a, b), c)
+    }
+
+    #[inline]
+    fn elem(&self, coeffs: Vec<Elem<F>>) -> Multivector<F> {
+        Multivector { parent: self.clone(), coeffs }
+    }
+}
+
+impl<F: Field> Multivector<F> {
+    /// Return the coefficients of the basis blades, indexed by bitmask.
+    #[inline]
+    pub fn coefficients(&self) -> &[Elem<F>] {
+        &self.coeffs
+    }
+
+    /// Return the coefficient of the basis blade with bitmask `mask`.
+    #[inline]
+    pub fn coeff(&self, mask: usize) -> &Elem<F> {
+        &self.coeffs[mask]
+    }
+
+    /// Return the scalar part, the coefficient of `1`.
+    #[inline]
+    pub fn scalar_part(&self) -> &Elem<F> {
+        &self.coeffs[0]
+    }
+
+    /// Return the grade `k` part of `self`.
+    pub fn grade(&self, k: u32) -> Self {
+        self.map_blades(|mask, c| if mask.count_ones() == k { Some(c.clone()) } else { None })
+    }
+
+    /// Return the grades with a nonzero part, in increasing order.
+    pub fn grades(&self) -> Vec<u32> {
+        let mut res: Vec<u32> = self.coeffs.iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(mask, _)| mask.count_ones())
+            .collect();
+        res.sort_unstable();
+        res.dedup();
+        res
+    }
+
+    /// Return the reversion `x̃`, reversing the order of the factors of each
+    /// blade. The grade `k` part is multiplied by `(-1)^(k(k-1)/2)`.
+    pub fn reverse(&self) -> Self {
+        self.with_signs(|k| k % 4 >= 2)
+    }
+
+    /// Return the grade involution `x̂`, sending each generator `e_i` to `-e_i`.
+    /// The grade `k` part is multiplied by `(-1)^k`.
+    pub fn involute(&self) -> Self {
+        self.with_signs(|k| k % 2 == 1)
+    }
+
+    /// Return the Clifford conjugate, the composite of reversion and grade
+    /// involution.
+    pub fn conjugate(&self) -> Self {
+        self.with_signs(|k| k % 4 == 1 || k % 4 == 2)
+    }
+
+    /// Return the outer product `x ∧ y`.
+    pub fn wedge(&self, rhs: &Self) -> Self {
+        self.product_where(rhs, |a, b| a & b == 0)
+    }
+
+    /// Return the left contraction `x ⌋ y`, the grade `l - k` part of the
+    /// product of a grade `k` blade and a grade `l` blade.
+    pub fn left_contraction(&self, rhs: &Self) -> Self {
+        self.product_where(rhs, |a, b| a & !b == 0)
+    }
+
+    /// Return the right contraction `x ⌊ y`, the grade `k - l` part of the
+    /// product of a grade `k` blade and a grade `l` blade.
+    pub fn right_contraction(&self, rhs: &Self) -> Self {
+        self.product_where(rhs, |a, b| b & !a == 0)
+    }
+
+    /// Return the scalar product, the scalar part of `x y`.
+    pub fn scalar_product(&self, rhs: &Self) -> Elem<F> {
+        self.product_where(rhs, |a, b| a == b).coeffs[0].clone()
+    }
+
+    /// Return the inverse `x̃ / (x x̃)` of a versor, a product of invertible
+    /// vectors. Returns `None` if `x x̃` is not a nonzero scalar.
+    pub fn versor_inverse(&self) -> Option<Self> {
+        let rev = self.reverse();
+        let s = Operation::<Multiplicative>::operate(self, &rev);
+        if s.coeffs[0].is_zero() || s.coeffs[1..].iter().any(|c| !c.is_zero()) {
+            return None;
+        }
+        let mut n = s.coeffs[0].clone();
+        n.inv_assign();
+        Some(rev.map_blades(|_, c| {
+            let mut c = c.clone();
+            c *= &n;
+            Some(c)
+        }))
+    }
+
+    fn map_blades<G>(&self, g: G) -> Self
+    where
+        G: Fn(usize, &<F as Ring>::Element) -> Option<<F as Ring>::Element>,
+    {
+        let zero = self.parent.field.zero();
+        let coeffs = self.coeffs.iter()
+            .enumerate()
+            .map(|(mask, c)| g(mask, c).unwrap_or_else(|| zero.clone()))
+            .collect();
+        self.parent.elem(coeffs)
+    }
+
+    fn with_signs<G: Fn(u32) -> bool>(&self, negate: G) -> Self {
+        self.map_blades(|mask, c| {
+            let mut c = c.clone();
+            if negate(mask.count_ones()) {
+                c.neg_assign();
+            }
+            Some(c)
+        })
+    }
+
+    /// Return the sum of the geometric products of the pairs of blades of
+    /// `self` and `rhs` whose bitmasks satisfy `keep`.
+    fn product_where<G: Fn(usize, usize) -> bool>(&self, rhs: &Self, keep: G) -> Self {
+        let p = &self.parent;
+        let mut coeffs = vec![p.field.zero(); self.coeffs.len()];
+        for (a, x) in self.coeffs.iter().enumerate().filter(|(_, x)| !x.is_zero()) {
+            for (b, y) in rhs.coeffs.iter().enumerate().filter(|(_, y)| !y.is_zero()) {
+                if !keep(a, b) {
+                    continue;
+                }
+                let (odd, mut t) = p.blade_product(a, b);
+                t *= x;
+                t *= y;
+                if odd {
+                    coeffs[a ^ b] -= &t;
+                } else {
+                    coeffs[a ^ b] += &t;
+                }
+            }
+        }
+        p.elem(coeffs)
+    }
+}
+
+impl<F: Field> PartialEq for Multivector<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+impl<F: Field> fmt::Debug for CliffordAlgebra<F>
+where
+    F: fmt::Debug,
+    <F as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CliffordAlgebra")
+            .field("field", &self.field)
+            .field("form", &self.form)
+            .finish()
+    }
+}
+
+impl<F: Field> fmt::Debug for Multivector<F>
+where
+    <F as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Multivector")
+            .field("coeffs", &self.coeffs)
+            .finish()
+    }
+}
+
+/// Terms are printed by increasing grade, and blades of the same grade in
+/// lexicographic order, for example `1 + 2*e1 + 3*e1*e2`.
+impl<F: Field> fmt::Display for Multivector<F>
+where
+    <F as Ring>::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut masks: Vec<usize> = (0..self.coeffs.len())
+            .filter(|&mask| !self.coeffs[mask].is_zero())
+            .collect();
+        if masks.is_empty() {
+            return write!(f, "0");
+        }
+        let indices = |mask: usize| -> Vec<usize> {
+            (0..self.parent.rank()).filter(|i| mask & (1 << i) != 0).collect()
+        };
+        masks.sort_by_key(|&mask| (mask.count_ones(), indices(mask)));
+        for (k, mask) in masks.into_iter().enumerate() {
+            if k > 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{}", self.coeffs[mask])?;
+            for i in indices(mask) {
+                write!(f, "*e{}", i + 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<F: Field> Parent for CliffordAlgebra<F> {
+    type Element = Multivector<F>;
+}
+
+impl<F: Field> Element for Multivector<F> {
+    type Parent = CliffordAlgebra<F>;
+
+    #[inline]
+    fn parent(&self) -> CliffordAlgebra<F> {
+        self.parent.clone()
+    }
+}
+
+/// Construct the multivector with the given blade coefficients, indexed by
+/// bitmask. Panics if there are not `2^n` coefficients.
+impl<F: Field> NewElement<Vec<Elem<F>>> for CliffordAlgebra<F> {
+    fn new(&self, src: Vec<Elem<F>>) -> Multivector<F> {
+        assert_eq!(src.len(), 1 << self.rank(), "wrong number of coefficients");
+        self.elem(src)
+    }
+}
+
+// Additive properties
+
+impl<F: Field> Operation<Additive> for Multivector<F> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let mut res = self.clone();
+        for (c, d) in res.coeffs.iter_mut().zip(rhs.coeffs.iter()) {
+            *c += d;
+        }
+        res
+    }
+}
+
+impl<F: Field> Identity<Additive> for CliffordAlgebra<F> {
+    #[inline]
+    fn identity(&self) -> Multivector<F> {
+        self.elem(vec![self.field.zero(); 1 << self.rank()])
+    }
+}
+
+impl<F: Field> IsIdentity<Additive> for Multivector<F> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs.iter().all(|c| c.is_zero())
+    }
+}
+
+impl<F: Field> TwoSidedInverse<Additive> for Multivector<F> {
+    fn two_sided_inverse(&self) -> Self {
+        self.with_signs(|_| true)
+    }
+}
+
+impl<F: Field> Divisible<Additive> for CliffordAlgebra<F> {}
+
+impl<F: Field> Associative<Additive> for CliffordAlgebra<F> {}
+
+impl<F: Field> Commutative<Additive> for CliffordAlgebra<F> {}
+
+// Multiplicative properties
+
+impl<F: Field> Operation<Multiplicative> for Multivector<F> {
+    /// The geometric product.
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self.product_where(rhs, |_, _| true)
+    }
+}
+
+impl<F: Field> Identity<Multiplicative> for CliffordAlgebra<F> {
+    #[inline]
+    fn identity(&self) -> Multivector<F> {
+        self.blade(0)
+    }
+}
+
+impl<F: Field> IsIdentity<Multiplicative> for Multivector<F> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs[0].is_one() && self.coeffs[1..].iter().all(|c| c.is_zero())
+    }
+}
+
+impl<F: Field> Associative<Multiplicative> for CliffordAlgebra<F> {}
+
+// Ring-like properties
+
+impl<F: Field> Distributive for CliffordAlgebra<F> {}
+
+impl_ring_ops!([F: Field] Multivector<F>);
+
+impl<F: Field> Algebra<F> for CliffordAlgebra<F> {
+    fn embed_base(&self, x: &Elem<F>) -> Multivector<F> {
+        let mut res = self.zero();
+        res.coeffs[0] = x.clone();
+        res
+    }
+}
+
+impl<F: Field> VectorSpace<F> for CliffordAlgebra<F> {
+    #[inline]
+    fn base_field(&self) -> &F {
+        &self.field
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        1 << self.rank()
+    }
+
+    fn scale(&self, c: &Elem<F>, x: &Multivector<F>) -> Multivector<F> {
+        x.map_blades(|_, d| {
+            let mut d = d.clone();
+            d *= c;
+            Some(d)
+        })
+    }
+
+    fn basis(&self) -> Vec<Multivector<F>> {
+        (0..self.dimension()).map(|mask| self.blade(mask)).collect()
+    }
+
+    #[inline]
+    fn coordinates(&self, x: &Multivector<F>) -> Vec<Elem<F>> {
+        x.coeffs.clone()
+    }
+}
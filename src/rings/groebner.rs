@@ -0,0 +1,421 @@
+//! Gröbner bases of ideals in multivariate polynomial rings over a field.
+
+use crate::*;
+use crate::ops::*;
+
+use std::collections::{BTreeSet, HashSet};
+
+/// The algorithm used to compute a Gröbner basis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GroebnerAlgorithm {
+    /// Buchberger's algorithm with the sugar selection strategy and the product
+    /// and chain criteria for discarding useless pairs.
+    #[default]
+    Buchberger,
+    /// Faugère's F4 algorithm: all critical pairs of minimal sugar are reduced
+    /// simultaneously by Gaussian elimination on a Macaulay matrix.
+    F4,
+}
+
+/// The reduced Gröbner basis of an ideal of a `SparseMPolyRing` over a field,
+/// with respect to the monomial ordering of the ring.
+///
+/// The basis is monic and sorted by increasing leading monomial, so it is
+/// uniquely determined by the ideal and the ordering.
+#[derive(Clone)]
+pub struct GroebnerBasis<T: Field> {
+    ring: SparseMPolyRing<T>,
+    basis: Vec<SparseMPoly<T>>,
+}
+
+/// A critical pair `(i, j)` with `i < j`, along with the lcm of the leading
+/// monomials and its sugar degree.
+#[derive(Clone, Debug)]
+struct CriticalPair {
+    i: usize,
+    j: usize,
+    lcm: Vec<u64>,
+    sugar: u64,
+}
+
+#[inline]
+fn divides(a: &[u64], b: &[u64]) -> bool {
+    a.iter().zip(b).all(|(x, y)| x <= y)
+}
+
+#[inline]
+fn lcm(a: &[u64], b: &[u64]) -> Vec<u64> {
+    a.iter().zip(b).map(|(x, y)| *x.max(y)).collect()
+}
+
+#[inline]
+fn quotient(a: &[u64], b: &[u64]) -> Vec<u64> {
+    a.iter().zip(b).map(|(x, y)| x - y).collect()
+}
+
+#[inline]
+fn degree(a: &[u64]) -> u64 {
+    a.iter().sum()
+}
+
+fn make_monic<T: Field>(f: &SparseMPoly<T>) -> SparseMPoly<T> {
+    match f.lt() {
+        Some((_, c)) if !c.is_one() => {
+            let mut inv = c.clone();
+            inv.inv_assign();
+            f.mul_coeff(&inv)
+        }
+        _ => f.clone(),
+    }
+}
+
+/// Return the normal form of `f` with respect to the monic polynomials `basis`,
+/// that is, the remainder of the multivariate division algorithm in which every
+/// term is fully reduced.
+fn normal_form<T: Field>(f: &SparseMPoly<T>, basis: &[SparseMPoly<T>]) -> SparseMPoly<T> {
+    let ring = f.parent();
+    let mut p = f.clone();
+    let mut rem = Vec::new();
+    while let Some((m, c)) = p.lt().cloned() {
+        match basis.iter().find(|g| divides(&g.terms()[0].0, &m)) {
+            Some(g) => {
+                let mut c = c;
+                c.neg_assign();
+                p += &g.mul_term(&c, &quotient(&m, &g.terms()[0].0));
+            }
+            None => {
+                p -= &ring.monomial(c.clone(), &m);
+                rem.push((m, c));
+            }
+        }
+    }
+    ring.new(rem)
+}
+
+/// Return the S-polynomial of the monic polynomials `f` and `g`.
+fn s_polynomial<T: Field>(f: &SparseMPoly<T>, g: &SparseMPoly<T>) -> SparseMPoly<T> {
+    let (mf, mg) = (&f.terms()[0].0, &g.terms()[0].0);
+    let l = lcm(mf, mg);
+    let one = f.base_ring().one();
+    let mut res = f.mul_term(&one, &quotient(&l, mf));
+    res -= &g.mul_term(&one, &quotient(&l, mg));
+    res
+}
+
+/// The state shared by both algorithms: the current basis, the sugar of each
+/// basis element and the pending critical pairs.
+struct PairSet<T: Field> {
+    polys: Vec<SparseMPoly<T>>,
+    sugar: Vec<u64>,
+    pairs: Vec<CriticalPair>,
+    pending: HashSet<(usize, usize)>,
+}
+
+impl<T: Field> PairSet<T> {
+    fn new() -> Self {
+        PairSet { polys: vec![], sugar: vec![], pairs: vec![], pending: HashSet::new() }
+    }
+
+    #[inline]
+    fn lm(&self, i: usize) -> &[u64] {
+        &self.polys[i].terms()[0].0
+    }
+
+    /// Add a nonzero polynomial to the basis, creating its critical pairs.
+    fn insert(&mut self, f: SparseMPoly<T>, sugar: u64) {
+        let f = make_monic(&f);
+        let j = self.polys.len();
+        let lm = f.terms()[0].0.clone();
+        for i in 0..j {
+            let l = lcm(self.lm(i), &lm);
+            let d = degree(&l);
+            let s = (self.sugar[i] + d - degree(self.lm(i))).max(sugar + d - degree(&lm));
+            self.pairs.push(CriticalPair { i, j, lcm: l, sugar: s });
+            self.pending.insert((i, j));
+        }
+        self.polys.push(f);
+        self.sugar.push(sugar);
+    }
+
+    /// Remove and return the pending pair of minimal sugar, breaking ties using
+    /// the monomial ordering on the lcm.
+    fn pop_min(&mut self) -> Option<CriticalPair> {
+        let ord = self.polys.first()?.parent().ordering();
+        let k = (0..self.pairs.len()).min_by(|&a, &b| {
+            let (p, q) = (&self.pairs[a], &self.pairs[b]);
+            p.sugar.cmp(&q.sugar).then_with(|| ord.cmp(&p.lcm, &q.lcm))
+        })?;
+        let pair = self.pairs.swap_remove(k);
+        self.pending.remove(&(pair.i, pair.j));
+        Some(pair)
+    }
+
+    /// Return `true` if the pair can be discarded by Buchberger's product
+    /// criterion (coprime leading monomials) or chain criterion.
+    fn is_useless(&self, pair: &CriticalPair) -> bool {
+        let (i, j) = (pair.i, pair.j);
+        if degree(&pair.lcm) == degree(self.lm(i)) + degree(self.lm(j)) {
+            return true;
+        }
+        let key = |a: usize, b: usize| (a.min(b), a.max(b));
+        (0..self.polys.len()).any(|k| {
+            k != i && k != j
+                && divides(self.lm(k), &pair.lcm)
+                && !self.pending.contains(&key(i, k))
+                && !self.pending.contains(&key(j, k))
+        })
+    }
+}
+
+/// Reduce each S-polynomial individually, selecting pairs by sugar degree.
+fn buchberger<T: Field>(set: &mut PairSet<T>) {
+    while let Some(pair) = set.pop_min() {
+        if set.is_useless(&pair) {
+            continue;
+        }
+        let s = s_polynomial(&set.polys[pair.i], &set.polys[pair.j]);
+        let r = normal_form(&s, &set.polys);
+        if !r.is_zero() {
+            set.insert(r, pair.sugar);
+        }
+    }
+}
+
+/// Reduce all pairs of minimal sugar at once by row reducing a Macaulay matrix.
+fn f4<T: Field>(set: &mut PairSet<T>) {
+    let ring = match set.polys.first() {
+        Some(f) => f.parent(),
+        None => return,
+    };
+    let field = ring.base_ring().clone();
+    let ord = ring.ordering();
+    let one = field.one();
+
+    loop {
+        // Selection: all useful pairs of minimal sugar.
+        let d = match set.pairs.iter().map(|p| p.sugar).min() {
+            Some(d) => d,
+            None => return,
+        };
+        let mut selected = Vec::new();
+        while set.pairs.iter().any(|p| p.sugar == d) {
+            let pair = set.pop_min().unwrap();
+            if !set.is_useless(&pair) {
+                selected.push(pair);
+            }
+        }
+        if selected.is_empty() {
+            continue;
+        }
+
+        // Symbolic preprocessing: add a reducer for every monomial that is
+        // divisible by a leading monomial.
+        let mut rows: Vec<SparseMPoly<T>> = Vec::new();
+        let mut seen_rows = HashSet::new();
+        let mut push_row = |rows: &mut Vec<SparseMPoly<T>>, k: usize, m: Vec<u64>| {
+            if seen_rows.insert((k, m.clone())) {
+                rows.push(set.polys[k].mul_term(&one, &m));
+            }
+        };
+        for pair in selected.iter() {
+            for k in [pair.i, pair.j] {
+                push_row(&mut rows, k, quotient(&pair.lcm, set.lm(k)));
+            }
+        }
+
+        let mut done = HashSet::new();
+        let mut r = 0;
+        while r < rows.len() {
+            let monomials: Vec<Vec<u64>> = rows[r].terms().iter().map(|t| t.0.clone()).collect();
+            for m in monomials {
+                if !done.insert(m.clone()) {
+                    continue;
+                }
+                if let Some(k) = (0..set.polys.len()).find(|&k| divides(set.lm(k), &m)) {
+                    push_row(&mut rows, k, quotient(&m, set.lm(k)));
+                }
+            }
+            r += 1;
+        }
+
+        let leads: HashSet<Vec<u64>> = rows.iter().map(|f| f.terms()[0].0.clone()).collect();
+
+        // Build the matrix with columns indexed by monomials in decreasing order.
+        let mut columns: Vec<Vec<u64>> = done.into_iter().collect();
+        columns.sort_by(|a, b| ord.cmp(b, a));
+        let index = |m: &Vec<u64>| columns.binary_search_by(|c| ord.cmp(m, c)).unwrap();
+        let mut matrix: Vec<Vec<Elem<T>>> = rows.iter()
+            .map(|f| {
+                let mut row = vec![field.zero(); columns.len()];
+                for (m, c) in f.terms() {
+                    row[index(m)] = c.clone();
+                }
+                row
+            })
+            .collect();
+
+        // Reduced row echelon form.
+        let mut rank = 0;
+        for col in 0..columns.len() {
+            let pivot = match (rank..matrix.len()).find(|&r| !matrix[r][col].is_zero()) {
+                Some(p) => p,
+                None => continue,
+            };
+            matrix.swap(rank, pivot);
+            let mut inv = matrix[rank][col].clone();
+            inv.inv_assign();
+            for x in matrix[rank].iter_mut() {
+                *x *= &inv;
+            }
+            let pivot_row = matrix[rank].clone();
+            for (r, row) in matrix.iter_mut().enumerate() {
+                if r == rank || row[col].is_zero() {
+                    continue;
+                }
+                let c = row[col].clone();
+                for (x, p) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                    let mut t = p.clone();
+                    t *= &c;
+                    *x -= &t;
+                }
+            }
+            rank += 1;
+        }
+
+        // Rows with new leading monomials are added to the basis.
+        for row in matrix.into_iter().take(rank) {
+            let terms: Vec<(Vec<u64>, Elem<T>)> = row.into_iter()
+                .zip(columns.iter())
+                .filter(|(c, _)| !c.is_zero())
+                .map(|(c, m)| (m.clone(), c))
+                .collect();
+            if !leads.contains(&terms[0].0) {
+                set.insert(ring.new(terms), d);
+            }
+        }
+    }
+}
+
+/// Turn a Gröbner basis into the reduced Gröbner basis.
+fn reduce_basis<T: Field>(polys: Vec<SparseMPoly<T>>) -> Vec<SparseMPoly<T>> {
+    let ord = match polys.first() {
+        Some(f) => f.parent().ordering(),
+        None => return polys,
+    };
+
+    // Minimal basis: discard elements whose leading monomial is divisible by the
+    // leading monomial of another element. Sorting first means that of several
+    // elements with the same leading monomial only the first is kept.
+    let mut polys = polys;
+    polys.sort_by(|f, g| ord.cmp(&f.terms()[0].0, &g.terms()[0].0));
+    let mut minimal: Vec<SparseMPoly<T>> = Vec::new();
+    for f in polys {
+        if !minimal.iter().any(|g| divides(&g.terms()[0].0, &f.terms()[0].0)) {
+            minimal.push(f);
+        }
+    }
+
+    // Interreduce: no term of any element is divisible by the leading monomial of
+    // another element.
+    for k in 0..minimal.len() {
+        let f = minimal[k].clone();
+        let others: Vec<SparseMPoly<T>> = minimal.iter()
+            .enumerate()
+            .filter(|(i, _)| *i != k)
+            .map(|(_, g)| g.clone())
+            .collect();
+        let lt = f.leading_term();
+        minimal[k] = make_monic(&(&lt + &normal_form(&(&f - &lt), &others)));
+    }
+    minimal
+}
+
+impl<T: Field> GroebnerBasis<T> {
+    /// Compute the reduced Gröbner basis of the ideal of `ring` generated by `gens`
+    /// using Buchberger's algorithm.
+    pub fn new(ring: &SparseMPolyRing<T>, gens: &[SparseMPoly<T>]) -> Self {
+        GroebnerBasis::with_algorithm(ring, gens, GroebnerAlgorithm::Buchberger)
+    }
+
+    /// Compute the reduced Gröbner basis of the ideal of `ring` generated by `gens`
+    /// using the given algorithm.
+    pub fn with_algorithm(
+        ring: &SparseMPolyRing<T>,
+        gens: &[SparseMPoly<T>],
+        algorithm: GroebnerAlgorithm
+    ) -> Self {
+        let mut set = PairSet::new();
+        for f in gens.iter().map(|f| ring.new(f)).filter(|f| !f.is_zero()) {
+            let sugar = f.total_degree() as u64;
+            set.insert(f, sugar);
+        }
+        match algorithm {
+            GroebnerAlgorithm::Buchberger => buchberger(&mut set),
+            GroebnerAlgorithm::F4 => f4(&mut set),
+        }
+        GroebnerBasis { ring: ring.clone(), basis: reduce_basis(set.polys) }
+    }
+
+    /// Return the polynomial ring containing the ideal.
+    #[inline]
+    pub fn ring(&self) -> &SparseMPolyRing<T> {
+        &self.ring
+    }
+
+    /// Return the elements of the reduced Gröbner basis.
+    #[inline]
+    pub fn basis(&self) -> &[SparseMPoly<T>] {
+        &self.basis
+    }
+
+    /// Return the normal form of `f` modulo the ideal. This is zero if and only if
+    /// `f` lies in the ideal, and two polynomials are congruent modulo the ideal if
+    /// and only if they have the same normal form.
+    pub fn reduce(&self, f: &SparseMPoly<T>) -> SparseMPoly<T> {
+        normal_form(&self.ring.new(f), &self.basis)
+    }
+
+    /// Return `true` if `f` lies in the ideal.
+    #[inline]
+    pub fn contains(&self, f: &SparseMPoly<T>) -> bool {
+        self.reduce(f).is_zero()
+    }
+
+    /// Return `true` if the ideal is the whole ring.
+    #[inline]
+    pub fn is_unit_ideal(&self) -> bool {
+        self.basis.len() == 1 && self.basis[0].is_one()
+    }
+
+    /// Return `true` if the ideal is zero.
+    #[inline]
+    pub fn is_zero_ideal(&self) -> bool {
+        self.basis.is_empty()
+    }
+
+    /// Return the `k`-th elimination ideal, that is, the intersection of the ideal
+    /// with the polynomials in the variables `k, k + 1, ...`. By the elimination
+    /// theorem this is generated by the elements of a lexicographic Gröbner basis
+    /// not involving the first `k` variables.
+    ///
+    /// The result is a lexicographic Gröbner basis, in the ring with the same
+    /// variables as `self` and the `Lex` ordering.
+    pub fn elimination_ideal(&self, k: usize) -> GroebnerBasis<T> {
+        let lex = if self.ring.ordering() == MonomialOrdering::Lex {
+            self.clone()
+        } else {
+            let ring = self.ring.with_ordering(MonomialOrdering::Lex);
+            GroebnerBasis::new(&ring, &self.basis)
+        };
+        let basis = lex.basis.into_iter()
+            .filter(|f| (0..k).all(|i| f.degree_in(i) <= 0))
+            .collect();
+        GroebnerBasis { ring: lex.ring, basis }
+    }
+
+    /// Return the leading monomials of the basis, which generate the initial
+    /// ideal.
+    pub fn leading_monomials(&self) -> BTreeSet<Vec<u64>> {
+        self.basis.iter().map(|f| f.terms()[0].0.clone()).collect()
+    }
+}
@@ -0,0 +1,353 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+
+/// An ideal of a Euclidean domain, which is always principal. The generator is
+/// kept as its canonical associate, so two ideals are equal if and only if their
+/// generators are.
+#[derive(Clone)]
+pub struct PrincipalIdeal<R: EuclideanDomain> {
+    ring: R,
+    gen: Elem<R>,
+}
+
+/// An ideal of a multivariate polynomial ring over a field, represented by its
+/// reduced Gröbner basis. Two ideals are equal if and only if their reduced
+/// Gröbner bases are.
+#[derive(Clone)]
+pub struct MPolyIdeal<T: Field> {
+    gb: GroebnerBasis<T>,
+}
+
+impl<R: EuclideanDomain> PrincipalIdeal<R> {
+    /// Initialize the ideal of `ring` generated by `gen`.
+    pub fn new(ring: &R, gen: &Elem<R>) -> Self {
+        PrincipalIdeal { ring: ring.clone(), gen: ring.normalize(gen) }
+    }
+
+    /// Return the canonical generator.
+    #[inline]
+    pub fn generator(&self) -> &Elem<R> {
+        &self.gen
+    }
+
+    /// Return the quotient of the ring by the ideal. Panics if the ideal is zero.
+    pub fn quotient_ring(&self) -> QuotientRing<R> {
+        QuotientRing::init(&self.ring, &self.gen)
+    }
+}
+
+impl<R: EuclideanDomain> PartialEq for PrincipalIdeal<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.gen == other.gen
+    }
+}
+
+impl<R: EuclideanDomain> fmt::Debug for PrincipalIdeal<R>
+where
+    <R as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PrincipalIdeal")
+            .field("gen", &self.gen)
+            .finish()
+    }
+}
+
+impl<R: EuclideanDomain> Ideal<R> for PrincipalIdeal<R> {
+    #[inline]
+    fn ring(&self) -> &R {
+        &self.ring
+    }
+
+    #[inline]
+    fn gens(&self) -> Vec<Elem<R>> {
+        vec![self.gen.clone()]
+    }
+
+    #[inline]
+    fn contains(&self, x: &Elem<R>) -> bool {
+        self.ring.divides(&self.gen, x)
+    }
+
+    /// `(a) + (b) = (gcd(a, b))`
+    #[inline]
+    fn sum(&self, other: &Self) -> Self {
+        PrincipalIdeal { ring: self.ring.clone(), gen: self.ring.gcd(&self.gen, &other.gen) }
+    }
+
+    /// `(a)(b) = (ab)`
+    fn product(&self, other: &Self) -> Self {
+        let mut gen = self.gen.clone();
+        gen *= &other.gen;
+        PrincipalIdeal::new(&self.ring, &gen)
+    }
+
+    /// `(a) ∩ (b) = (lcm(a, b))`
+    #[inline]
+    fn intersection(&self, other: &Self) -> Self {
+        PrincipalIdeal { ring: self.ring.clone(), gen: self.ring.lcm(&self.gen, &other.gen) }
+    }
+
+    /// `((a) : (b)) = (a/gcd(a, b))`, and `((a) : 0)` is the whole ring.
+    fn quotient(&self, other: &Self) -> Self {
+        if other.gen.is_zero() {
+            return PrincipalIdeal { ring: self.ring.clone(), gen: self.ring.one() };
+        }
+        let g = self.ring.gcd(&self.gen, &other.gen);
+        PrincipalIdeal::new(&self.ring, &self.ring.divexact(&self.gen, &g))
+    }
+
+    /// In a Euclidean domain a nonzero ideal is prime if and only if it is
+    /// generated by an irreducible element, and the zero ideal is prime.
+    fn is_prime(&self) -> Option<bool> {
+        if self.gen.is_zero() {
+            Some(true)
+        } else {
+            self.ring.is_irreducible(&self.gen)
+        }
+    }
+
+    /// Nonzero prime ideals are maximal. Whether the zero ideal is maximal
+    /// depends on whether the ring is a field, which is not decided.
+    fn is_maximal(&self) -> Option<bool> {
+        if self.gen.is_zero() {
+            None
+        } else {
+            self.ring.is_irreducible(&self.gen)
+        }
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.gen.is_zero()
+    }
+
+    #[inline]
+    fn is_whole_ring(&self) -> bool {
+        self.gen.is_one()
+    }
+}
+
+/// Return `f/g`. Panics if `g` does not divide `f`.
+fn divexact<T: Field>(f: &SparseMPoly<T>, g: &SparseMPoly<T>) -> SparseMPoly<T> {
+    let ring = f.parent();
+    let (gm, gc) = g.lt().expect("division by zero");
+    let mut inv = gc.clone();
+    inv.inv_assign();
+
+    let mut p = f.clone();
+    let mut quo = Vec::new();
+    while let Some((m, c)) = p.lt().cloned() {
+        assert!(gm.iter().zip(m.iter()).all(|(a, b)| a <= b), "inexact division");
+        let e: Vec<u64> = m.iter().zip(gm.iter()).map(|(a, b)| a - b).collect();
+        let mut c = c;
+        c *= &inv;
+        quo.push((e.clone(), c.clone()));
+        c.neg_assign();
+        p += &g.mul_term(&c, &e);
+    }
+    ring.new(quo)
+}
+
+impl<T: Field> MPolyIdeal<T> {
+    /// Initialize the ideal of `ring` generated by `gens`, computing its reduced
+    /// Gröbner basis.
+    pub fn new(ring: &SparseMPolyRing<T>, gens: &[SparseMPoly<T>]) -> Self {
+        MPolyIdeal { gb: GroebnerBasis::new(ring, gens) }
+    }
+
+    /// Return the reduced Gröbner basis of the ideal.
+    #[inline]
+    pub fn groebner_basis(&self) -> &GroebnerBasis<T> {
+        &self.gb
+    }
+
+    /// Return the normal form of `f` modulo the ideal.
+    #[inline]
+    pub fn reduce(&self, f: &SparseMPoly<T>) -> SparseMPoly<T> {
+        self.gb.reduce(f)
+    }
+
+    /// Return the `k`-th elimination ideal, the intersection with the polynomials
+    /// in the variables `k, k + 1, ...`, as an ideal of the ring with the `Lex`
+    /// ordering.
+    pub fn elimination_ideal(&self, k: usize) -> Self {
+        MPolyIdeal { gb: self.gb.elimination_ideal(k) }
+    }
+}
+
+impl<T: Field> PartialEq for MPolyIdeal<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let ring = self.gb.ring();
+        if ring.ordering() == other.gb.ring().ordering() {
+            self.gb.basis() == other.gb.basis()
+        } else {
+            self.contains_ideal(other) && other.contains_ideal(self)
+        }
+    }
+}
+
+impl<T: Field> fmt::Debug for MPolyIdeal<T>
+where
+    <T as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let basis: Vec<_> = self.gb.basis().iter().map(|g| g.terms()).collect();
+        f.debug_struct("MPolyIdeal")
+            .field("vars", &self.gb.ring().vars())
+            .field("basis", &basis)
+            .finish()
+    }
+}
+
+impl<T: Field> Ideal<SparseMPolyRing<T>> for MPolyIdeal<T> {
+    #[inline]
+    fn ring(&self) -> &SparseMPolyRing<T> {
+        self.gb.ring()
+    }
+
+    #[inline]
+    fn gens(&self) -> Vec<SparseMPoly<T>> {
+        self.gb.basis().to_vec()
+    }
+
+    #[inline]
+    fn contains(&self, x: &SparseMPoly<T>) -> bool {
+        self.gb.contains(x)
+    }
+
+    fn sum(&self, other: &Self) -> Self {
+        let gens: Vec<_> = self.gens().into_iter().chain(other.gens()).collect();
+        MPolyIdeal::new(self.ring(), &gens)
+    }
+
+    fn product(&self, other: &Self) -> Self {
+        let mut gens = Vec::new();
+        for f in self.gb.basis() {
+            for g in other.gb.basis() {
+                gens.push(f * g);
+            }
+        }
+        MPolyIdeal::new(self.ring(), &gens)
+    }
+
+    /// Compute `I ∩ J` by eliminating `t` from `tI + (1 - t)J`.
+    fn intersection(&self, other: &Self) -> Self {
+        let ring = self.ring();
+        let vars = std::iter::once("_t".to_string()).chain(ring.vars());
+        let big = SparseMPolyRing::init(ring.base_ring(), vars, MonomialOrdering::Lex);
+        let lift = |f: &SparseMPoly<T>, t: u64| {
+            let terms = f.terms().iter()
+                .map(|(m, c)| (std::iter::once(t).chain(m.iter().copied()).collect(), c.clone()))
+                .collect::<Vec<_>>();
+            big.new(terms)
+        };
+
+        let mut gens = Vec::new();
+        for f in self.gb.basis() {
+            gens.push(lift(f, 1));
+        }
+        for g in other.gb.basis() {
+            gens.push(&lift(g, 0) - &lift(g, 1));
+        }
+        let elim = GroebnerBasis::new(&big, &gens).elimination_ideal(1);
+        let gens: Vec<_> = elim.basis().iter()
+            .map(|f| {
+                let terms = f.terms().iter()
+                    .map(|(m, c)| (m[1..].to_vec(), c.clone()))
+                    .collect::<Vec<_>>();
+                ring.new(terms)
+            })
+            .collect();
+        MPolyIdeal::new(ring, &gens)
+    }
+
+    /// Compute `(I : J)` as the intersection of `(I : g) = (I ∩ (g))/g` over the
+    /// generators `g` of `J`.
+    fn quotient(&self, other: &Self) -> Self {
+        let ring = self.ring();
+        let mut res = MPolyIdeal::new(ring, &[ring.one()]);
+        for g in other.gb.basis() {
+            let principal = MPolyIdeal::new(ring, std::slice::from_ref(g));
+            let gens: Vec<_> = self.intersection(&principal).gb.basis().iter()
+                .map(|f| divexact(f, g))
+                .collect();
+            res = res.intersection(&MPolyIdeal::new(ring, &gens));
+        }
+        res
+    }
+
+    /// Decided for the zero ideal, the whole ring and ideals generated by linear
+    /// polynomials, whose quotients are polynomial rings.
+    fn is_prime(&self) -> Option<bool> {
+        if self.gb.is_unit_ideal() {
+            Some(false)
+        } else if self.gb.basis().iter().all(|f| f.total_degree() == 1) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    /// Decided for the zero ideal, the whole ring and ideals generated by linear
+    /// polynomials. The latter are maximal exactly when there is one generator
+    /// per variable, in which case the quotient is the base field.
+    fn is_maximal(&self) -> Option<bool> {
+        let basis = self.gb.basis();
+        if self.gb.is_unit_ideal() {
+            Some(false)
+        } else if basis.iter().all(|f| f.total_degree() == 1) {
+            Some(basis.len() as i64 == self.ring().nvars())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.gb.is_zero_ideal()
+    }
+
+    #[inline]
+    fn is_whole_ring(&self) -> bool {
+        self.gb.is_unit_ideal()
+    }
+}
+
+impl<R: EuclideanDomain> Add for &PrincipalIdeal<R> {
+    type Output = PrincipalIdeal<R>;
+
+    #[inline]
+    fn add(self, rhs: Self) -> PrincipalIdeal<R> {
+        self.sum(rhs)
+    }
+}
+
+impl<R: EuclideanDomain> Mul for &PrincipalIdeal<R> {
+    type Output = PrincipalIdeal<R>;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> PrincipalIdeal<R> {
+        self.product(rhs)
+    }
+}
+
+impl<T: Field> Add for &MPolyIdeal<T> {
+    type Output = MPolyIdeal<T>;
+
+    #[inline]
+    fn add(self, rhs: Self) -> MPolyIdeal<T> {
+        self.sum(rhs)
+    }
+}
+
+impl<T: Field> Mul for &MPolyIdeal<T> {
+    type Output = MPolyIdeal<T>;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> MPolyIdeal<T> {
+        self.product(rhs)
+    }
+}
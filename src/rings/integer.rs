@@ -0,0 +1,343 @@
+use crate::*;
+use crate::ops::*;
+
+/// The ring of integers, with elements represented by `i64`.
+///
+/// Arithmetic is that of `i64`, so results are only correct as long as they fit in
+/// 64 bits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntegerRing;
+
+impl IntegerRing {
+    pub fn init() -> Self {
+        IntegerRing
+    }
+}
+
+impl Parent for IntegerRing {
+    type Element = i64;
+}
+
+impl Element for i64 {
+    type Parent = IntegerRing;
+
+    #[inline]
+    fn parent(&self) -> IntegerRing {
+        IntegerRing
+    }
+}
+
+impl NewElement<i64> for IntegerRing {
+    #[inline]
+    fn new(&self, src: i64) -> i64 {
+        src
+    }
+}
+
+// Additive properties
+
+impl Operation<Additive> for i64 {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+}
+
+impl Identity<Additive> for IntegerRing {
+    #[inline]
+    fn identity(&self) -> i64 {
+        0
+    }
+}
+
+impl IsIdentity<Additive> for i64 {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        *self == 0
+    }
+}
+
+impl TwoSidedInverse<Additive> for i64 {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self
+    }
+}
+
+impl Divisible<Additive> for IntegerRing {}
+
+impl Associative<Additive> for IntegerRing {}
+
+impl Commutative<Additive> for IntegerRing {}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for i64 {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+}
+
+impl Identity<Multiplicative> for IntegerRing {
+    #[inline]
+    fn identity(&self) -> i64 {
+        1
+    }
+}
+
+impl IsIdentity<Multiplicative> for i64 {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        *self == 1
+    }
+}
+
+impl Associative<Multiplicative> for IntegerRing {}
+
+impl Commutative<Multiplicative> for IntegerRing {}
+
+// Ring-like properties
+
+impl Distributive for IntegerRing {}
+
+// Ops not provided by `std`
+
+impl<'a> AddFrom<&'a i64> for i64 {
+    #[inline]
+    fn add_from(&mut self, lhs: &'a i64) {
+        *self += lhs;
+    }
+}
+
+impl<'a> SubFrom<&'a i64> for i64 {
+    #[inline]
+    fn sub_from(&mut self, lhs: &'a i64) {
+        *self = lhs - *self;
+    }
+}
+
+impl<'a> MulFrom<&'a i64> for i64 {
+    #[inline]
+    fn mul_from(&mut self, lhs: &'a i64) {
+        *self *= lhs;
+    }
+}
+
+impl NegAssign for i64 {
+    #[inline]
+    fn neg_assign(&mut self) {
+        *self = -*self;
+    }
+}
+
+// Euclidean structure
+
+#[inline]
+pub(crate) fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+pub(crate) fn pow_mod_u64(mut a: u64, mut e: u64, m: u64) -> u64 {
+    let mut res = 1 % m;
+    while e > 0 {
+        if e & 1 == 1 {
+            res = mul_mod_u64(res, a, m);
+        }
+        a = mul_mod_u64(a, a, m);
+        e >>= 1;
+    }
+    res
+}
+
+pub(crate) fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    for p in BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for a in BASES {
+        let mut x = pow_mod_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod_u64(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Return a nontrivial factor of an odd composite `n`, using Pollard's rho
+/// method with Brent's cycle detection.
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let f = |x: u64| (mul_mod_u64(x, x, n) + c) % n;
+        let (mut y, mut g) = (2, 1);
+        let mut x;
+        let mut r = 1;
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                let ys = y;
+                let mut q = 1;
+                for _ in 0..(r - k).min(128) {
+                    y = f(y);
+                    q = mul_mod_u64(q, x.abs_diff(y), n);
+                }
+                g = gcd_u64(q, n);
+                if g == n {
+                    // The batch overshot, so retrace it one step at a time.
+                    y = ys;
+                    loop {
+                        y = f(y);
+                        g = gcd_u64(x.abs_diff(y), n);
+                        if g != 1 {
+                            break;
+                        }
+                    }
+                }
+                k += 128;
+            }
+            r *= 2;
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+/// Return the prime factorization of `n > 0` as pairs `(p, e)` with increasing
+/// `p`.
+pub(crate) fn factor_u64(mut n: u64) -> Vec<(u64, u32)> {
+    let mut primes = vec![];
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime_u64(m) {
+            primes.push(m);
+        } else {
+            let d = pollard_rho(m);
+            stack.push(d);
+            stack.push(m / d);
+        }
+    }
+    primes.sort_unstable();
+    let mut res: Vec<(u64, u32)> = vec![];
+    for p in primes {
+        match res.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => res.push((p, 1)),
+        }
+    }
+    res
+}
+
+impl IntegerRing {
+    /// Return `true` if `|n|` is prime, using a Miller-Rabin test with a set of
+    /// bases which is deterministic for all 64-bit integers.
+    #[inline]
+    pub fn is_prime(&self, n: &i64) -> bool {
+        is_prime_u64(n.unsigned_abs())
+    }
+
+    /// Return the prime factorization of `|n|` as pairs `(p, e)` with increasing
+    /// `p`, using trial division and Pollard's rho method. Panics if `n` is zero.
+    pub fn factor(&self, n: &i64) -> Vec<(i64, u32)> {
+        assert!(*n != 0, "factorization of zero");
+        factor_u64(n.unsigned_abs()).into_iter().map(|(p, e)| (p as i64, e)).collect()
+    }
+}
+
+/// Division rounds towards negative infinity relative to the divisor so that
+/// remainders lie in `[0, |b|)`, which makes them canonical representatives.
+impl EuclideanDomain for IntegerRing {
+    #[inline]
+    fn div_rem(&self, a: &i64, b: &i64) -> (i64, i64) {
+        assert!(*b != 0, "division by zero");
+        (a.div_euclid(*b), a.rem_euclid(*b))
+    }
+
+    #[inline]
+    fn canonical_unit(&self, a: &i64) -> i64 {
+        if *a < 0 { -1 } else { 1 }
+    }
+
+    #[inline]
+    fn is_irreducible(&self, a: &i64) -> Option<bool> {
+        Some(self.is_prime(a))
+    }
+
+    #[inline]
+    fn is_unit(&self, a: &i64) -> bool {
+        a.abs() == 1
+    }
+
+    /// Add with 128-bit intermediates, so this never overflows.
+    #[inline]
+    fn add_mod(&self, a: &i64, b: &i64, m: &i64) -> i64 {
+        assert!(*m != 0, "division by zero");
+        (*a as i128 + *b as i128).rem_euclid(m.unsigned_abs() as i128) as i64
+    }
+
+    /// Multiply with 128-bit intermediates, so this never overflows.
+    #[inline]
+    fn mul_mod(&self, a: &i64, b: &i64, m: &i64) -> i64 {
+        assert!(*m != 0, "division by zero");
+        (*a as i128 * *b as i128).rem_euclid(m.unsigned_abs() as i128) as i64
+    }
+
+    /// Compute `a*b - c*d` in 128 bits, so this only overflows if the quotient
+    /// does not fit in an `i64`.
+    fn mul_sub_divexact(&self, a: &i64, b: &i64, c: &i64, d: &i64, e: &i64) -> i64 {
+        assert!(*e != 0, "division by zero");
+        let t = *a as i128 * *b as i128 - *c as i128 * *d as i128;
+        let e = *e as i128;
+        assert!(t % e == 0, "inexact division");
+        i64::try_from(t / e).expect("quotient does not fit in an i64")
+    }
+
+    /// Compute modulo two primes near `2^62` and recover the determinant by the
+    /// Chinese remainder theorem, so only the result has to fit. Returns `None`
+    /// if Hadamard's bound for the chosen rows exceeds `2^122`.
+    fn lattice_det(&self, a: &[i64], m: usize, n: usize) -> Option<i64> {
+        lattice_det_multimodular(a, m, n)
+    }
+
+    /// Solve modulo two primes near `2^62` and recover `X` by the Chinese
+    /// remainder theorem, checking `X A = B` exactly. Returns `None` if the
+    /// entries of `X` do not fit in an `i64`.
+    fn solve_left_exact(&self, a: &[i64], b: &[i64], n: usize) -> Option<Vec<i64>> {
+        solve_left_multimodular(a, b, n)
+    }
+}
@@ -0,0 +1,410 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Truncated Laurent series over a field. The parent has a precision cap `n`
+/// bounding the relative precision of its elements, so that an exact element of
+/// valuation `v` is known modulo `O(x^(v + n))`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaurentSeriesRing<F: Field> {
+    field: F,
+    var: Arc<str>,
+    prec: u64,
+}
+
+/// An element of a `LaurentSeriesRing`, stored as `x^v * (c_0 + c_1 x + ...)`
+/// together with an absolute precision `p`, representing the series modulo
+/// `O(x^p)`.
+///
+/// The leading coefficient `c_0` is nonzero and there are no trailing zeros. A
+/// series which is zero to its precision has no coefficients and valuation `p`.
+/// A precision of `i64::MAX` marks an exact series; nonzero series are truncated
+/// to the precision cap, so only zero is ever exact.
+/// Two series compare equal if their coefficients agree up to the smaller of
+/// their precisions.
+#[derive(Clone)]
+pub struct LaurentSeries<F: Field> {
+    parent: LaurentSeriesRing<F>,
+    val: i64,
+    coeffs: Vec<Elem<F>>,
+    prec: i64,
+}
+
+impl<F: Field> LaurentSeriesRing<F> {
+    /// Initialize the `LaurentSeriesRing` from a base field, a variable used for
+    /// printing and the precision cap.
+    pub fn init<S: Into<String>>(field: &F, var: S, prec: u64) -> Self {
+        LaurentSeriesRing { field: field.clone(), var: var.into().into(), prec }
+    }
+
+    /// Return a reference to the base field.
+    #[inline]
+    pub fn base_field(&self) -> &F {
+        &self.field
+    }
+
+    /// Return the variable as a `String`.
+    #[inline]
+    pub fn var(&self) -> String {
+        self.var.to_string()
+    }
+
+    /// Return the precision cap.
+    #[inline]
+    pub fn precision_cap(&self) -> u64 {
+        self.prec
+    }
+
+    /// Return the generator of the Laurent series ring.
+    pub fn gen(&self) -> LaurentSeries<F> {
+        self.series(1, vec![self.field.one()], i64::MAX)
+    }
+
+    /// Return `x^val * (c_0 + c_1 x + ...)` modulo `O(x^prec)`. The precision is
+    /// reduced to respect the precision cap if necessary.
+    pub fn series(&self, val: i64, coeffs: Vec<Elem<F>>, prec: i64) -> LaurentSeries<F> {
+        LaurentSeries::from_coeffs(self, val, coeffs, prec)
+    }
+
+    /// Return the exact zero, with valuation and precision `i64::MAX`.
+    #[inline]
+    fn exact_zero(&self) -> LaurentSeries<F> {
+        LaurentSeries { parent: self.clone(), val: i64::MAX, coeffs: vec![], prec: i64::MAX }
+    }
+}
+
+impl<F: Field> LaurentSeries<F> {
+    fn from_coeffs(parent: &LaurentSeriesRing<F>, val: i64, coeffs: Vec<Elem<F>>, prec: i64) -> Self {
+        let mut res = LaurentSeries { parent: parent.clone(), val, coeffs, prec };
+        res.normalize();
+        res
+    }
+
+    /// Strip leading and trailing zeros, adjusting the valuation, and truncate
+    /// to the precision and the precision cap.
+    fn normalize(&mut self) {
+        let lead = self.coeffs.iter().position(|c| !c.is_zero()).unwrap_or(self.coeffs.len());
+        self.coeffs.drain(..lead);
+        self.val += lead as i64;
+        if !self.coeffs.is_empty() {
+            self.prec = self.prec.min(self.val.saturating_add(self.parent.prec as i64));
+        }
+        let len = self.prec.saturating_sub(self.val).max(0) as usize;
+        self.coeffs.truncate(len);
+        while let Some(c) = self.coeffs.last() {
+            if c.is_zero() {
+                self.coeffs.pop();
+            } else {
+                break;
+            }
+        }
+        if self.coeffs.is_empty() {
+            self.val = self.prec;
+        }
+    }
+
+    /// Return `true` if the series is the exact zero.
+    #[inline]
+    fn is_exact_zero(&self) -> bool {
+        self.coeffs.is_empty() && self.prec == i64::MAX
+    }
+
+    /// Return the valuation, that is, the exponent of the first nonzero term, or
+    /// the precision if the series is zero to its precision.
+    #[inline]
+    pub fn valuation(&self) -> i64 {
+        self.val
+    }
+
+    /// Return the absolute precision `p` of the series, so that it is known
+    /// modulo `O(x^p)`.
+    #[inline]
+    pub fn prec(&self) -> i64 {
+        self.prec
+    }
+
+    /// Return the relative precision, that is, the number of known coefficients
+    /// starting from the valuation.
+    #[inline]
+    pub fn relative_prec(&self) -> i64 {
+        self.prec - self.val
+    }
+
+    /// Return a reference to the coefficients `c_0, c_1, ...` following the
+    /// valuation.
+    #[inline]
+    pub fn coefficients(&self) -> &[Elem<F>] {
+        &self.coeffs
+    }
+
+    /// Return the coefficient of `x^i`. Panics if `i` is not less than the
+    /// precision.
+    pub fn coeff(&self, i: i64) -> Elem<F> {
+        assert!(i < self.prec, "coefficient is beyond the precision of the series");
+        if i < self.val {
+            return self.parent.field.zero();
+        }
+        match self.coeffs.get((i - self.val) as usize) {
+            Some(c) => c.clone(),
+            None => self.parent.field.zero(),
+        }
+    }
+
+    /// Return the coefficient of `x^-1`.
+    #[inline]
+    pub fn residue(&self) -> Elem<F> {
+        self.coeff(-1)
+    }
+
+    /// Return the series modulo `O(x^n)`.
+    pub fn truncate(&self, n: i64) -> Self {
+        LaurentSeries::from_coeffs(&self.parent, self.val, self.coeffs.clone(), n.min(self.prec))
+    }
+
+    /// Return `x^k` times the series.
+    pub fn shift(&self, k: i64) -> Self {
+        if self.is_exact_zero() {
+            return self.clone();
+        }
+        LaurentSeries {
+            parent: self.parent.clone(),
+            val: self.val + k,
+            coeffs: self.coeffs.clone(),
+            prec: self.prec.saturating_add(k),
+        }
+    }
+
+    /// Return the formal derivative. The precision decreases by one.
+    pub fn derivative(&self) -> Self {
+        if self.is_exact_zero() {
+            return self.clone();
+        }
+        let field = &self.parent.field;
+        let coeffs = self.coeffs.iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let e = self.val + i as i64;
+                let mut t = integer(field, e.unsigned_abs());
+                if e < 0 {
+                    t.neg_assign();
+                }
+                t *= c;
+                t
+            })
+            .collect();
+        LaurentSeries::from_coeffs(&self.parent, self.val - 1, coeffs, self.prec.saturating_sub(1))
+    }
+
+    /// Return the series as a power series, or `None` if the valuation is
+    /// negative.
+    pub fn power_series(&self, ring: &PowerSeriesRing<F>) -> Option<PowerSeries<F>> {
+        if self.val < 0 {
+            return None;
+        }
+        if self.coeffs.is_empty() {
+            return Some(ring.series(vec![], self.prec as u64));
+        }
+        let mut coeffs = vec![self.parent.field.zero(); self.val as usize];
+        coeffs.extend(self.coeffs.iter().cloned());
+        Some(ring.series(coeffs, self.prec as u64))
+    }
+}
+
+impl<F: Field> PartialEq for LaurentSeries<F> {
+    fn eq(&self, other: &Self) -> bool {
+        let prec = self.prec.min(other.prec);
+        let lo = self.val.min(other.val);
+        (lo..prec).all(|i| self.coeff(i) == other.coeff(i))
+    }
+}
+
+impl<F: Field> fmt::Debug for LaurentSeries<F>
+where
+    <F as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LaurentSeries")
+            .field("var", &self.parent.var)
+            .field("val", &self.val)
+            .field("coeffs", &self.coeffs)
+            .field("prec", &self.prec)
+            .finish()
+    }
+}
+
+impl<F: Field> fmt::Display for LaurentSeries<F>
+where
+    <F as Ring>::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_exact_zero() {
+            return write!(f, "0");
+        }
+        let var = &self.parent.var;
+        for (i, c) in self.coeffs.iter().enumerate() {
+            if c.is_zero() {
+                continue;
+            }
+            match self.val + i as i64 {
+                0 => write!(f, "{} + ", c)?,
+                1 => write!(f, "{}*{} + ", c, var)?,
+                e => write!(f, "{}*{}^{} + ", c, var, e)?,
+            }
+        }
+        write!(f, "O({}^{})", var, self.prec)
+    }
+}
+
+impl<F: Field> Parent for LaurentSeriesRing<F> {
+    type Element = LaurentSeries<F>;
+}
+
+impl<F: Field> Element for LaurentSeries<F> {
+    type Parent = LaurentSeriesRing<F>;
+
+    #[inline]
+    fn parent(&self) -> LaurentSeriesRing<F> {
+        self.parent.clone()
+    }
+}
+
+/// Construct the exact series with the given coefficients, starting from the
+/// constant term.
+impl<F: Field> NewElement<Vec<Elem<F>>> for LaurentSeriesRing<F> {
+    #[inline]
+    fn new(&self, src: Vec<Elem<F>>) -> LaurentSeries<F> {
+        LaurentSeries::from_coeffs(self, 0, src, i64::MAX)
+    }
+}
+
+/// Construct a Laurent series from a power series, keeping its precision.
+impl<'a, F: Field> NewElement<&'a PowerSeries<F>> for LaurentSeriesRing<F> {
+    #[inline]
+    fn new(&self, src: &'a PowerSeries<F>) -> LaurentSeries<F> {
+        LaurentSeries::from_coeffs(self, 0, src.coefficients().to_vec(), src.prec() as i64)
+    }
+}
+
+/// Construct the exact series from a polynomial.
+impl<'a, F: Field> NewElement<&'a GenericPoly<F>> for LaurentSeriesRing<F> {
+    #[inline]
+    fn new(&self, src: &'a GenericPoly<F>) -> LaurentSeries<F> {
+        LaurentSeries::from_coeffs(self, 0, src.coefficients().to_vec(), i64::MAX)
+    }
+}
+
+// Additive properties
+
+impl<F: Field> Operation<Additive> for LaurentSeries<F> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let prec = self.prec.min(rhs.prec);
+        if self.coeffs.is_empty() || rhs.coeffs.is_empty() {
+            let nonzero = if self.coeffs.is_empty() { rhs } else { self };
+            return nonzero.truncate(prec);
+        }
+        let val = self.val.min(rhs.val);
+        let end = (self.val + self.coeffs.len() as i64).max(rhs.val + rhs.coeffs.len() as i64);
+        let mut coeffs = vec![self.parent.field.zero(); (end.min(prec) - val).max(0) as usize];
+        for s in [self, rhs] {
+            let offset = (s.val - val) as usize;
+            for (c, d) in coeffs.iter_mut().skip(offset).zip(s.coeffs.iter()) {
+                *c += d;
+            }
+        }
+        LaurentSeries::from_coeffs(&self.parent, val, coeffs, prec)
+    }
+}
+
+impl<F: Field> Identity<Additive> for LaurentSeriesRing<F> {
+    #[inline]
+    fn identity(&self) -> LaurentSeries<F> {
+        self.exact_zero()
+    }
+}
+
+impl<F: Field> IsIdentity<Additive> for LaurentSeries<F> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+}
+
+impl<F: Field> TwoSidedInverse<Additive> for LaurentSeries<F> {
+    fn two_sided_inverse(&self) -> Self {
+        let mut res = self.clone();
+        for c in res.coeffs.iter_mut() {
+            c.neg_assign();
+        }
+        res
+    }
+}
+
+impl<F: Field> Divisible<Additive> for LaurentSeriesRing<F> {}
+
+impl<F: Field> Associative<Additive> for LaurentSeriesRing<F> {}
+
+impl<F: Field> Commutative<Additive> for LaurentSeriesRing<F> {}
+
+// Multiplicative properties
+
+/// The product of series known modulo `O(x^p)` and `O(x^q)` with valuations `v`
+/// and `w` is known modulo `O(x^min(p + w, q + v))`.
+impl<F: Field> Operation<Multiplicative> for LaurentSeries<F> {
+    fn operate(&self, rhs: &Self) -> Self {
+        if self.is_exact_zero() || rhs.is_exact_zero() {
+            return self.parent.exact_zero();
+        }
+        let prec = self.prec.saturating_add(rhs.val).min(rhs.prec.saturating_add(self.val));
+        let val = self.val.saturating_add(rhs.val);
+        let n = prec.saturating_sub(val).max(0).min(self.parent.prec as i64) as usize;
+        let coeffs = mul_trunc(&self.parent.field, &self.coeffs, &rhs.coeffs, n);
+        LaurentSeries::from_coeffs(&self.parent, val, coeffs, prec)
+    }
+}
+
+impl<F: Field> Identity<Multiplicative> for LaurentSeriesRing<F> {
+    #[inline]
+    fn identity(&self) -> LaurentSeries<F> {
+        LaurentSeries::from_coeffs(self, 0, vec![self.field.one()], i64::MAX)
+    }
+}
+
+impl<F: Field> IsIdentity<Multiplicative> for LaurentSeries<F> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.val == 0 && self.coeffs.len() == 1 && self.coeffs[0].is_one()
+    }
+}
+
+impl<F: Field> TwoSidedInverse<Multiplicative> for LaurentSeries<F> {
+    /// Return the inverse, which has the same relative precision. Panics if the
+    /// series is zero to its precision.
+    fn two_sided_inverse(&self) -> Self {
+        assert!(!self.coeffs.is_empty(), "division by zero");
+        let n = self.relative_prec().min(self.parent.prec as i64);
+        let coeffs = inverse_trunc(&self.parent.field, &self.coeffs, n as usize);
+        LaurentSeries::from_coeffs(&self.parent, -self.val, coeffs, n - self.val)
+    }
+}
+
+impl<F: Field> Divisible<Multiplicative> for LaurentSeriesRing<F> {}
+
+impl<F: Field> Associative<Multiplicative> for LaurentSeriesRing<F> {}
+
+impl<F: Field> Commutative<Multiplicative> for LaurentSeriesRing<F> {}
+
+// Ring-like properties
+
+impl<F: Field> Distributive for LaurentSeriesRing<F> {}
+
+impl_ring_ops!([F: Field] LaurentSeries<F>);
+impl_field_ops!([F: Field] LaurentSeries<F>);
+
+impl<F: Field> Algebra<F> for LaurentSeriesRing<F> {
+    #[inline]
+    fn embed_base(&self, x: &Elem<F>) -> LaurentSeries<F> {
+        LaurentSeries::from_coeffs(self, 0, vec![x.clone()], i64::MAX)
+    }
+}
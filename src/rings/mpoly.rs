@@ -0,0 +1,562 @@
+use crate::*;
+use crate::ops::*;
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Sparse multivariate polynomials over an arbitrary ring in named variables, with
+/// terms kept sorted with respect to a monomial ordering.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMPolyRing<T: Ring> {
+    ring: T,
+    vars: Arc<[String]>,
+    ordering: MonomialOrdering,
+}
+
+/// An element of a `SparseMPolyRing`, stored as `(exponents, coefficient)` pairs
+/// in decreasing order, so the leading term comes first. Zero coefficients are
+/// never stored.
+#[derive(Clone)]
+pub struct SparseMPoly<T: Ring> {
+    parent: SparseMPolyRing<T>,
+    terms: Vec<(Vec<u64>, Elem<T>)>,
+}
+
+impl<T: Ring> SparseMPolyRing<T> {
+    /// Return the same ring with a different monomial ordering. Elements can be
+    /// moved between the two with `NewElement`.
+    pub fn with_ordering(&self, ordering: MonomialOrdering) -> Self {
+        SparseMPolyRing { ring: self.ring.clone(), vars: self.vars.clone(), ordering }
+    }
+
+    /// Return all the variables as elements of the ring.
+    pub fn gens(&self) -> Vec<SparseMPoly<T>> {
+        (0..self.vars.len()).map(|i| self.gen(i)).collect()
+    }
+
+    /// Return the monomial `c*x^exps`.
+    pub fn monomial(&self, c: Elem<T>, exps: &[u64]) -> SparseMPoly<T> {
+        assert_eq!(exps.len(), self.vars.len(), "wrong number of exponents");
+        let terms = if c.is_zero() { vec![] } else { vec![(exps.to_vec(), c)] };
+        SparseMPoly { parent: self.clone(), terms }
+    }
+}
+
+impl<T: Ring> SparseMPoly<T> {
+    /// Construct a polynomial from terms in any order, combining repeated monomials
+    /// and dropping zero coefficients.
+    fn from_terms(parent: &SparseMPolyRing<T>, mut terms: Vec<(Vec<u64>, Elem<T>)>) -> Self {
+        let ord = parent.ordering;
+        terms.sort_by(|a, b| ord.cmp(&b.0, &a.0));
+        let mut res: Vec<(Vec<u64>, Elem<T>)> = Vec::with_capacity(terms.len());
+        for (m, c) in terms {
+            match res.last_mut() {
+                Some(last) if last.0 == m => last.1 += &c,
+                _ => res.push((m, c)),
+            }
+        }
+        res.retain(|t| !t.1.is_zero());
+        SparseMPoly { parent: parent.clone(), terms: res }
+    }
+
+    /// Return a reference to the nonzero terms in decreasing order.
+    #[inline]
+    pub fn terms(&self) -> &[(Vec<u64>, Elem<T>)] {
+        &self.terms
+    }
+
+    /// Return a reference to the leading term, or `None` for the zero polynomial.
+    #[inline]
+    pub fn lt(&self) -> Option<&(Vec<u64>, Elem<T>)> {
+        self.terms.first()
+    }
+
+    /// Return the product of `self` with the term `c*x^m`. Since monomial
+    /// orderings are compatible with multiplication no sorting is needed.
+    /// Panics if `m` does not have one exponent for each variable.
+    pub fn mul_term(&self, c: &Elem<T>, m: &[u64]) -> Self {
+        assert_eq!(m.len(), self.parent.vars.len(), "wrong number of exponents");
+        let terms = self.terms.iter()
+            .filter_map(|(e, d)| {
+                let mut d = d.clone();
+                d *= c;
+                if d.is_zero() {
+                    None
+                } else {
+                    Some((e.iter().zip(m).map(|(a, b)| a + b).collect(), d))
+                }
+            })
+            .collect();
+        SparseMPoly { parent: self.parent.clone(), terms }
+    }
+
+    /// Multiply every coefficient by `c`.
+    pub fn mul_coeff(&self, c: &Elem<T>) -> Self {
+        let zero = vec![0; self.parent.vars.len()];
+        self.mul_term(c, &zero)
+    }
+
+    /// Merge the sorted term lists of `self` and `rhs`.
+    fn merge(&self, rhs: &Self) -> Self {
+        let ord = self.parent.ordering;
+        let mut terms = Vec::with_capacity(self.terms.len() + rhs.terms.len());
+        let (mut i, mut j) = (0, 0);
+        while i < self.terms.len() && j < rhs.terms.len() {
+            let (a, b) = (&self.terms[i], &rhs.terms[j]);
+            match ord.cmp(&a.0, &b.0) {
+                Ordering::Greater => {
+                    terms.push(a.clone());
+                    i += 1;
+                }
+                Ordering::Less => {
+                    terms.push(b.clone());
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    let mut c = a.1.clone();
+                    c += &b.1;
+                    if !c.is_zero() {
+                        terms.push((a.0.clone(), c));
+                    }
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        terms.extend_from_slice(&self.terms[i..]);
+        terms.extend_from_slice(&rhs.terms[j..]);
+        SparseMPoly { parent: self.parent.clone(), terms }
+    }
+}
+
+impl<T: Ring> PartialEq for SparseMPoly<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.terms == other.terms
+    }
+}
+
+impl<T: Ring> fmt::Debug for SparseMPoly<T>
+where
+    <T as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SparseMPoly")
+            .field("vars", &self.parent.vars)
+            .field("terms", &self.terms)
+            .finish()
+    }
+}
+
+impl<T: Ring> fmt::Display for SparseMPoly<T>
+where
+    <T as Ring>::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+        for (k, (m, c)) in self.terms.iter().enumerate() {
+            if k > 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{}", c)?;
+            for (v, e) in self.parent.vars.iter().zip(m.iter()) {
+                match e {
+                    0 => {}
+                    1 => write!(f, "*{}", v)?,
+                    _ => write!(f, "*{}^{}", v, e)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Ring> Parent for SparseMPolyRing<T> {
+    type Element = SparseMPoly<T>;
+}
+
+impl<T: Ring> Element for SparseMPoly<T> {
+    type Parent = SparseMPolyRing<T>;
+
+    #[inline]
+    fn parent(&self) -> SparseMPolyRing<T> {
+        self.parent.clone()
+    }
+}
+
+/// Construct a polynomial from `(exponents, coefficient)` pairs in any order.
+impl<T: Ring> NewElement<Vec<(Vec<u64>, Elem<T>)>> for SparseMPolyRing<T> {
+    fn new(&self, src: Vec<(Vec<u64>, Elem<T>)>) -> SparseMPoly<T> {
+        assert!(
+            src.iter().all(|t| t.0.len() == self.vars.len()),
+            "wrong number of exponents"
+        );
+        SparseMPoly::from_terms(self, src)
+    }
+}
+
+/// Convert a polynomial from a ring with the same number of variables, for
+/// example to change the monomial ordering.
+impl<'a, T: Ring> NewElement<&'a SparseMPoly<T>> for SparseMPolyRing<T> {
+    fn new(&self, src: &'a SparseMPoly<T>) -> SparseMPoly<T> {
+        assert_eq!(src.parent.vars.len(), self.vars.len(), "wrong number of variables");
+        SparseMPoly::from_terms(self, src.terms.clone())
+    }
+}
+
+// Additive properties
+
+impl<T: Ring> Operation<Additive> for SparseMPoly<T> {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self.merge(rhs)
+    }
+}
+
+impl<T: Ring> Identity<Additive> for SparseMPolyRing<T> {
+    #[inline]
+    fn identity(&self) -> SparseMPoly<T> {
+        SparseMPoly { parent: self.clone(), terms: vec![] }
+    }
+}
+
+impl<T: Ring> IsIdentity<Additive> for SparseMPoly<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<T: Ring> TwoSidedInverse<Additive> for SparseMPoly<T> {
+    fn two_sided_inverse(&self) -> Self {
+        let mut res = self.clone();
+        for t in res.terms.iter_mut() {
+            t.1.neg_assign();
+        }
+        res
+    }
+}
+
+impl<T: Ring> Divisible<Additive> for SparseMPolyRing<T> {}
+
+impl<T: Ring> Associative<Additive> for SparseMPolyRing<T> {}
+
+impl<T: Ring> Commutative<Additive> for SparseMPolyRing<T> {}
+
+// Multiplicative properties
+
+/// A pending product `a_i*b_j` in the multiplication heap, ordered by its monomial.
+struct HeapEntry {
+    exps: Vec<u64>,
+    i: usize,
+    j: usize,
+    ordering: MonomialOrdering,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for HeapEntry {}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ordering.cmp(&self.exps, &other.exps)
+    }
+}
+
+/// Multiplication uses Johnson's heap algorithm, as for `SparsePoly`, so the
+/// product is generated directly in decreasing order.
+impl<T: Ring> Operation<Multiplicative> for SparseMPoly<T> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let (a, b) = if self.terms.len() <= rhs.terms.len() {
+            (&self.terms, &rhs.terms)
+        } else {
+            (&rhs.terms, &self.terms)
+        };
+        if a.is_empty() {
+            return self.parent.zero();
+        }
+
+        let ordering = self.parent.ordering;
+        let entry = |i: usize, j: usize| HeapEntry {
+            exps: a[i].0.iter().zip(b[j].0.iter()).map(|(x, y)| x + y).collect(),
+            i,
+            j,
+            ordering,
+        };
+
+        let mut heap: BinaryHeap<HeapEntry> = (0..a.len()).map(|i| entry(i, 0)).collect();
+        let mut terms: Vec<(Vec<u64>, Elem<T>)> = Vec::new();
+        while let Some(HeapEntry { exps, i, j, .. }) = heap.pop() {
+            let mut t = a[i].1.clone();
+            t *= &b[j].1;
+            match terms.last_mut() {
+                Some(last) if last.0 == exps => last.1 += &t,
+                _ => {
+                    if terms.last().is_some_and(|last| last.1.is_zero()) {
+                        terms.pop();
+                    }
+                    terms.push((exps, t));
+                }
+            }
+            if j + 1 < b.len() {
+                heap.push(entry(i, j + 1));
+            }
+        }
+        if terms.last().is_some_and(|last| last.1.is_zero()) {
+            terms.pop();
+        }
+        SparseMPoly { parent: self.parent.clone(), terms }
+    }
+}
+
+impl<T: Ring> Identity<Multiplicative> for SparseMPolyRing<T> {
+    #[inline]
+    fn identity(&self) -> SparseMPoly<T> {
+        self.monomial(self.ring.one(), &vec![0; self.vars.len()])
+    }
+}
+
+impl<T: Ring> IsIdentity<Multiplicative> for SparseMPoly<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.terms.len() == 1
+            && self.terms[0].0.iter().all(|e| *e == 0)
+            && self.terms[0].1.is_one()
+    }
+}
+
+impl<T: Ring> Associative<Multiplicative> for SparseMPolyRing<T> {}
+
+impl<T: Ring> Commutative<Multiplicative> for SparseMPolyRing<T> {}
+
+// Ring-like properties
+
+impl<T: Ring> Distributive for SparseMPolyRing<T> {}
+
+impl_ring_ops!([T: Ring] SparseMPoly<T>);
+
+impl<T: Ring> Algebra<T> for SparseMPolyRing<T> {
+    #[inline]
+    fn embed_base(&self, x: &Elem<T>) -> SparseMPoly<T> {
+        self.monomial(x.clone(), &vec![0; self.vars.len()])
+    }
+}
+
+impl<T: Ring> MultivariatePolynomialRing<T> for SparseMPolyRing<T> {
+    type Element = SparseMPoly<T>;
+
+    fn init<I, S>(ring: &T, vars: I, ordering: MonomialOrdering) -> Self
+    where
+        I: IntoIterator<Item=S>,
+        S: Into<String>,
+    {
+        SparseMPolyRing {
+            ring: ring.clone(),
+            vars: vars.into_iter().map(|v| v.into()).collect(),
+            ordering,
+        }
+    }
+
+    #[inline]
+    fn base_ring(&self) -> &T {
+        &self.ring
+    }
+
+    #[inline]
+    fn vars(&self) -> Vec<String> {
+        self.vars.to_vec()
+    }
+
+    #[inline]
+    fn nvars(&self) -> i64 {
+        self.vars.len() as i64
+    }
+
+    #[inline]
+    fn ordering(&self) -> MonomialOrdering {
+        self.ordering
+    }
+
+    fn gen(&self, i: usize) -> SparseMPoly<T> {
+        assert!(i < self.vars.len(), "variable index out of bounds");
+        let mut exps = vec![0; self.vars.len()];
+        exps[i] = 1;
+        self.monomial(self.ring.one(), &exps)
+    }
+
+    #[inline]
+    fn is_generic(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Ring> MultivariatePolynomialRingElement<T> for SparseMPoly<T> {
+    type Parent = SparseMPolyRing<T>;
+
+    #[inline]
+    fn base_ring(&self) -> &T {
+        &self.parent.ring
+    }
+
+    #[inline]
+    fn vars(&self) -> Vec<String> {
+        self.parent.vars.to_vec()
+    }
+
+    #[inline]
+    fn nvars(&self) -> i64 {
+        self.parent.vars.len() as i64
+    }
+
+    #[inline]
+    fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    fn total_degree(&self) -> i64 {
+        self.terms.iter()
+            .map(|t| t.0.iter().sum::<u64>() as i64)
+            .max()
+            .unwrap_or(-1)
+    }
+
+    fn degree_in(&self, i: usize) -> i64 {
+        self.terms.iter().map(|t| t.0[i] as i64).max().unwrap_or(-1)
+    }
+
+    fn get_coefficient(&self, exps: &[u64]) -> Elem<T> {
+        let ord = self.parent.ordering;
+        match self.terms.binary_search_by(|t| ord.cmp(exps, &t.0)) {
+            Ok(k) => self.terms[k].1.clone(),
+            Err(_) => self.parent.ring.zero(),
+        }
+    }
+
+    fn set_coefficient(&mut self, exps: &[u64], coeff: Elem<T>) {
+        assert_eq!(exps.len(), self.parent.vars.len(), "wrong number of exponents");
+        let ord = self.parent.ordering;
+        match self.terms.binary_search_by(|t| ord.cmp(exps, &t.0)) {
+            Ok(k) if coeff.is_zero() => {
+                self.terms.remove(k);
+            }
+            Ok(k) => self.terms[k].1 = coeff,
+            Err(_) if coeff.is_zero() => {}
+            Err(k) => self.terms.insert(k, (exps.to_vec(), coeff)),
+        }
+    }
+
+    #[inline]
+    fn get_terms(&self) -> Vec<(Vec<u64>, Elem<T>)> {
+        self.terms.clone()
+    }
+
+    #[inline]
+    fn leading_monomial(&self) -> Option<Vec<u64>> {
+        self.terms.first().map(|t| t.0.clone())
+    }
+
+    #[inline]
+    fn leading_coefficient(&self) -> Option<Elem<T>> {
+        self.terms.first().map(|t| t.1.clone())
+    }
+
+    fn leading_term(&self) -> Self {
+        SparseMPoly {
+            parent: self.parent.clone(),
+            terms: self.terms.first().cloned().into_iter().collect(),
+        }
+    }
+
+    fn evaluate_var(&self, i: usize, value: &Elem<T>) -> Self {
+        assert!(i < self.parent.vars.len(), "variable index out of bounds");
+        let ring = &self.parent.ring;
+        let terms = self.terms.iter()
+            .map(|(m, c)| {
+                let mut c = c.clone();
+                c *= &ring.power(Multiplicative, value, m[i]);
+                let mut m = m.clone();
+                m[i] = 0;
+                (m, c)
+            })
+            .collect();
+        SparseMPoly::from_terms(&self.parent, terms)
+    }
+
+    fn substitute(&self, i: usize, value: &Self) -> Self {
+        assert!(i < self.parent.vars.len(), "variable index out of bounds");
+        let mut res = self.parent.zero();
+        for (m, c) in self.terms.iter() {
+            let p = self.parent.power(Multiplicative, value, m[i]);
+            let mut m = m.clone();
+            m[i] = 0;
+            res += &p.mul_term(c, &m);
+        }
+        res
+    }
+
+    #[inline]
+    fn is_generic(&self) -> bool {
+        true
+    }
+}
+
+// Evaluation
+
+/// Evaluate the polynomial at a point whose coordinates lie in an algebra over
+/// the base ring, for example the base ring itself or another polynomial ring.
+///
+/// The algebra is taken from the first coordinate, so a polynomial in no
+/// variables panics here. Use `assign_evaluate`, which takes the algebra from
+/// its target, to get its constant term.
+impl<'a, T: Ring, X> Evaluate<&'a [X]> for SparseMPoly<T>
+where
+    X: Element + AddOps + MulOps,
+    Par<X>: Algebra<T> + AbstractMonoid<Multiplicative, Element = X>,
+{
+    type Output = X;
+
+    fn evaluate(&self, point: &'a [X]) -> X {
+        assert_eq!(point.len(), self.parent.vars.len(), "wrong number of coordinates");
+        assert!(!point.is_empty(), "no coordinate to take the algebra from, use assign_evaluate");
+        let mut res = point[0].parent().embed_base(&self.parent.ring.zero());
+        res.assign_evaluate(self, point);
+        res
+    }
+}
+
+/// Evaluate the polynomial at a point, writing the result into `self`, whose
+/// parent is the algebra of the coordinates. A polynomial in no variables
+/// evaluates to its constant term.
+impl<'a, 'b, T: Ring, X> AssignEvaluate<&'a SparseMPoly<T>, &'b [X]> for X
+where
+    X: Element + AddOps + MulOps,
+    Par<X>: Algebra<T> + AbstractMonoid<Multiplicative, Element = X>,
+{
+    fn assign_evaluate(&mut self, expr: &'a SparseMPoly<T>, point: &'b [X]) {
+        assert_eq!(point.len(), expr.parent.vars.len(), "wrong number of coordinates");
+        let alg = self.parent();
+        *self = alg.embed_base(&expr.parent.ring.zero());
+        for (m, c) in expr.terms.iter() {
+            let mut t = alg.embed_base(c);
+            for (x, e) in point.iter().zip(m.iter()) {
+                if *e > 0 {
+                    t *= &alg.power(Multiplicative, x, *e);
+                }
+            }
+            *self += &t;
+        }
+    }
+}
@@ -0,0 +1,398 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+
+/// The number field `Q(α) = Q[x]/(f)` defined by an irreducible polynomial `f`
+/// over the rationals, with `α` a root of `f`.
+///
+/// Elements are polynomials in `α` of degree less than `deg f`. Irreducibility of
+/// `f` is not checked; if it is reducible the quotient is not a field and
+/// inverting a zero divisor panics.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberField {
+    ring: GenericPolyRing<RationalField>,
+    modulus: GenericPoly<RationalField>,
+}
+
+/// An element of a `NumberField`, stored as its reduced polynomial in `α`.
+#[derive(Clone)]
+pub struct NumberFieldElement {
+    parent: NumberField,
+    poly: GenericPoly<RationalField>,
+}
+
+/// Return the roots of the polynomial with complex coefficients `coeffs`, in
+/// order of increasing degree and with leading coefficient one, using the
+/// Durand-Kerner iteration.
+fn complex_roots(coeffs: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mul = |a: (f64, f64), b: (f64, f64)| (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0);
+    let div = |a: (f64, f64), b: (f64, f64)| {
+        let d = b.0 * b.0 + b.1 * b.1;
+        ((a.0 * b.0 + a.1 * b.1) / d, (a.1 * b.0 - a.0 * b.1) / d)
+    };
+    let eval = |z: (f64, f64)| {
+        coeffs.iter().rev().fold((0.0, 0.0), |acc, &c| {
+            let p = mul(acc, z);
+            (p.0 + c.0, p.1 + c.1)
+        })
+    };
+
+    // Start on a circle containing all the roots, rotated off the real axis.
+    let n = coeffs.len() - 1;
+    let radius = 1.0 + coeffs[..n].iter().map(|c| c.0.hypot(c.1)).fold(0.0, f64::max);
+    let mut roots: Vec<(f64, f64)> = (0..n)
+        .map(|k| {
+            let theta = 2.0 * std::f64::consts::PI * k as f64 / n as f64 + 0.4;
+            (radius * theta.cos(), radius * theta.sin())
+        })
+        .collect();
+    for _ in 0..1000 {
+        let mut delta: f64 = 0.0;
+        for i in 0..n {
+            let mut den = (1.0, 0.0);
+            for j in 0..n {
+                if i != j {
+                    den = mul(den, (roots[i].0 - roots[j].0, roots[i].1 - roots[j].1));
+                }
+            }
+            let step = div(eval(roots[i]), den);
+            roots[i] = (roots[i].0 - step.0, roots[i].1 - step.1);
+            delta = delta.max(step.0.hypot(step.1));
+        }
+        if delta < 1e-15 {
+            break;
+        }
+    }
+    roots
+}
+
+impl NumberField {
+    /// Initialize the number field defined by the irreducible polynomial `f`,
+    /// printing the generator as `var`. Panics if `f` is constant.
+    pub fn init<S: Into<String>>(f: &GenericPoly<RationalField>, var: S) -> Self {
+        assert!(f.degree() >= 1, "defining polynomial must be nonconstant");
+        let ring = GenericPolyRing::init(&RationalField::init(), var);
+        let modulus = ring.normalize(&ring.new(f.coefficients().to_vec()));
+        NumberField { ring, modulus }
+    }
+
+    /// Return the monic defining polynomial.
+    #[inline]
+    pub fn defining_polynomial(&self) -> &GenericPoly<RationalField> {
+        &self.modulus
+    }
+
+    /// Return the degree of the field over the rationals.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.modulus.degree() as usize
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.ring.var()
+    }
+
+    /// Return the generator `α`.
+    #[inline]
+    pub fn gen(&self) -> NumberFieldElement {
+        self.new(self.ring.gen())
+    }
+
+    /// Return the roots of the defining polynomial in the complex numbers as
+    /// `(re, im)` pairs of floats. Real roots come first in increasing order,
+    /// followed by the complex roots ordered by real part and then imaginary
+    /// part, so that conjugate roots are adjacent.
+    pub fn complex_roots(&self) -> Vec<(f64, f64)> {
+        let coeffs: Vec<_> = self.modulus.coefficients().iter()
+            .map(|c| (c.to_f64(), 0.0))
+            .collect();
+        let mut roots = complex_roots(&coeffs);
+        let scale = roots.iter().map(|z| z.0.hypot(z.1)).fold(1.0, f64::max);
+        for z in roots.iter_mut() {
+            if z.1.abs() < 1e-9 * scale {
+                z.1 = 0.0;
+            }
+        }
+        roots.sort_by(|a, b| {
+            (a.1 != 0.0).cmp(&(b.1 != 0.0))
+                .then(a.0.total_cmp(&b.0))
+                .then(b.1.total_cmp(&a.1))
+        });
+        roots
+    }
+
+    /// Return the signature `(r1, r2)`, the numbers of real embeddings and of
+    /// pairs of complex conjugate embeddings.
+    pub fn signature(&self) -> (usize, usize) {
+        let r1 = self.complex_roots().iter().filter(|z| z.1 == 0.0).count();
+        (r1, (self.degree() - r1) / 2)
+    }
+
+    #[inline]
+    fn elem(&self, poly: GenericPoly<RationalField>) -> NumberFieldElement {
+        NumberFieldElement { parent: self.clone(), poly }
+    }
+}
+
+impl NumberFieldElement {
+    /// Return a reference to the reduced polynomial in `α` representing the
+    /// element.
+    #[inline]
+    pub fn polynomial(&self) -> &GenericPoly<RationalField> {
+        &self.poly
+    }
+
+    /// Return the coordinates in the power basis `1, α, ..., α^(n-1)`.
+    pub fn coefficients(&self) -> Vec<Rational> {
+        let n = self.parent.degree();
+        (0..n).map(|i| self.poly.get_coeff(i)).collect()
+    }
+
+    /// Return the multiplicative inverse, or `None` if the element is zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.poly.is_zero() {
+            return None;
+        }
+        let p = &self.parent;
+        let inv = p.ring.inverse_mod(&self.poly, &p.modulus)
+            .expect("defining polynomial is reducible");
+        Some(p.elem(inv))
+    }
+
+    /// Return the matrix of multiplication by the element with respect to the
+    /// power basis, whose `j`-th column holds the coordinates of `self * α^j`.
+    pub fn representation_matrix(&self) -> GenericMatrix<RationalField> {
+        let p = &self.parent;
+        let n = p.degree();
+        let mut cols = Vec::with_capacity(n);
+        let mut x = self.clone();
+        let alpha = p.gen();
+        for _ in 0..n {
+            cols.push(x.coefficients());
+            x *= &alpha;
+        }
+        let entries = (0..n).flat_map(|i| cols.iter().map(move |c| c[i])).collect();
+        let space = GenericMatrixSpace::init(&RationalField::init(), n as u64, n as u64);
+        space.new(entries)
+    }
+
+    /// Return the characteristic polynomial of the representation matrix,
+    /// computed with the Faddeev-LeVerrier recurrence.
+    pub fn charpoly(&self) -> GenericPoly<RationalField> {
+        let a = self.representation_matrix();
+        let space = a.parent();
+        let n = self.parent.degree();
+        let trace = |m: &GenericMatrix<RationalField>| {
+            (0..n).fold(Rational::from(0), |acc, i| acc + m.entry(i, i))
+        };
+
+        // M_k = A M_(k-1) + c_(n-k+1) I and c_(n-k) = -tr(A M_k)/k.
+        let mut coeffs = vec![Rational::from(0); n + 1];
+        coeffs[n] = Rational::from(1);
+        let mut m = space.zero();
+        for k in 1..=n {
+            m = a.mul_matrix(&m) + space.scalar(&coeffs[n - k + 1]);
+            let t = trace(&a.mul_matrix(&m));
+            coeffs[n - k] = -(t / Rational::from(k as i64));
+        }
+        self.parent.ring.new(coeffs)
+    }
+
+    /// Return the minimal polynomial. Since the characteristic polynomial is a
+    /// power of the minimal polynomial, it is its squarefree part.
+    pub fn minpoly(&self) -> GenericPoly<RationalField> {
+        let ring = &self.parent.ring;
+        let chi = self.charpoly();
+        let deriv: Vec<_> = chi.coefficients().iter().enumerate().skip(1)
+            .map(|(i, c)| c * &Rational::from(i as i64))
+            .collect();
+        let g = ring.gcd(&chi, &ring.new(deriv));
+        ring.divexact(&chi, &g)
+    }
+
+    /// Return the norm, the determinant of the representation matrix.
+    pub fn norm(&self) -> Rational {
+        let chi = self.charpoly();
+        let c = chi.get_coeff(0);
+        if self.parent.degree().is_multiple_of(2) { c } else { -c }
+    }
+
+    /// Return the trace, the trace of the representation matrix.
+    pub fn trace(&self) -> Rational {
+        -self.charpoly().get_coeff(self.parent.degree() - 1)
+    }
+
+    /// Return the images of the element under the complex embeddings, as
+    /// `(re, im)` pairs in the order of `NumberField::complex_roots`.
+    pub fn complex_embeddings(&self) -> Vec<(f64, f64)> {
+        let coeffs: Vec<f64> = self.poly.coefficients().iter().map(|c| c.to_f64()).collect();
+        self.parent.complex_roots().into_iter()
+            .map(|z| {
+                coeffs.iter().rev().fold((0.0, 0.0), |acc: (f64, f64), &c| {
+                    (acc.0 * z.0 - acc.1 * z.1 + c, acc.0 * z.1 + acc.1 * z.0)
+                })
+            })
+            .collect()
+    }
+}
+
+impl PartialEq for NumberFieldElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.poly == other.poly
+    }
+}
+
+impl fmt::Debug for NumberFieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NumberFieldElement")
+            .field("poly", &self.poly)
+            .field("modulus", &self.parent.modulus)
+            .finish()
+    }
+}
+
+impl fmt::Display for NumberFieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.poly)
+    }
+}
+
+impl Parent for NumberField {
+    type Element = NumberFieldElement;
+}
+
+impl Element for NumberFieldElement {
+    type Parent = NumberField;
+
+    #[inline]
+    fn parent(&self) -> NumberField {
+        self.parent.clone()
+    }
+}
+
+/// Construct the image of a rational polynomial evaluated at `α`.
+impl NewElement<GenericPoly<RationalField>> for NumberField {
+    fn new(&self, src: GenericPoly<RationalField>) -> NumberFieldElement {
+        let poly = self.ring.new(src.coefficients().to_vec());
+        self.elem(self.ring.rem(&poly, &self.modulus))
+    }
+}
+
+/// Construct the element with the given coordinates in the power basis.
+impl NewElement<Vec<Rational>> for NumberField {
+    #[inline]
+    fn new(&self, src: Vec<Rational>) -> NumberFieldElement {
+        self.new(self.ring.new(src))
+    }
+}
+
+impl NewElement<i64> for NumberField {
+    #[inline]
+    fn new(&self, src: i64) -> NumberFieldElement {
+        self.elem(self.ring.new(vec![Rational::from(src)]))
+    }
+}
+
+impl NewElement<Rational> for NumberField {
+    #[inline]
+    fn new(&self, src: Rational) -> NumberFieldElement {
+        self.elem(self.ring.new(vec![src]))
+    }
+}
+
+// Additive properties
+
+impl Operation<Additive> for NumberFieldElement {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self.parent.elem(&self.poly + &rhs.poly)
+    }
+}
+
+impl Identity<Additive> for NumberField {
+    #[inline]
+    fn identity(&self) -> NumberFieldElement {
+        self.elem(self.ring.zero())
+    }
+}
+
+impl IsIdentity<Additive> for NumberFieldElement {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.poly.is_zero()
+    }
+}
+
+impl TwoSidedInverse<Additive> for NumberFieldElement {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.parent.elem(-&self.poly)
+    }
+}
+
+impl Divisible<Additive> for NumberField {}
+
+impl Associative<Additive> for NumberField {}
+
+impl Commutative<Additive> for NumberField {}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for NumberFieldElement {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        let p = &self.parent;
+        p.elem(p.ring.mul_mod(&self.poly, &rhs.poly, &p.modulus))
+    }
+}
+
+impl Identity<Multiplicative> for NumberField {
+    #[inline]
+    fn identity(&self) -> NumberFieldElement {
+        self.elem(self.ring.one())
+    }
+}
+
+impl IsIdentity<Multiplicative> for NumberFieldElement {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.poly.is_one()
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for NumberFieldElement {
+    /// Panics if `self` is zero.
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.inverse().expect("division by zero")
+    }
+}
+
+impl Divisible<Multiplicative> for NumberField {}
+
+impl Associative<Multiplicative> for NumberField {}
+
+impl Commutative<Multiplicative> for NumberField {}
+
+// Ring-like properties
+
+impl Distributive for NumberField {}
+
+impl_ring_ops!([] NumberFieldElement);
+impl_field_ops!([] NumberFieldElement);
+
+impl Algebra<RationalField> for NumberField {
+    #[inline]
+    fn embed_base(&self, x: &Rational) -> NumberFieldElement {
+        self.new(*x)
+    }
+}
+
+impl Algebra<IntegerRing> for NumberField {
+    #[inline]
+    fn embed_base(&self, x: &i64) -> NumberFieldElement {
+        self.new(*x)
+    }
+}
@@ -0,0 +1,517 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Whether a `PAdicParent` contains only integral elements, which determines at
+/// the type level whether it is a `Ring` or a `Field`.
+pub trait PAdicKind: Clone + Copy + fmt::Debug + Default + PartialEq + Eq {
+    /// Whether elements must have nonnegative valuation.
+    const INTEGRAL: bool;
+}
+
+/// The p-adic integers `Z_p`. Elements have nonnegative valuation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Integral;
+
+/// The p-adic numbers `Q_p`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fractional;
+
+impl PAdicKind for Integral {
+    const INTEGRAL: bool = true;
+}
+
+impl PAdicKind for Fractional {
+    const INTEGRAL: bool = false;
+}
+
+/// The p-adic integers or p-adic numbers with capped relative precision. The
+/// parent holds the prime `p` and the precision cap `N`.
+///
+/// A nonzero element is stored as `p^v * u + O(p^(v + r))` with `u` a unit
+/// reduced modulo `p^r` and relative precision `r <= N`. Zero to absolute
+/// precision `k` is stored as `O(p^k)`, and exact zero, which is the additive
+/// identity, gets absolute precision `i64::MAX`. Nonzero elements constructed
+/// from integers or rationals have relative precision `N`, and arithmetic
+/// tracks the precision of its results.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PAdicParent<K: PAdicKind> {
+    p: i64,
+    prec: u32,
+    kind: PhantomData<K>,
+}
+
+/// The ring of p-adic integers `Z_p`.
+pub type PAdicIntegers = PAdicParent<Integral>;
+
+/// The field of p-adic numbers `Q_p`.
+pub type PAdicField = PAdicParent<Fractional>;
+
+/// An element of a `PAdicParent`.
+#[derive(Clone)]
+pub struct PAdic<K: PAdicKind> {
+    parent: PAdicParent<K>,
+    val: i64,
+    unit: i64,
+    prec: i64,
+}
+
+/// An element of `Z_p`.
+pub type PAdicInteger = PAdic<Integral>;
+
+/// An element of `Q_p`.
+pub type PAdicNumber = PAdic<Fractional>;
+
+/// Return `(v, u)` with `n = p^v * u` and `u` coprime to `p`, for nonzero `n`.
+#[inline]
+fn split_i128(mut n: i128, p: i128) -> (i64, i128) {
+    let mut v = 0;
+    while n % p == 0 {
+        n /= p;
+        v += 1;
+    }
+    (v, n)
+}
+
+impl PAdicIntegers {
+    /// Initialize `Z_p` with relative precision cap `prec`. Panics if `p` is not
+    /// prime or `p^prec` does not fit in 62 bits.
+    pub fn init(p: i64, prec: u32) -> Self {
+        PAdicParent::init_kind(p, prec)
+    }
+
+    /// Return the fraction field `Q_p` with the same precision cap.
+    pub fn fraction_field(&self) -> PAdicField {
+        PAdicParent { p: self.p, prec: self.prec, kind: PhantomData }
+    }
+
+    /// Return the root of `f` congruent to `x` obtained by Hensel lifting, or
+    /// `None` if `v(f(x)) <= 2 v(f'(x))`, in which case the lift is not unique
+    /// or may not exist.
+    pub fn hensel_lift<P>(&self, f: &P, x: &PAdicInteger) -> Option<PAdicInteger>
+    where
+        P: PolynomialRingElement<PAdicIntegers>,
+    {
+        self.newton(f, x)
+    }
+}
+
+impl PAdicField {
+    /// Initialize `Q_p` with relative precision cap `prec`. Panics if `p` is not
+    /// prime or `p^prec` does not fit in 62 bits.
+    pub fn init(p: i64, prec: u32) -> Self {
+        PAdicParent::init_kind(p, prec)
+    }
+
+    /// Return the ring of integers `Z_p` with the same precision cap.
+    pub fn integers(&self) -> PAdicIntegers {
+        PAdicParent { p: self.p, prec: self.prec, kind: PhantomData }
+    }
+
+    /// Return the root of `f` congruent to `x` obtained by Hensel lifting, or
+    /// `None` if `v(f(x)) <= 2 v(f'(x))`, in which case the lift is not unique
+    /// or may not exist.
+    pub fn hensel_lift<P>(&self, f: &P, x: &PAdicNumber) -> Option<PAdicNumber>
+    where
+        P: PolynomialRingElement<PAdicField>,
+    {
+        self.newton(f, x)
+    }
+}
+
+impl<K: PAdicKind> PAdicParent<K> {
+    fn init_kind(p: i64, prec: u32) -> Self {
+        assert!(IntegerRing::init().is_prime(&p) && p > 0, "{} is not prime", p);
+        assert!(prec >= 1, "precision must be positive");
+        let fits = (p as i128).checked_pow(prec).is_some_and(|q| q < 1 << 62);
+        assert!(fits, "{}^{} does not fit in 62 bits", p, prec);
+        PAdicParent { p, prec, kind: PhantomData }
+    }
+
+    /// Return the prime.
+    #[inline]
+    pub fn prime(&self) -> i64 {
+        self.p
+    }
+
+    /// Return the relative precision cap.
+    #[inline]
+    pub fn precision_cap(&self) -> u32 {
+        self.prec
+    }
+
+    /// Return `p^k`.
+    #[inline]
+    fn pow(&self, k: i64) -> i128 {
+        (self.p as i128).pow(k as u32)
+    }
+
+    /// Return `p^val * unit + O(p^prec)`, reducing the unit and capping the
+    /// relative precision.
+    fn elem_from_parts(&self, val: i64, unit: i128, prec: i64) -> PAdic<K> {
+        let prec = prec.min(val.saturating_add(self.prec as i64));
+        if prec <= val {
+            return self.zero_to(prec);
+        }
+        let unit = unit.rem_euclid(self.pow(prec - val));
+        PAdic { parent: self.clone(), val, unit: unit as i64, prec }
+    }
+
+    /// Return `p^val * x + O(p^prec)` where `x` is not necessarily a unit.
+    fn elem_from_i128(&self, val: i64, x: i128, prec: i64) -> PAdic<K> {
+        if x == 0 {
+            return self.zero_to(prec);
+        }
+        let (v, u) = split_i128(x, self.p as i128);
+        self.elem_from_parts(val + v, u, prec)
+    }
+
+    /// Return `O(p^prec)`.
+    #[inline]
+    fn zero_to(&self, prec: i64) -> PAdic<K> {
+        PAdic { parent: self.clone(), val: prec, unit: 0, prec }
+    }
+
+    /// Return the element `p^k`. Panics in `Z_p` if `k` is negative.
+    pub fn uniformizer_pow(&self, k: i64) -> PAdic<K> {
+        assert!(!K::INTEGRAL || k >= 0, "negative valuation in Z_p");
+        self.elem_from_parts(k, 1, i64::MAX)
+    }
+
+    fn newton<P>(&self, f: &P, x: &PAdic<K>) -> Option<PAdic<K>>
+    where
+        P: PolynomialRingElement<PAdicParent<K>>,
+    {
+        let coeffs = f.get_coefficients();
+        let deriv: Vec<_> = coeffs.iter().enumerate().skip(1)
+            .map(|(i, c)| c * &self.new(i as i64))
+            .collect();
+        let eval = |cs: &[PAdic<K>], x: &PAdic<K>| cs.iter().rev().fold(self.zero(), |acc, c| &(&acc * x) + c);
+
+        let mut x = x.clone();
+        let (fx, dx) = (eval(&coeffs, &x), eval(&deriv, &x));
+        if fx.is_zero() {
+            return Some(x);
+        }
+        if dx.is_zero() || fx.val <= 2 * dx.val {
+            return None;
+        }
+        // Newton's method converges quadratically, so the number of iterations
+        // is logarithmic in the precision.
+        for _ in 0..=2 * (u32::BITS - self.prec.leading_zeros()) {
+            let fx = eval(&coeffs, &x);
+            if fx.is_zero() {
+                break;
+            }
+            let dx = eval(&deriv, &x);
+            x -= &fx.div_unchecked(&dx);
+        }
+        Some(x)
+    }
+}
+
+impl<K: PAdicKind> PAdic<K> {
+    /// Return the valuation, or the absolute precision if the element is zero to
+    /// its precision.
+    #[inline]
+    pub fn valuation(&self) -> i64 {
+        self.val
+    }
+
+    /// Return the unit part `u` of `p^v * u`, reduced modulo `p^r` where `r` is
+    /// the relative precision. Zero has unit part zero.
+    #[inline]
+    pub fn unit_part(&self) -> i64 {
+        self.unit
+    }
+
+    /// Return the absolute precision `k`, so that the element is known modulo
+    /// `p^k`.
+    #[inline]
+    pub fn prec(&self) -> i64 {
+        self.prec
+    }
+
+    /// Return the relative precision, the number of known p-adic digits.
+    #[inline]
+    pub fn relative_prec(&self) -> i64 {
+        self.prec - self.val
+    }
+
+    /// Return `true` if the element is a unit of `Z_p`, that is, has valuation
+    /// zero.
+    #[inline]
+    pub fn is_unit(&self) -> bool {
+        self.unit != 0 && self.val == 0
+    }
+
+    /// Return the p-adic digits `d_v, ..., d_(k-1)` of the element, where `v` is
+    /// the valuation and `k` the absolute precision.
+    pub fn digits(&self) -> Vec<i64> {
+        let mut u = self.unit;
+        (self.val..self.prec)
+            .map(|_| {
+                let d = u % self.parent.p;
+                u /= self.parent.p;
+                d
+            })
+            .collect()
+    }
+
+    /// Return the rational number `p^v * u`, which agrees with the element to its
+    /// precision. Panics if this does not fit in a `Rational`.
+    pub fn lift(&self) -> Rational {
+        let pk = || i64::try_from(self.parent.pow(self.val.abs())).expect("rational overflow");
+        if self.unit == 0 {
+            Rational::from(0)
+        } else if self.val >= 0 {
+            Rational::from(self.unit.checked_mul(pk()).expect("rational overflow"))
+        } else {
+            Rational::new(self.unit, pk())
+        }
+    }
+
+    /// Return `self` reduced to absolute precision at most `prec`.
+    pub fn truncate(&self, prec: i64) -> Self {
+        self.parent.elem_from_parts(self.val, self.unit as i128, prec.min(self.prec))
+    }
+
+    /// Return the multiplicative inverse, or `None` if it does not exist in the
+    /// parent, that is, if the element is zero or, in `Z_p`, not a unit.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.unit == 0 || (K::INTEGRAL && self.val != 0) {
+            return None;
+        }
+        Some(self.parent.one().div_unchecked(self))
+    }
+
+    /// Return `self/rhs` without checking that the result lies in the parent.
+    /// Panics if `rhs` is zero.
+    fn div_unchecked(&self, rhs: &Self) -> Self {
+        assert!(rhs.unit != 0, "division by zero");
+        let p = &self.parent;
+        if self.unit == 0 {
+            return p.zero_to(self.prec.saturating_sub(rhs.val));
+        }
+        let rel = self.relative_prec().min(rhs.relative_prec());
+        let m = p.pow(rel) as i64;
+        let inv = IntegerRing::init().inverse_mod(&rhs.unit, &m).unwrap() as i128;
+        let val = self.val - rhs.val;
+        p.elem_from_parts(val, self.unit as i128 * inv, val + rel)
+    }
+
+    /// Return `u * p^(v - lo)` reduced modulo `p^(prec - lo)`.
+    #[inline]
+    fn shifted(&self, lo: i64, prec: i64) -> i128 {
+        if self.val >= prec {
+            0
+        } else {
+            self.unit as i128 * self.parent.pow(self.val - lo) % self.parent.pow(prec - lo)
+        }
+    }
+}
+
+impl<K: PAdicKind> PartialEq for PAdic<K> {
+    /// Elements are equal if they agree to the smaller of their precisions.
+    fn eq(&self, other: &Self) -> bool {
+        let prec = self.prec.min(other.prec);
+        let lo = self.val.min(other.val).min(prec);
+        self.shifted(lo, prec) == other.shifted(lo, prec)
+    }
+}
+
+impl<K: PAdicKind> fmt::Debug for PAdic<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PAdic")
+            .field("p", &self.parent.p)
+            .field("val", &self.val)
+            .field("unit", &self.unit)
+            .field("prec", &self.prec)
+            .finish()
+    }
+}
+
+impl<K: PAdicKind> fmt::Display for PAdic<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let p = self.parent.p;
+        for (i, d) in self.digits().into_iter().enumerate() {
+            if d == 0 {
+                continue;
+            }
+            match self.val + i as i64 {
+                0 => write!(f, "{} + ", d)?,
+                1 => write!(f, "{}*{} + ", d, p)?,
+                k => write!(f, "{}*{}^{} + ", d, p, k)?,
+            }
+        }
+        write!(f, "O({}^{})", p, self.prec)
+    }
+}
+
+impl<K: PAdicKind> Parent for PAdicParent<K> {
+    type Element = PAdic<K>;
+}
+
+impl<K: PAdicKind> Element for PAdic<K> {
+    type Parent = PAdicParent<K>;
+
+    #[inline]
+    fn parent(&self) -> PAdicParent<K> {
+        self.parent.clone()
+    }
+}
+
+impl<K: PAdicKind> NewElement<i64> for PAdicParent<K> {
+    #[inline]
+    fn new(&self, src: i64) -> PAdic<K> {
+        self.elem_from_i128(0, src as i128, i64::MAX)
+    }
+}
+
+/// Construct the expansion of a rational number. Panics in `Z_p` if the
+/// denominator is divisible by `p`.
+impl<K: PAdicKind> NewElement<Rational> for PAdicParent<K> {
+    fn new(&self, src: Rational) -> PAdic<K> {
+        if src.numerator() == 0 {
+            return self.zero();
+        }
+        let p = self.p as i128;
+        let (vn, un) = split_i128(src.numerator() as i128, p);
+        let (vd, ud) = split_i128(src.denominator() as i128, p);
+        let val = vn - vd;
+        assert!(!K::INTEGRAL || val >= 0, "negative valuation in Z_p");
+        let m = self.pow(self.prec as i64);
+        let inv = IntegerRing::init().inverse_mod(&((ud % m) as i64), &(m as i64)).unwrap();
+        self.elem_from_parts(val, un % m * inv as i128, i64::MAX)
+    }
+}
+
+/// Construct the image of a p-adic integer in `Q_p`.
+impl<'a> NewElement<&'a PAdicInteger> for PAdicField {
+    #[inline]
+    fn new(&self, src: &'a PAdicInteger) -> PAdicNumber {
+        PAdic { parent: self.clone(), val: src.val, unit: src.unit, prec: src.prec }
+    }
+}
+
+// Additive properties
+
+impl<K: PAdicKind> Operation<Additive> for PAdic<K> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let prec = self.prec.min(rhs.prec);
+        let lo = self.val.min(rhs.val);
+        if lo >= prec {
+            return self.parent.zero_to(prec);
+        }
+        let x = self.shifted(lo, prec) + rhs.shifted(lo, prec);
+        self.parent.elem_from_i128(lo, x % self.parent.pow(prec - lo), prec)
+    }
+}
+
+impl<K: PAdicKind> Identity<Additive> for PAdicParent<K> {
+    #[inline]
+    fn identity(&self) -> PAdic<K> {
+        self.zero_to(i64::MAX)
+    }
+}
+
+impl<K: PAdicKind> IsIdentity<Additive> for PAdic<K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.unit == 0
+    }
+}
+
+impl<K: PAdicKind> TwoSidedInverse<Additive> for PAdic<K> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        if self.unit == 0 {
+            return self.clone();
+        }
+        self.parent.elem_from_parts(self.val, -(self.unit as i128), self.prec)
+    }
+}
+
+impl<K: PAdicKind> Divisible<Additive> for PAdicParent<K> {}
+
+impl<K: PAdicKind> Associative<Additive> for PAdicParent<K> {}
+
+impl<K: PAdicKind> Commutative<Additive> for PAdicParent<K> {}
+
+// Multiplicative properties
+
+impl<K: PAdicKind> Operation<Multiplicative> for PAdic<K> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let p = &self.parent;
+        match (self.unit == 0, rhs.unit == 0) {
+            (true, true) => p.zero_to(self.prec.saturating_add(rhs.prec)),
+            (true, false) => p.zero_to(self.prec.saturating_add(rhs.val)),
+            (false, true) => p.zero_to(rhs.prec.saturating_add(self.val)),
+            (false, false) => {
+                let rel = self.relative_prec().min(rhs.relative_prec());
+                let val = self.val + rhs.val;
+                let m = p.pow(rel);
+                let u = (self.unit as i128 % m) * (rhs.unit as i128 % m);
+                p.elem_from_parts(val, u, val + rel)
+            }
+        }
+    }
+}
+
+impl<K: PAdicKind> Identity<Multiplicative> for PAdicParent<K> {
+    #[inline]
+    fn identity(&self) -> PAdic<K> {
+        self.elem_from_parts(0, 1, i64::MAX)
+    }
+}
+
+impl<K: PAdicKind> IsIdentity<Multiplicative> for PAdic<K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.val == 0 && self.unit == 1
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for PAdicNumber {
+    /// Panics if `self` is zero.
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.inverse().expect("division by zero")
+    }
+}
+
+impl Divisible<Multiplicative> for PAdicField {}
+
+impl<K: PAdicKind> Associative<Multiplicative> for PAdicParent<K> {}
+
+impl<K: PAdicKind> Commutative<Multiplicative> for PAdicParent<K> {}
+
+// Ring-like properties
+
+impl<K: PAdicKind> Distributive for PAdicParent<K> {}
+
+impl_ring_ops!([K: PAdicKind] PAdic<K>);
+impl_field_ops!([] PAdicNumber);
+
+impl<K: PAdicKind> Algebra<IntegerRing> for PAdicParent<K> {
+    #[inline]
+    fn embed_base(&self, x: &i64) -> PAdic<K> {
+        self.new(*x)
+    }
+}
+
+impl Algebra<RationalField> for PAdicField {
+    #[inline]
+    fn embed_base(&self, x: &Rational) -> PAdicNumber {
+        self.new(*x)
+    }
+}
+
+impl Algebra<PAdicIntegers> for PAdicField {
+    #[inline]
+    fn embed_base(&self, x: &PAdicInteger) -> PAdicNumber {
+        self.new(x)
+    }
+}
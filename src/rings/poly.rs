@@ -0,0 +1,440 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Dense univariate polynomials over an arbitrary ring.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericPolyRing<T: Ring> {
+    ring: T,
+    var: Arc<str>,
+}
+
+/// An element of a `GenericPolyRing`. Coefficients are stored in order of
+/// increasing degree and there are never any trailing zeros, so the zero
+/// polynomial has no coefficients.
+#[derive(Clone)]
+pub struct GenericPoly<T: Ring> {
+    parent: GenericPolyRing<T>,
+    coeffs: Vec<Elem<T>>,
+}
+
+impl<T: Ring> GenericPolyRing<T> {
+    /// Return the generator of the polynomial ring.
+    pub fn gen(&self) -> GenericPoly<T> {
+        GenericPoly::from_coeffs(self, vec![self.ring.zero(), self.ring.one()])
+    }
+}
+
+impl<T: Ring> GenericPoly<T> {
+    #[inline]
+    pub(crate) fn from_coeffs(parent: &GenericPolyRing<T>, coeffs: Vec<Elem<T>>) -> Self {
+        let mut res = GenericPoly { parent: parent.clone(), coeffs };
+        res.normalize();
+        res
+    }
+
+    /// Return a reference to the coefficients, in order of increasing degree.
+    #[inline]
+    pub fn coefficients(&self) -> &[Elem<T>] {
+        &self.coeffs
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        while let Some(c) = self.coeffs.last() {
+            if c.is_zero() {
+                self.coeffs.pop();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+impl<T: Ring> PartialEq for GenericPoly<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+impl<T: Ring> Eq for GenericPoly<T>
+where
+    <T as Ring>::Element: Eq,
+{}
+
+impl<T: Ring> Hash for GenericPoly<T>
+where
+    <T as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coeffs.hash(state);
+    }
+}
+
+impl<T: Ring> fmt::Debug for GenericPoly<T>
+where
+    <T as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GenericPoly")
+            .field("var", &self.parent.var)
+            .field("coeffs", &self.coeffs)
+            .finish()
+    }
+}
+
+impl<T: Ring> fmt::Display for GenericPoly<T>
+where
+    <T as Ring>::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+        for (i, c) in self.coeffs.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+            match i {
+                0 => write!(f, "{}", c)?,
+                1 => write!(f, "{}*{}", c, self.parent.var)?,
+                _ => write!(f, "{}*{}^{}", c, self.parent.var, i)?,
+            }
+        }
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Ring> Parent for GenericPolyRing<T> {
+    type Element = GenericPoly<T>;
+}
+
+impl<T: Ring> Element for GenericPoly<T> {
+    type Parent = GenericPolyRing<T>;
+
+    #[inline]
+    fn parent(&self) -> GenericPolyRing<T> {
+        self.parent.clone()
+    }
+}
+
+impl<T: Ring> NewElement<Vec<Elem<T>>> for GenericPolyRing<T> {
+    #[inline]
+    fn new(&self, src: Vec<Elem<T>>) -> GenericPoly<T> {
+        GenericPoly::from_coeffs(self, src)
+    }
+}
+
+// Additive properties
+
+impl<T: Ring> Operation<Additive> for GenericPoly<T> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let (long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut coeffs = long.coeffs.clone();
+        for (c, d) in coeffs.iter_mut().zip(short.coeffs.iter()) {
+            *c += d;
+        }
+        GenericPoly::from_coeffs(&self.parent, coeffs)
+    }
+}
+
+impl<T: Ring> Identity<Additive> for GenericPolyRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericPoly<T> {
+        GenericPoly { parent: self.clone(), coeffs: vec![] }
+    }
+}
+
+impl<T: Ring> IsIdentity<Additive> for GenericPoly<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+}
+
+impl<T: Ring> TwoSidedInverse<Additive> for GenericPoly<T> {
+    fn two_sided_inverse(&self) -> Self {
+        let mut res = self.clone();
+        for c in res.coeffs.iter_mut() {
+            c.neg_assign();
+        }
+        res
+    }
+}
+
+impl<T: Ring> Divisible<Additive> for GenericPolyRing<T> {}
+
+impl<T: Ring> Associative<Additive> for GenericPolyRing<T> {}
+
+impl<T: Ring> Commutative<Additive> for GenericPolyRing<T> {}
+
+// Multiplicative properties
+
+impl<T: Ring> Operation<Multiplicative> for GenericPoly<T> {
+    fn operate(&self, rhs: &Self) -> Self {
+        if self.coeffs.is_empty() || rhs.coeffs.is_empty() {
+            return self.parent.zero();
+        }
+        let ring = &self.parent.ring;
+        let mut coeffs = vec![ring.zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                let mut t = a.clone();
+                t *= b;
+                coeffs[i + j] += &t;
+            }
+        }
+        GenericPoly::from_coeffs(&self.parent, coeffs)
+    }
+}
+
+impl<T: Ring> Identity<Multiplicative> for GenericPolyRing<T> {
+    #[inline]
+    fn identity(&self) -> GenericPoly<T> {
+        GenericPoly::from_coeffs(self, vec![self.ring.one()])
+    }
+}
+
+impl<T: Ring> IsIdentity<Multiplicative> for GenericPoly<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs.len() == 1 && self.coeffs[0].is_one()
+    }
+}
+
+impl<T: Ring> Associative<Multiplicative> for GenericPolyRing<T> {}
+
+impl<T: Ring> Commutative<Multiplicative> for GenericPolyRing<T> {}
+
+// Ring-like properties
+
+impl<T: Ring> Distributive for GenericPolyRing<T> {}
+
+impl_ring_ops!([T: Ring] GenericPoly<T>);
+
+impl<T: Ring> Algebra<T> for GenericPolyRing<T> {
+    #[inline]
+    fn embed_base(&self, x: &Elem<T>) -> GenericPoly<T> {
+        GenericPoly::from_coeffs(self, vec![x.clone()])
+    }
+}
+
+impl<T: Ring> PolynomialRing<T> for GenericPolyRing<T> {
+    type Element = GenericPoly<T>;
+
+    fn init<S: Into<String>>(ring: &T, var: S) -> Self {
+        GenericPolyRing { ring: ring.clone(), var: var.into().into() }
+    }
+
+    #[inline]
+    fn base_ring(&self) -> &T {
+        &self.ring
+    }
+
+    #[inline]
+    fn var(&self) -> String {
+        self.var.to_string()
+    }
+
+    #[inline]
+    fn set_var<S: Into<String>>(&mut self, var: S) {
+        self.var = var.into().into();
+    }
+
+    #[inline]
+    fn is_generic(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Ring> PolynomialRingElement<T> for GenericPoly<T> {
+    type Parent = GenericPolyRing<T>;
+
+    type Borrow<'a> = &'a Elem<T> where T: 'a;
+    type BorrowMut<'a> = &'a mut Elem<T> where T: 'a;
+
+    #[inline]
+    fn base_ring(&self) -> &T {
+        &self.parent.ring
+    }
+
+    #[inline]
+    fn var(&self) -> String {
+        self.parent.var.to_string()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    fn get_coefficient(&self, i: usize) -> Elem<T> {
+        match self.coeffs.get(i) {
+            Some(c) => c.clone(),
+            None => self.parent.ring.zero(),
+        }
+    }
+
+    fn set_coefficient(&mut self, i: usize, coeff: Elem<T>) {
+        if i >= self.coeffs.len() {
+            if coeff.is_zero() {
+                return;
+            }
+            self.coeffs.resize(i + 1, self.parent.ring.zero());
+        }
+        self.coeffs[i] = coeff;
+        self.normalize();
+    }
+
+    #[inline]
+    fn get_coefficients(&self) -> Vec<Elem<T>> {
+        self.coeffs.clone()
+    }
+
+    #[inline]
+    fn is_generic(&self) -> bool {
+        true
+    }
+}
+
+// Euclidean structure
+
+/// Polynomials over a field, with the degree as Euclidean function. Canonical
+/// associates are monic.
+impl<F: Field> EuclideanDomain for GenericPolyRing<F> {
+    fn div_rem(&self, a: &GenericPoly<F>, b: &GenericPoly<F>) -> (GenericPoly<F>, GenericPoly<F>) {
+        let (lead, _) = b.coeffs.split_last().expect("division by zero");
+        let mut inv = lead.clone();
+        inv.inv_assign();
+
+        let n = b.coeffs.len();
+        let mut rem = a.coeffs.clone();
+        if rem.len() < n {
+            return (self.zero(), a.clone());
+        }
+        let mut quo = vec![self.ring.zero(); rem.len() + 1 - n];
+        for i in (0..quo.len()).rev() {
+            let mut q = rem[i + n - 1].clone();
+            if q.is_zero() {
+                continue;
+            }
+            q *= &inv;
+            for (r, c) in rem[i..].iter_mut().zip(b.coeffs.iter()) {
+                let mut t = q.clone();
+                t *= c;
+                *r -= &t;
+            }
+            quo[i] = q;
+        }
+        rem.truncate(n - 1);
+        (GenericPoly::from_coeffs(self, quo), GenericPoly::from_coeffs(self, rem))
+    }
+
+    fn canonical_unit(&self, a: &GenericPoly<F>) -> GenericPoly<F> {
+        match a.coeffs.last() {
+            Some(c) => {
+                let mut inv = c.clone();
+                inv.inv_assign();
+                GenericPoly::from_coeffs(self, vec![inv])
+            }
+            None => self.one(),
+        }
+    }
+
+    /// Only decided for polynomials of degree at most one.
+    fn is_irreducible(&self, a: &GenericPoly<F>) -> Option<bool> {
+        match a.degree() {
+            1 => Some(true),
+            d if d < 1 => Some(false),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn is_unit(&self, a: &GenericPoly<F>) -> bool {
+        a.coeffs.len() == 1
+    }
+}
+
+// Evaluation
+
+/// Evaluate the polynomial at any element of an algebra over the base ring, for
+/// example an element of the base ring itself, a square matrix or another
+/// polynomial (composition).
+impl<'a, T: Ring, X> Evaluate<&'a X> for GenericPoly<T>
+where
+    X: Element + AddOps + MulOps,
+    Par<X>: Algebra<T>,
+{
+    type Output = X;
+
+    fn evaluate(&self, x: &'a X) -> X {
+        let mut res = x.parent().embed_base(&self.parent.ring.zero());
+        res.assign_evaluate(self, x);
+        res
+    }
+}
+
+/// Evaluate a polynomial using Horner's method, writing the result into `self`.
+impl<'a, 'b, T: Ring, X> AssignEvaluate<&'a GenericPoly<T>, &'b X> for X
+where
+    X: Element + AddOps + MulOps,
+    Par<X>: Algebra<T>,
+{
+    fn assign_evaluate(&mut self, expr: &'a GenericPoly<T>, x: &'b X) {
+        let alg = x.parent();
+        match expr.coeffs.split_last() {
+            None => *self = alg.embed_base(&expr.parent.ring.zero()),
+            Some((lead, rest)) => {
+                *self = alg.embed_base(lead);
+                for c in rest.iter().rev() {
+                    *self *= x;
+                    *self += &alg.embed_base(c);
+                }
+            }
+        }
+    }
+}
+
+/// Evaluate an integer polynomial modulo `modulus`, returning the least
+/// non-negative residue. Intermediate results are reduced at every step and
+/// computed with 128-bit arithmetic, so this never overflows.
+impl<'a, 'b> EvaluateMod<&'a i64, &'b i64> for GenericPoly<IntegerRing> {
+    type Output = i64;
+
+    fn evaluate_mod(&self, x: &'a i64, modulus: &'b i64) -> i64 {
+        let mut res = 0;
+        res.assign_evaluate_mod(self, x, modulus);
+        res
+    }
+}
+
+impl<'a, 'b, 'c> AssignEvaluateMod<&'a GenericPoly<IntegerRing>, &'b i64, &'c i64> for i64 {
+    fn assign_evaluate_mod(
+        &mut self,
+        expr: &'a GenericPoly<IntegerRing>,
+        x: &'b i64,
+        modulus: &'c i64
+    ) {
+        assert!(*modulus > 0, "modulus must be positive");
+        let m = *modulus as i128;
+        let x = (*x as i128).rem_euclid(m);
+        let mut res = 0i128;
+        for c in expr.coeffs.iter().rev() {
+            res = (res * x + *c as i128).rem_euclid(m);
+        }
+        *self = res as i64;
+    }
+}
@@ -0,0 +1,496 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Truncated power series over an arbitrary ring. The parent has a precision cap
+/// `n`, so that exact elements such as zero, one and polynomials are known modulo
+/// `x^n`. Every element carries its own precision, which is at most the cap and
+/// decreases as information is lost through arithmetic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PowerSeriesRing<T: Ring> {
+    ring: T,
+    var: Arc<str>,
+    prec: u64,
+}
+
+/// An element of a `PowerSeriesRing`: a polynomial together with a precision
+/// `p`, representing the series modulo `O(x^p)`. Coefficients are stored in order
+/// of increasing degree, there are never any trailing zeros and no coefficients
+/// of degree `p` or higher.
+///
+/// Two series compare equal if their coefficients agree up to the smaller of
+/// their precisions.
+#[derive(Clone)]
+pub struct PowerSeries<T: Ring> {
+    parent: PowerSeriesRing<T>,
+    coeffs: Vec<Elem<T>>,
+    prec: u64,
+}
+
+/// Return the coefficients of `a * b` of degree less than `n`.
+pub(crate) fn mul_trunc<T: Ring>(ring: &T, a: &[Elem<T>], b: &[Elem<T>], n: usize) -> Vec<Elem<T>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let len = (a.len() + b.len() - 1).min(n);
+    let mut res = vec![ring.zero(); len];
+    for (i, x) in a.iter().enumerate().take(len) {
+        for (j, y) in b.iter().enumerate().take(len - i) {
+            let mut t = x.clone();
+            t *= y;
+            res[i + j] += &t;
+        }
+    }
+    res
+}
+
+/// Return the first `n` coefficients of the inverse of `f`, whose constant
+/// term must be nonzero, using the iteration `g <- g(2 - fg)`.
+pub(crate) fn inverse_trunc<T: Field>(ring: &T, f: &[Elem<T>], n: usize) -> Vec<Elem<T>> {
+    let mut c = f.first().filter(|c| !c.is_zero())
+        .expect("constant term of the series is not invertible")
+        .clone();
+    c.inv_assign();
+    let mut g = vec![c];
+    let mut k = 1;
+    while k < n {
+        k = (2 * k).min(n);
+        let mut e = mul_trunc(ring, &f[..f.len().min(k)], &g, k);
+        for x in e.iter_mut() {
+            x.neg_assign();
+        }
+        e[0] += &integer(ring, 2);
+        g = mul_trunc(ring, &g, &e, k);
+    }
+    g
+}
+
+/// Return the integer `k` as an element of the ring.
+#[inline]
+pub(crate) fn integer<T: Ring>(ring: &T, k: u64) -> Elem<T> {
+    AbstractMonoid::<Additive>::power(ring, Additive, &ring.one(), k)
+}
+
+impl<T: Ring> PowerSeriesRing<T> {
+    /// Initialize the `PowerSeriesRing` from a base ring, a variable used for
+    /// printing and the precision cap.
+    pub fn init<S: Into<String>>(ring: &T, var: S, prec: u64) -> Self {
+        PowerSeriesRing { ring: ring.clone(), var: var.into().into(), prec }
+    }
+
+    /// Return a reference to the base ring.
+    #[inline]
+    pub fn base_ring(&self) -> &T {
+        &self.ring
+    }
+
+    /// Return the variable as a `String`.
+    #[inline]
+    pub fn var(&self) -> String {
+        self.var.to_string()
+    }
+
+    /// Return the precision cap.
+    #[inline]
+    pub fn precision_cap(&self) -> u64 {
+        self.prec
+    }
+
+    /// Return the generator of the power series ring.
+    pub fn gen(&self) -> PowerSeries<T> {
+        self.new(vec![self.ring.zero(), self.ring.one()])
+    }
+
+    /// Return the series with the given coefficients modulo `O(x^prec)`. The
+    /// precision is reduced to the precision cap if necessary.
+    pub fn series(&self, coeffs: Vec<Elem<T>>, prec: u64) -> PowerSeries<T> {
+        PowerSeries::from_coeffs(self, coeffs, prec)
+    }
+}
+
+impl<T: Ring> PowerSeries<T> {
+    fn from_coeffs(parent: &PowerSeriesRing<T>, coeffs: Vec<Elem<T>>, prec: u64) -> Self {
+        let mut res = PowerSeries { parent: parent.clone(), coeffs, prec: prec.min(parent.prec) };
+        res.normalize();
+        res
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        self.coeffs.truncate(self.prec as usize);
+        while let Some(c) = self.coeffs.last() {
+            if c.is_zero() {
+                self.coeffs.pop();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Return a reference to the known coefficients, in order of increasing
+    /// degree.
+    #[inline]
+    pub fn coefficients(&self) -> &[Elem<T>] {
+        &self.coeffs
+    }
+
+    /// Return the coefficient of `x^i`. Panics if `i` is not less than the
+    /// precision.
+    pub fn coeff(&self, i: u64) -> Elem<T> {
+        assert!(i < self.prec, "coefficient is beyond the precision of the series");
+        match self.coeffs.get(i as usize) {
+            Some(c) => c.clone(),
+            None => self.parent.ring.zero(),
+        }
+    }
+
+    /// Return the precision `p` of the series, so that it is known modulo
+    /// `O(x^p)`.
+    #[inline]
+    pub fn prec(&self) -> u64 {
+        self.prec
+    }
+
+    /// Return the index of the first nonzero coefficient, or the precision if the
+    /// series is zero to its precision.
+    pub fn valuation(&self) -> u64 {
+        self.coeffs.iter()
+            .position(|c| !c.is_zero())
+            .map_or(self.prec, |v| v as u64)
+    }
+
+    /// Return the series modulo `O(x^n)`.
+    pub fn truncate(&self, n: u64) -> Self {
+        PowerSeries::from_coeffs(&self.parent, self.coeffs.clone(), n.min(self.prec))
+    }
+
+    /// Return the formal derivative. The precision decreases by one.
+    pub fn derivative(&self) -> Self {
+        let ring = &self.parent.ring;
+        let coeffs = self.coeffs.iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| {
+                let mut t = integer(ring, i as u64);
+                t *= c;
+                t
+            })
+            .collect();
+        PowerSeries::from_coeffs(&self.parent, coeffs, self.prec.saturating_sub(1))
+    }
+
+    /// Return the composition `self(g)`. Panics unless `g` has positive valuation.
+    pub fn compose(&self, g: &Self) -> Self {
+        let v = g.valuation();
+        assert!(v > 0, "composition requires a series with positive valuation");
+        let prec = self.prec.saturating_mul(v).min(g.prec).min(self.parent.prec);
+        let n = prec as usize;
+
+        let ring = &self.parent.ring;
+        let mut res: Vec<Elem<T>> = vec![];
+        for c in self.coeffs.iter().take(n).rev() {
+            res = mul_trunc(ring, &res, &g.coeffs, n);
+            match res.first_mut() {
+                Some(r) => *r += c,
+                None => res.push(c.clone()),
+            }
+        }
+        PowerSeries::from_coeffs(&self.parent, res, prec)
+    }
+
+    /// Return the known coefficients as a polynomial.
+    pub fn polynomial<P: PolynomialRing<T>>(&self, ring: &P) -> <P as PolynomialRing<T>>::Element {
+        let mut res = ring.zero();
+        for (i, c) in self.coeffs.iter().enumerate() {
+            res.set_coefficient(i, c.clone());
+        }
+        res
+    }
+}
+
+/// Newton iteration for fields. Each method loses no precision: the result is
+/// known to the same precision as `self`.
+impl<T: Field> PowerSeries<T> {
+    /// Return the first `n` coefficients of the logarithm of `f`, whose constant
+    /// term must be one, as the integral of `f'/f`.
+    fn log_trunc(ring: &T, f: &[Elem<T>], n: usize) -> Vec<Elem<T>> {
+        if n <= 1 {
+            return vec![];
+        }
+        let df: Vec<Elem<T>> = f.iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| {
+                let mut t = integer(ring, i as u64);
+                t *= c;
+                t
+            })
+            .collect();
+        let q = mul_trunc(ring, &df, &inverse_trunc(ring, f, n - 1), n - 1);
+        Self::integrate(ring, &q)
+    }
+
+    /// Return the coefficients of the integral with zero constant term. Panics if
+    /// the characteristic of the field is too small.
+    fn integrate(ring: &T, f: &[Elem<T>]) -> Vec<Elem<T>> {
+        let mut res = vec![ring.zero()];
+        for (i, c) in f.iter().enumerate() {
+            let mut d = integer(ring, i as u64 + 1);
+            assert!(!d.is_zero(), "characteristic of the base field is too small");
+            d.inv_assign();
+            d *= c;
+            res.push(d);
+        }
+        res
+    }
+
+    /// Return the multiplicative inverse. Panics if the constant term is zero.
+    pub fn inverse(&self) -> Self {
+        let g = inverse_trunc(&self.parent.ring, &self.coeffs, self.prec as usize);
+        PowerSeries::from_coeffs(&self.parent, g, self.prec)
+    }
+
+    /// Return the formal integral with zero constant term. The precision
+    /// increases by one, up to the precision cap.
+    pub fn integral(&self) -> Self {
+        let res = Self::integrate(&self.parent.ring, &self.coeffs);
+        PowerSeries::from_coeffs(&self.parent, res, self.prec + 1)
+    }
+
+    /// Return the logarithm. Panics unless the constant term is one.
+    pub fn log(&self) -> Self {
+        assert!(
+            self.coeffs.first().is_some_and(|c| c.is_one()),
+            "logarithm requires a series with constant term one"
+        );
+        let res = Self::log_trunc(&self.parent.ring, &self.coeffs, self.prec as usize);
+        PowerSeries::from_coeffs(&self.parent, res, self.prec)
+    }
+
+    /// Return the exponential, using the iteration `g <- g(1 - log(g) + f)`.
+    /// Panics unless the constant term is zero.
+    pub fn exp(&self) -> Self {
+        assert!(
+            self.coeffs.first().is_none_or(|c| c.is_zero()),
+            "exponential requires a series with constant term zero"
+        );
+        let ring = &self.parent.ring;
+        let n = self.prec as usize;
+        let mut g = vec![ring.one()];
+        let mut k = 1;
+        while k < n {
+            k = (2 * k).min(n);
+            let mut e = Self::log_trunc(ring, &g, k);
+            e.resize(k, ring.zero());
+            for x in e.iter_mut() {
+                x.neg_assign();
+            }
+            for (x, c) in e.iter_mut().zip(self.coeffs.iter()) {
+                *x += c;
+            }
+            e[0] += &ring.one();
+            g = mul_trunc(ring, &g, &e, k);
+        }
+        g.truncate(n);
+        PowerSeries::from_coeffs(&self.parent, g, self.prec)
+    }
+
+    /// Return the square root with constant term one, using the iteration
+    /// `g <- (g + f/g)/2`. Panics unless the constant term is one or if the
+    /// characteristic of the field is two.
+    pub fn sqrt(&self) -> Self {
+        assert!(
+            self.coeffs.first().is_some_and(|c| c.is_one()),
+            "square root requires a series with constant term one"
+        );
+        let ring = &self.parent.ring;
+        let mut half = integer(ring, 2);
+        assert!(!half.is_zero(), "characteristic of the base field is too small");
+        half.inv_assign();
+
+        let n = self.prec as usize;
+        let mut g = vec![ring.one()];
+        let mut k = 1;
+        while k < n {
+            k = (2 * k).min(n);
+            let f = &self.coeffs[..self.coeffs.len().min(k)];
+            let mut e = mul_trunc(ring, f, &inverse_trunc(ring, &g, k), k);
+            e.resize(k.max(g.len()), ring.zero());
+            for (x, c) in e.iter_mut().zip(g.iter()) {
+                *x += c;
+            }
+            for x in e.iter_mut() {
+                *x *= &half;
+            }
+            g = e;
+        }
+        PowerSeries::from_coeffs(&self.parent, g, self.prec)
+    }
+}
+
+impl<T: Ring> PartialEq for PowerSeries<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let n = self.prec.min(other.prec) as usize;
+        let (a, b) = (&self.coeffs[..self.coeffs.len().min(n)], &other.coeffs[..other.coeffs.len().min(n)]);
+        let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+        long[..short.len()] == *short && long[short.len()..].iter().all(|c| c.is_zero())
+    }
+}
+
+impl<T: Ring> fmt::Debug for PowerSeries<T>
+where
+    <T as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PowerSeries")
+            .field("var", &self.parent.var)
+            .field("coeffs", &self.coeffs)
+            .field("prec", &self.prec)
+            .finish()
+    }
+}
+
+impl<T: Ring> fmt::Display for PowerSeries<T>
+where
+    <T as Ring>::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let var = &self.parent.var;
+        for (i, c) in self.coeffs.iter().enumerate() {
+            if c.is_zero() {
+                continue;
+            }
+            match i {
+                0 => write!(f, "{} + ", c)?,
+                1 => write!(f, "{}*{} + ", c, var)?,
+                _ => write!(f, "{}*{}^{} + ", c, var, i)?,
+            }
+        }
+        write!(f, "O({}^{})", var, self.prec)
+    }
+}
+
+impl<T: Ring> Parent for PowerSeriesRing<T> {
+    type Element = PowerSeries<T>;
+}
+
+impl<T: Ring> Element for PowerSeries<T> {
+    type Parent = PowerSeriesRing<T>;
+
+    #[inline]
+    fn parent(&self) -> PowerSeriesRing<T> {
+        self.parent.clone()
+    }
+}
+
+/// Construct a series from its coefficients, known up to the precision cap.
+impl<T: Ring> NewElement<Vec<Elem<T>>> for PowerSeriesRing<T> {
+    #[inline]
+    fn new(&self, src: Vec<Elem<T>>) -> PowerSeries<T> {
+        PowerSeries::from_coeffs(self, src, self.prec)
+    }
+}
+
+/// Construct a series from a polynomial, truncated at the precision cap.
+impl<'a, T: Ring, P: PolynomialRingElement<T>> NewElement<&'a P> for PowerSeriesRing<T> {
+    #[inline]
+    fn new(&self, src: &'a P) -> PowerSeries<T> {
+        let n = src.len().min(self.prec as usize);
+        let coeffs = (0..n).map(|i| src.get_coefficient(i)).collect();
+        PowerSeries::from_coeffs(self, coeffs, self.prec)
+    }
+}
+
+// Additive properties
+
+impl<T: Ring> Operation<Additive> for PowerSeries<T> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let (long, short) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut coeffs = long.coeffs.clone();
+        for (c, d) in coeffs.iter_mut().zip(short.coeffs.iter()) {
+            *c += d;
+        }
+        PowerSeries::from_coeffs(&self.parent, coeffs, self.prec.min(rhs.prec))
+    }
+}
+
+impl<T: Ring> Identity<Additive> for PowerSeriesRing<T> {
+    #[inline]
+    fn identity(&self) -> PowerSeries<T> {
+        PowerSeries { parent: self.clone(), coeffs: vec![], prec: self.prec }
+    }
+}
+
+impl<T: Ring> IsIdentity<Additive> for PowerSeries<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+}
+
+impl<T: Ring> TwoSidedInverse<Additive> for PowerSeries<T> {
+    fn two_sided_inverse(&self) -> Self {
+        let mut res = self.clone();
+        for c in res.coeffs.iter_mut() {
+            c.neg_assign();
+        }
+        res
+    }
+}
+
+impl<T: Ring> Divisible<Additive> for PowerSeriesRing<T> {}
+
+impl<T: Ring> Associative<Additive> for PowerSeriesRing<T> {}
+
+impl<T: Ring> Commutative<Additive> for PowerSeriesRing<T> {}
+
+// Multiplicative properties
+
+/// The product of series known modulo `O(x^p)` and `O(x^q)` with valuations `v`
+/// and `w` is known modulo `O(x^min(p + w, q + v))`.
+impl<T: Ring> Operation<Multiplicative> for PowerSeries<T> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let prec = self.prec.saturating_add(rhs.valuation())
+            .min(rhs.prec.saturating_add(self.valuation()))
+            .min(self.parent.prec);
+        let coeffs = mul_trunc(&self.parent.ring, &self.coeffs, &rhs.coeffs, prec as usize);
+        PowerSeries::from_coeffs(&self.parent, coeffs, prec)
+    }
+}
+
+impl<T: Ring> Identity<Multiplicative> for PowerSeriesRing<T> {
+    #[inline]
+    fn identity(&self) -> PowerSeries<T> {
+        PowerSeries::from_coeffs(self, vec![self.ring.one()], self.prec)
+    }
+}
+
+impl<T: Ring> IsIdentity<Multiplicative> for PowerSeries<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs.len() == 1 && self.coeffs[0].is_one()
+    }
+}
+
+impl<T: Ring> Associative<Multiplicative> for PowerSeriesRing<T> {}
+
+impl<T: Ring> Commutative<Multiplicative> for PowerSeriesRing<T> {}
+
+// Ring-like properties
+
+impl<T: Ring> Distributive for PowerSeriesRing<T> {}
+
+impl_ring_ops!([T: Ring] PowerSeries<T>);
+
+impl<T: Ring> Algebra<T> for PowerSeriesRing<T> {
+    #[inline]
+    fn embed_base(&self, x: &Elem<T>) -> PowerSeries<T> {
+        PowerSeries::from_coeffs(self, vec![x.clone()], self.prec)
+    }
+}
@@ -0,0 +1,225 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// The kind of a `ProductParent`, which determines at the type level which
+/// structure the product inherits from its factors.
+pub trait ProductKind: Clone + Copy + fmt::Debug + Default + PartialEq + Eq {}
+
+/// A product of groups, monoids or other one-operation structures. Every
+/// property shared by both factors carries over, including divisibility.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GroupProduct;
+
+/// A product of rings. It is distributive, but only its additive structure is
+/// divisible: a product of fields is a ring but not a field, since
+/// `(1, 0) (0, 1) = 0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RingProduct;
+
+impl ProductKind for GroupProduct {}
+
+impl ProductKind for RingProduct {}
+
+/// The direct product `A × B` of two parents, such as `G × H` for groups or
+/// `R × S` for rings. Elements are pairs `(a, b)` and every operation acts
+/// componentwise.
+///
+/// A product is initialized as a product of groups; use `as_ring` to obtain
+/// the product of two rings. The elements do not support the arithmetic
+/// operators, so a product of rings is an `AbstractRing` rather than a `Ring`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProductParent<A: Parent, B: Parent, K: ProductKind = GroupProduct> {
+    first: A,
+    second: B,
+    kind: PhantomData<K>,
+}
+
+/// The direct product `R × S` of two rings. Obtained from a `ProductParent`
+/// using `as_ring`.
+pub type ProductRing<A, B> = ProductParent<A, B, RingProduct>;
+
+/// An element `(a, b)` of a `ProductParent`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProductElement<X: Element, Y: Element, K: ProductKind = GroupProduct> {
+    first: X,
+    second: Y,
+    kind: PhantomData<K>,
+}
+
+impl<A: Parent, B: Parent> ProductParent<A, B> {
+    /// Initialize the product of `first` and `second`.
+    pub fn init(first: &A, second: &B) -> Self {
+        ProductParent { first: first.clone(), second: second.clone(), kind: PhantomData }
+    }
+
+    /// Return the product as a product of rings.
+    pub fn as_ring(&self) -> ProductRing<A, B>
+    where
+        A: Distributive,
+        B: Distributive,
+    {
+        ProductParent { first: self.first.clone(), second: self.second.clone(), kind: PhantomData }
+    }
+}
+
+impl<A: Parent, B: Parent, K: ProductKind> ProductParent<A, B, K> {
+    /// Return a reference to the first factor.
+    #[inline]
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Return a reference to the second factor.
+    #[inline]
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<X: Element, Y: Element, K: ProductKind> ProductElement<X, Y, K> {
+    #[inline]
+    fn pair(first: X, second: Y) -> Self {
+        ProductElement { first, second, kind: PhantomData }
+    }
+
+    /// Return a reference to the first component.
+    #[inline]
+    pub fn first(&self) -> &X {
+        &self.first
+    }
+
+    /// Return a reference to the second component.
+    #[inline]
+    pub fn second(&self) -> &Y {
+        &self.second
+    }
+
+    /// Return the components as a pair.
+    #[inline]
+    pub fn into_pair(self) -> (X, Y) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: Parent, B: Parent, K: ProductKind> Parent for ProductParent<A, B, K> {
+    type Element = ProductElement<Elem<A>, Elem<B>, K>;
+}
+
+impl<X: Element, Y: Element, K: ProductKind> Element for ProductElement<X, Y, K> {
+    type Parent = ProductParent<Par<X>, Par<Y>, K>;
+
+    #[inline]
+    fn parent(&self) -> ProductParent<Par<X>, Par<Y>, K> {
+        ProductParent { first: self.first.parent(), second: self.second.parent(), kind: PhantomData }
+    }
+}
+
+/// Construct a pair from elements of the factors.
+impl<A: Parent, B: Parent, K: ProductKind> NewElement<(Elem<A>, Elem<B>)> for ProductParent<A, B, K> {
+    #[inline]
+    fn new(&self, src: (Elem<A>, Elem<B>)) -> ProductElement<Elem<A>, Elem<B>, K> {
+        ProductElement::pair(src.0, src.1)
+    }
+}
+
+impl<O, X, Y, K> Operation<O> for ProductElement<X, Y, K>
+where
+    O: Operator,
+    X: Element + Operation<O>,
+    Y: Element + Operation<O>,
+    K: ProductKind,
+{
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        ProductElement::pair(self.first.operate(&rhs.first), self.second.operate(&rhs.second))
+    }
+}
+
+impl<O, X, Y, K> TwoSidedInverse<O> for ProductElement<X, Y, K>
+where
+    O: Operator,
+    X: Element + TwoSidedInverse<O>,
+    Y: Element + TwoSidedInverse<O>,
+    K: ProductKind,
+{
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        ProductElement::pair(self.first.two_sided_inverse(), self.second.two_sided_inverse())
+    }
+}
+
+impl<O, X, Y, K> IsIdentity<O> for ProductElement<X, Y, K>
+where
+    O: Operator,
+    X: Element + IsIdentity<O>,
+    Y: Element + IsIdentity<O>,
+    K: ProductKind,
+{
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.first.is_identity() && self.second.is_identity()
+    }
+}
+
+impl<O, A, B, K> Identity<O> for ProductParent<A, B, K>
+where
+    O: Operator,
+    K: ProductKind,
+    A: Identity<O>,
+    B: Identity<O>,
+    Elem<A>: Operation<O>,
+    Elem<B>: Operation<O>,
+{
+    #[inline]
+    fn identity(&self) -> ProductElement<Elem<A>, Elem<B>, K> {
+        ProductElement::pair(self.first.identity(), self.second.identity())
+    }
+}
+
+impl<O, A, B> Divisible<O> for ProductParent<A, B>
+where
+    O: Operator,
+    A: Divisible<O>,
+    B: Divisible<O>,
+    Elem<A>: Operation<O> + TwoSidedInverse<O>,
+    Elem<B>: Operation<O> + TwoSidedInverse<O>,
+{}
+
+impl<A, B> Divisible<Additive> for ProductRing<A, B>
+where
+    A: Divisible<Additive>,
+    B: Divisible<Additive>,
+    Elem<A>: Operation<Additive> + TwoSidedInverse<Additive>,
+    Elem<B>: Operation<Additive> + TwoSidedInverse<Additive>,
+{}
+
+impl<O, A, B, K> Associative<O> for ProductParent<A, B, K>
+where
+    O: Operator,
+    K: ProductKind,
+    A: Associative<O>,
+    B: Associative<O>,
+    Elem<A>: Operation<O>,
+    Elem<B>: Operation<O>,
+{}
+
+impl<O, A, B, K> Commutative<O> for ProductParent<A, B, K>
+where
+    O: Operator,
+    K: ProductKind,
+    A: Commutative<O>,
+    B: Commutative<O>,
+    Elem<A>: Operation<O>,
+    Elem<B>: Operation<O>,
+{}
+
+impl<AO, MO, A, B> Distributive<AO, MO> for ProductRing<A, B>
+where
+    AO: Operator,
+    MO: Operator,
+    A: Parent + Distributive<AO, MO>,
+    B: Parent + Distributive<AO, MO>,
+{}
@@ -0,0 +1,576 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Whether a `QuadraticIntegerRing` is known to be norm-Euclidean, which
+/// determines at the type level whether it is a `EuclideanDomain`.
+pub trait QuadraticKind: Clone + Copy + fmt::Debug + Default + PartialEq + Eq {}
+
+/// A quadratic integer ring which is not known to be norm-Euclidean.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AnyQuadratic;
+
+/// A quadratic integer ring which is Euclidean with respect to the absolute
+/// value of the norm.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NormEuclidean;
+
+impl QuadraticKind for AnyQuadratic {}
+
+impl QuadraticKind for NormEuclidean {}
+
+/// Discriminants of the imaginary quadratic fields whose rings of integers are
+/// norm-Euclidean.
+const IMAGINARY_EUCLIDEAN: [i64; 5] = [-11, -8, -7, -4, -3];
+
+/// Discriminants of the real quadratic fields whose rings of integers are
+/// norm-Euclidean.
+const REAL_EUCLIDEAN: [i64; 16] = [5, 8, 12, 13, 17, 21, 24, 28, 29, 33, 37, 41, 44, 57, 73, 76];
+
+/// The ring of integers of the quadratic field of fundamental discriminant `d`,
+/// for example the Gaussian integers `Z[i]` (`d = -4`), `Z[√-2]` (`d = -8`) or
+/// `Z[(1 + √5)/2]` (`d = 5`).
+///
+/// Elements are written `a + bω` in the integral basis `1, ω`, where `ω = √(d/4)`
+/// if `d ≡ 0 mod 4` and `ω = (1 + √d)/2` if `d ≡ 1 mod 4`. Coordinates are `i64`s
+/// and intermediate results are computed with 128-bit integers; arithmetic panics
+/// if a result does not fit in 64 bits.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuadraticIntegerRing<K: QuadraticKind = AnyQuadratic> {
+    disc: i64,
+    var: Arc<str>,
+    unit: (i64, i64),
+    kind: PhantomData<K>,
+}
+
+/// A norm-Euclidean quadratic integer ring. Obtained from a
+/// `QuadraticIntegerRing` using `as_euclidean`.
+pub type EuclideanQuadraticRing = QuadraticIntegerRing<NormEuclidean>;
+
+/// An element `a + bω` of a `QuadraticIntegerRing`.
+#[derive(Clone)]
+pub struct QuadraticInteger<K: QuadraticKind = AnyQuadratic> {
+    parent: QuadraticIntegerRing<K>,
+    a: i64,
+    b: i64,
+}
+
+#[inline]
+fn to_i64(x: i128) -> i64 {
+    x.try_into().expect("quadratic integer overflow")
+}
+
+fn is_squarefree(n: i64) -> bool {
+    let n = n.unsigned_abs();
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p * p) {
+            return false;
+        }
+        p += 1;
+    }
+    true
+}
+
+fn isqrt(n: i128) -> Option<i128> {
+    if n < 0 {
+        return None;
+    }
+    let mut r = (n as f64).sqrt() as i128;
+    while r * r > n {
+        r -= 1;
+    }
+    while (r + 1) * (r + 1) <= n {
+        r += 1;
+    }
+    Some(r)
+}
+
+impl QuadraticIntegerRing {
+    /// Initialize the ring of integers of discriminant `d`, printing `ω` as
+    /// `var`. Panics if `d` is not a fundamental discriminant.
+    pub fn init<S: Into<String>>(d: i64, var: S) -> Self {
+        let fundamental = match d.rem_euclid(4) {
+            1 => d != 1 && is_squarefree(d),
+            0 => matches!((d / 4).rem_euclid(4), 2 | 3) && is_squarefree(d / 4),
+            _ => false,
+        };
+        assert!(fundamental, "{} is not a fundamental discriminant", d);
+        QuadraticIntegerRing { disc: d, var: var.into().into(), unit: (1, 0), kind: PhantomData }
+    }
+
+    /// Return the ring as a `EuclideanDomain`, or `None` if it is not
+    /// norm-Euclidean.
+    pub fn as_euclidean(&self) -> Option<EuclideanQuadraticRing> {
+        if !self.is_norm_euclidean() {
+            return None;
+        }
+        let mut ring = QuadraticIntegerRing {
+            disc: self.disc,
+            var: self.var.clone(),
+            unit: (1, 0),
+            kind: PhantomData,
+        };
+        if self.disc > 0 {
+            ring.unit = ring.find_fundamental_unit();
+        }
+        Some(ring)
+    }
+}
+
+impl<K: QuadraticKind> QuadraticIntegerRing<K> {
+    /// Return the discriminant.
+    #[inline]
+    pub fn discriminant(&self) -> i64 {
+        self.disc
+    }
+
+    #[inline]
+    pub fn var(&self) -> String {
+        self.var.to_string()
+    }
+
+    /// Return `true` if the field is imaginary, that is, the discriminant is
+    /// negative.
+    #[inline]
+    pub fn is_imaginary(&self) -> bool {
+        self.disc < 0
+    }
+
+    /// Return `true` if the ring is Euclidean with respect to the absolute value
+    /// of the norm.
+    pub fn is_norm_euclidean(&self) -> bool {
+        IMAGINARY_EUCLIDEAN.contains(&self.disc) || REAL_EUCLIDEAN.contains(&self.disc)
+    }
+
+    /// Return the generator `ω` of the integral basis.
+    #[inline]
+    pub fn gen(&self) -> QuadraticInteger<K> {
+        self.elem(0, 1)
+    }
+
+    /// Return `(t, n)` such that `ω^2 = tω + n`.
+    #[inline]
+    fn min_poly(&self) -> (i128, i128) {
+        if self.disc.rem_euclid(4) == 1 {
+            (1, (self.disc as i128 - 1) / 4)
+        } else {
+            (0, self.disc as i128 / 4)
+        }
+    }
+
+    #[inline]
+    fn elem(&self, a: i64, b: i64) -> QuadraticInteger<K> {
+        QuadraticInteger { parent: self.clone(), a, b }
+    }
+
+    #[inline]
+    fn elem_i128(&self, a: i128, b: i128) -> QuadraticInteger<K> {
+        self.elem(to_i64(a), to_i64(b))
+    }
+
+    /// Return the smallest unit greater than one, which exists for real fields.
+    fn find_fundamental_unit(&self) -> (i64, i64) {
+        let (t, n) = self.min_poly();
+        let d = self.disc as i128;
+        for b in 1i128.. {
+            let mut best = None;
+            for s in [-4, 4] {
+                // Solve `a^2 + tab - nb^2 = ±1` for `a`.
+                let a = if t == 0 {
+                    isqrt(n * b * b + s / 4).map(|r| (r * r == n * b * b + s / 4).then_some(r))
+                } else {
+                    isqrt(d * b * b + s)
+                        .map(|r| (r * r == d * b * b + s && (r - b) % 2 == 0).then_some((r - b) / 2))
+                };
+                if let Some(Some(a)) = a {
+                    if best.is_none_or(|x| a < x) {
+                        best = Some(a);
+                    }
+                }
+            }
+            if let Some(a) = best {
+                return (to_i64(a), to_i64(b));
+            }
+        }
+        unreachable!()
+    }
+
+    /// Return the units of an imaginary quadratic field.
+    fn torsion_units(&self) -> Vec<QuadraticInteger<K>> {
+        let mut units = vec![self.elem(1, 0), self.elem(-1, 0)];
+        match self.disc {
+            -4 => units.extend([self.elem(0, 1), self.elem(0, -1)]),
+            -3 => units.extend([
+                self.elem(0, 1),
+                self.elem(0, -1),
+                self.elem(-1, 1),
+                self.elem(1, -1),
+            ]),
+            _ => {}
+        }
+        units
+    }
+}
+
+impl<K: QuadraticKind> QuadraticInteger<K> {
+    /// Return the coordinates `(a, b)` of `a + bω`.
+    #[inline]
+    pub fn coefficients(&self) -> (i64, i64) {
+        (self.a, self.b)
+    }
+
+    /// Return the Galois conjugate.
+    pub fn conjugate(&self) -> Self {
+        let (t, _) = self.parent.min_poly();
+        self.parent.elem_i128(self.a as i128 + t * self.b as i128, -(self.b as i128))
+    }
+
+    #[inline]
+    fn norm_i128(&self) -> i128 {
+        let (t, n) = self.parent.min_poly();
+        let (a, b) = (self.a as i128, self.b as i128);
+        a * a + t * a * b - n * b * b
+    }
+
+    /// Return the norm, the product of the element with its conjugate.
+    #[inline]
+    pub fn norm(&self) -> i64 {
+        to_i64(self.norm_i128())
+    }
+
+    /// Return the trace, the sum of the element with its conjugate.
+    #[inline]
+    pub fn trace(&self) -> i64 {
+        let (t, _) = self.parent.min_poly();
+        to_i64(2 * self.a as i128 + t * self.b as i128)
+    }
+}
+
+impl<K: QuadraticKind> PartialEq for QuadraticInteger<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a && self.b == other.b
+    }
+}
+
+impl<K: QuadraticKind> fmt::Debug for QuadraticInteger<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QuadraticInteger")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("disc", &self.parent.disc)
+            .finish()
+    }
+}
+
+impl<K: QuadraticKind> fmt::Display for QuadraticInteger<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let var = &self.parent.var;
+        match (self.a, self.b) {
+            (a, 0) => write!(f, "{}", a),
+            (0, b) => write!(f, "{}*{}", b, var),
+            (a, b) if b < 0 => write!(f, "{} - {}*{}", a, b.unsigned_abs(), var),
+            (a, b) => write!(f, "{} + {}*{}", a, b, var),
+        }
+    }
+}
+
+impl<K: QuadraticKind> Parent for QuadraticIntegerRing<K> {
+    type Element = QuadraticInteger<K>;
+}
+
+impl<K: QuadraticKind> Element for QuadraticInteger<K> {
+    type Parent = QuadraticIntegerRing<K>;
+
+    #[inline]
+    fn parent(&self) -> QuadraticIntegerRing<K> {
+        self.parent.clone()
+    }
+}
+
+impl<K: QuadraticKind> NewElement<i64> for QuadraticIntegerRing<K> {
+    #[inline]
+    fn new(&self, src: i64) -> QuadraticInteger<K> {
+        self.elem(src, 0)
+    }
+}
+
+/// Construct `a + bω` from `(a, b)`.
+impl<K: QuadraticKind> NewElement<(i64, i64)> for QuadraticIntegerRing<K> {
+    #[inline]
+    fn new(&self, src: (i64, i64)) -> QuadraticInteger<K> {
+        self.elem(src.0, src.1)
+    }
+}
+
+// Additive properties
+
+impl<K: QuadraticKind> Operation<Additive> for QuadraticInteger<K> {
+    #[inline]
+    fn operate(&self, rhs: &Self) -> Self {
+        self.parent.elem_i128(
+            self.a as i128 + rhs.a as i128,
+            self.b as i128 + rhs.b as i128,
+        )
+    }
+}
+
+impl<K: QuadraticKind> Identity<Additive> for QuadraticIntegerRing<K> {
+    #[inline]
+    fn identity(&self) -> QuadraticInteger<K> {
+        self.elem(0, 0)
+    }
+}
+
+impl<K: QuadraticKind> IsIdentity<Additive> for QuadraticInteger<K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.a == 0 && self.b == 0
+    }
+}
+
+impl<K: QuadraticKind> TwoSidedInverse<Additive> for QuadraticInteger<K> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.parent.elem_i128(-(self.a as i128), -(self.b as i128))
+    }
+}
+
+impl<K: QuadraticKind> Divisible<Additive> for QuadraticIntegerRing<K> {}
+
+impl<K: QuadraticKind> Associative<Additive> for QuadraticIntegerRing<K> {}
+
+impl<K: QuadraticKind> Commutative<Additive> for QuadraticIntegerRing<K> {}
+
+// Multiplicative properties
+
+/// `(a + bω)(c + eω) = ac + nbe + (ae + bc + tbe)ω` where `ω^2 = tω + n`.
+impl<K: QuadraticKind> Operation<Multiplicative> for QuadraticInteger<K> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let (t, n) = self.parent.min_poly();
+        let (a, b) = (self.a as i128, self.b as i128);
+        let (c, e) = (rhs.a as i128, rhs.b as i128);
+        let be = b * e;
+        self.parent.elem_i128(a * c + n * be, a * e + b * c + t * be)
+    }
+}
+
+impl<K: QuadraticKind> Identity<Multiplicative> for QuadraticIntegerRing<K> {
+    #[inline]
+    fn identity(&self) -> QuadraticInteger<K> {
+        self.elem(1, 0)
+    }
+}
+
+impl<K: QuadraticKind> IsIdentity<Multiplicative> for QuadraticInteger<K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.a == 1 && self.b == 0
+    }
+}
+
+impl<K: QuadraticKind> Associative<Multiplicative> for QuadraticIntegerRing<K> {}
+
+impl<K: QuadraticKind> Commutative<Multiplicative> for QuadraticIntegerRing<K> {}
+
+// Ring-like properties
+
+impl<K: QuadraticKind> Distributive for QuadraticIntegerRing<K> {}
+
+impl_ring_ops!([K: QuadraticKind] QuadraticInteger<K>);
+
+impl<K: QuadraticKind> Algebra<IntegerRing> for QuadraticIntegerRing<K> {
+    #[inline]
+    fn embed_base(&self, x: &i64) -> QuadraticInteger<K> {
+        self.elem(*x, 0)
+    }
+}
+
+// Euclidean structure
+
+/// Return the Kronecker symbol `(d/p)` for a prime `p`.
+fn kronecker(d: i64, p: i64) -> i64 {
+    if p == 2 {
+        return match d.rem_euclid(8) {
+            1 | 7 => 1,
+            3 | 5 => -1,
+            _ => 0,
+        };
+    }
+    let p = p as u64;
+    match pow_mod_u64(d.rem_euclid(p as i64) as u64, (p - 1) / 2, p) {
+        0 => 0,
+        1 => 1,
+        _ => -1,
+    }
+}
+
+/// The trace of `x^2`, which is the sum of the squares of the real embeddings of
+/// `x` and is minimized over the associates of `x` to choose a canonical one.
+fn trace_of_square(x: &QuadraticInteger<NormEuclidean>) -> i128 {
+    let (t, n) = x.parent.min_poly();
+    let (a, b) = (x.a as i128, x.b as i128);
+    // x^2 = a^2 + nb^2 + (2ab + tb^2)ω and Tr(ω) = t.
+    2 * (a * a + n * b * b) + t * (2 * a * b + t * b * b)
+}
+
+impl EuclideanQuadraticRing {
+    /// Return the fundamental unit of a real field, or `None` for an imaginary
+    /// field.
+    pub fn fundamental_unit(&self) -> Option<QuadraticInteger<NormEuclidean>> {
+        if self.is_imaginary() {
+            None
+        } else {
+            Some(self.elem(self.unit.0, self.unit.1))
+        }
+    }
+
+    /// Return the associates of `x` which are smallest with respect to
+    /// `trace_of_square`, together with the units giving them.
+    #[allow(clippy::type_complexity)]
+    fn small_associates(
+        &self,
+        x: &QuadraticInteger<NormEuclidean>,
+    ) -> Vec<(QuadraticInteger<NormEuclidean>, QuadraticInteger<NormEuclidean>)> {
+        if self.is_imaginary() {
+            return self.torsion_units().into_iter().map(|u| (&u * x, u)).collect();
+        }
+        let eps = self.elem(self.unit.0, self.unit.1);
+        let mut eps_inv = eps.conjugate();
+        if eps.norm() < 0 {
+            eps_inv.neg_assign();
+        }
+
+        let (mut y, mut u) = (x.clone(), self.one());
+        for step in [&eps, &eps_inv] {
+            loop {
+                let z = &y * step;
+                if trace_of_square(&z) >= trace_of_square(&y) {
+                    break;
+                }
+                y = z;
+                u *= step;
+            }
+        }
+        let mut res = vec![(y.clone(), u.clone())];
+        for step in [&eps, &eps_inv] {
+            let z = &y * step;
+            if trace_of_square(&z) == trace_of_square(&y) {
+                res.push((z, &u * step));
+            }
+        }
+        let negated: Vec<_> = res.iter().map(|(y, u)| (-y, -u)).collect();
+        res.extend(negated);
+        res
+    }
+}
+
+/// Division rounds the exact quotient in `Q(√d)` to a nearby element of the ring
+/// whose remainder has smaller absolute norm, which exists precisely because the
+/// ring is norm-Euclidean. Canonical associates and canonical representatives
+/// modulo `m` are chosen so that results do not depend on the choice of
+/// generator.
+impl EuclideanDomain for EuclideanQuadraticRing {
+    fn div_rem(
+        &self,
+        x: &QuadraticInteger<NormEuclidean>,
+        y: &QuadraticInteger<NormEuclidean>,
+    ) -> (QuadraticInteger<NormEuclidean>, QuadraticInteger<NormEuclidean>) {
+        assert!(!y.is_zero(), "division by zero");
+        let ny = y.norm_i128();
+        let num = x * &y.conjugate();
+        let (c0, c1) = if ny > 0 {
+            ((num.a as i128).div_euclid(ny), (num.b as i128).div_euclid(ny))
+        } else {
+            ((-(num.a as i128)).div_euclid(-ny), (-(num.b as i128)).div_euclid(-ny))
+        };
+
+        // Search boxes of increasing size around the rounded quotient, only
+        // visiting the cells added at each step. A suitable quotient exists since
+        // the ring is norm-Euclidean, so this terminates; the box needed is small
+        // for imaginary fields but grows to a radius of about 25 for `d = 73`.
+        let mut r = 1i128;
+        loop {
+            let mut best: Option<(i128, QuadraticInteger<NormEuclidean>, QuadraticInteger<NormEuclidean>)> = None;
+            for i in 1 - r..=r {
+                for j in 1 - r..=r {
+                    if r > 1 && i != 1 - r && i != r && j != 1 - r && j != r {
+                        continue;
+                    }
+                    let q = self.elem_i128(c0 + i, c1 + j);
+                    let rem = x - &(y * &q);
+                    let nr = rem.norm_i128().abs();
+                    if nr < ny.abs() && best.as_ref().is_none_or(|(n, _, _)| nr < *n) {
+                        best = Some((nr, q, rem));
+                    }
+                }
+            }
+            if let Some((_, q, rem)) = best {
+                return (q, rem);
+            }
+            r += 1;
+        }
+    }
+
+    /// Among the associates of smallest `Tr(a^2)`, the canonical one has the
+    /// largest coordinates in lexicographic order.
+    fn canonical_unit(&self, a: &QuadraticInteger<NormEuclidean>) -> QuadraticInteger<NormEuclidean> {
+        if a.is_zero() {
+            return self.one();
+        }
+        self.small_associates(a)
+            .into_iter()
+            .max_by_key(|(y, _)| (y.a, y.b))
+            .map(|(_, u)| u)
+            .unwrap()
+    }
+
+    /// An element is irreducible if its norm is prime, or if it is an associate
+    /// of a rational prime which is inert.
+    fn is_irreducible(&self, a: &QuadraticInteger<NormEuclidean>) -> Option<bool> {
+        let zz = IntegerRing::init();
+        let n = a.norm().abs();
+        if zz.is_prime(&n) {
+            return Some(true);
+        }
+        let p = isqrt(n as i128).unwrap() as i64;
+        Some(
+            p * p == n
+                && zz.is_prime(&p)
+                && a.a % p == 0
+                && a.b % p == 0
+                && kronecker(self.disc, p) == -1,
+        )
+    }
+
+    #[inline]
+    fn is_unit(&self, a: &QuadraticInteger<NormEuclidean>) -> bool {
+        a.norm_i128().abs() == 1
+    }
+
+    /// The multiples of `m` form a lattice with a basis `(g, h), (0, k)` in
+    /// Hermite normal form, so `a + bω` is reduced to `0 <= a < g`, `0 <= b < |k|`.
+    fn reduce(
+        &self,
+        x: &QuadraticInteger<NormEuclidean>,
+        m: &QuadraticInteger<NormEuclidean>,
+    ) -> QuadraticInteger<NormEuclidean> {
+        assert!(!m.is_zero(), "division by zero");
+        let zz = IntegerRing::init();
+        let mw = m * &self.gen();
+        let (m0, m1, w0, w1) = (m.a as i128, m.b as i128, mw.a as i128, mw.b as i128);
+        let (g, s, t) = zz.xgcd(&m.a, &mw.a);
+        let (g, s, t) = (g as i128, s as i128, t as i128);
+        let h = s * m1 + t * w1;
+        let k = ((w0 / g) * m1 - (m0 / g) * w1).abs();
+
+        let (mut a, mut b) = (x.a as i128, x.b as i128);
+        let q = a.div_euclid(g);
+        a -= q * g;
+        b -= q * h;
+        self.elem_i128(a, b.rem_euclid(k))
+    }
+}
@@ -0,0 +1,411 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Whether an algebra is known at the type level to be a division algebra, which
+/// determines whether it is `Divisible` under multiplication.
+pub trait AlgebraKind: Clone + Copy + fmt::Debug + Default + PartialEq + Eq {}
+
+/// An algebra which may have zero divisors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AnyAlgebra;
+
+/// A division algebra, in which every nonzero element is invertible.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DivisionAlgebra;
+
+impl AlgebraKind for AnyAlgebra {}
+
+impl AlgebraKind for DivisionAlgebra {}
+
+/// The quaternion algebra `(a, b)_F` over a field `F`, with basis `1, i, j, k`
+/// and multiplication determined by
+///
+/// ```notrust
+/// i² = a,  j² = b,  ij = -ji = k
+/// ```
+///
+/// For `F = Q` and `a = b = -1` these are Hamilton's quaternions. The algebra is
+/// an `NCRing`; it is a division algebra exactly when the norm form has no
+/// nontrivial zero, and otherwise it is isomorphic to the 2 × 2 matrices over
+/// `F`. Only algebras of kind `DivisionAlgebra`, obtained from `init` using
+/// `as_division_algebra`, are `Divisible` under multiplication. Inverting an
+/// element of norm zero panics.
+#[derive(Clone, PartialEq)]
+pub struct QuaternionAlgebra<F: Field, K: AlgebraKind = AnyAlgebra> {
+    field: F,
+    a: Elem<F>,
+    b: Elem<F>,
+    kind: PhantomData<K>,
+}
+
+/// An element `x_0 + x_1 i + x_2 j + x_3 k` of a `QuaternionAlgebra`.
+#[derive(Clone)]
+pub struct Quaternion<F: Field, K: AlgebraKind = AnyAlgebra> {
+    parent: QuaternionAlgebra<F, K>,
+    coeffs: [Elem<F>; 4],
+}
+
+/// Return `x * y` for field elements.
+#[inline]
+fn mul<F: Field>(x: &Elem<F>, y: &Elem<F>) -> Elem<F> {
+    let mut res = x.clone();
+    res *= y;
+    res
+}
+
+/// Return the Hilbert symbol `(a, b)_p` of nonzero integers at a prime `p`,
+/// which is `1` if `a x² + b y² = z²` has a nontrivial solution in `Q_p` and
+/// `-1` otherwise.
+fn hilbert_symbol(a: i64, b: i64, p: u64) -> i32 {
+    let split = |mut x: i64| {
+        let mut v = 0;
+        while x % p as i64 == 0 {
+            x /= p as i64;
+            v += 1;
+        }
+        (v, x)
+    };
+    let ((alpha, u), (beta, v)) = (split(a), split(b));
+    let sign = |e: bool| if e { -1 } else { 1 };
+    if p == 2 {
+        let eps = |x: i64| (x.rem_euclid(4) == 3) as u32;
+        let omega = |x: i64| matches!(x.rem_euclid(8), 3 | 5) as u32;
+        return sign((eps(u) * eps(v) + alpha * omega(v) + beta * omega(u)) % 2 == 1);
+    }
+    let legendre = |x: i64| sign(pow_mod_u64(x.rem_euclid(p as i64) as u64, (p - 1) / 2, p) != 1);
+    let mut res = sign(alpha * beta % 2 == 1 && p % 4 == 3);
+    if beta % 2 == 1 {
+        res *= legendre(u);
+    }
+    if alpha % 2 == 1 {
+        res *= legendre(v);
+    }
+    res
+}
+
+impl<F: Field> QuaternionAlgebra<F> {
+    /// Initialize the quaternion algebra `(a, b)` over `field`. Panics if `a` or
+    /// `b` is zero.
+    pub fn init(field: &F, a: &Elem<F>, b: &Elem<F>) -> Self {
+        assert!(!a.is_zero() && !b.is_zero(), "quaternion algebra parameters must be nonzero");
+        QuaternionAlgebra { field: field.clone(), a: a.clone(), b: b.clone(), kind: PhantomData }
+    }
+
+    /// Return the algebra as a division algebra without checking that it is one.
+    /// If it is not, inverting a zero divisor panics.
+    pub fn as_division_algebra_unchecked(&self) -> QuaternionAlgebra<F, DivisionAlgebra> {
+        QuaternionAlgebra { field: self.field.clone(), a: self.a.clone(), b: self.b.clone(), kind: PhantomData }
+    }
+}
+
+impl QuaternionAlgebra<RationalField> {
+    /// Return `true` if the algebra is a division algebra, that is if it is
+    /// ramified at some place of `Q`. The Hilbert symbol `(a, b)` is computed
+    /// at infinity and at the primes dividing `2ab`.
+    pub fn is_division_algebra(&self) -> bool {
+        if self.a < Rational::from(0) && self.b < Rational::from(0) {
+            return true;
+        }
+        // The symbol is bimultiplicative, so numerators and denominators can be
+        // treated separately.
+        let xs = [self.a.numerator(), self.a.denominator()];
+        let ys = [self.b.numerator(), self.b.denominator()];
+        let mut primes = vec![2];
+        for x in xs.iter().chain(ys.iter()) {
+            primes.extend(factor_u64(x.unsigned_abs()).into_iter().map(|(p, _)| p));
+        }
+        primes.sort_unstable();
+        primes.dedup();
+        primes.into_iter().any(|p| {
+            xs.iter().flat_map(|&x| ys.iter().map(move |&y| hilbert_symbol(x, y, p))).product::<i32>() == -1
+        })
+    }
+
+    /// Return the algebra as a division algebra, or `None` if it is split.
+    pub fn as_division_algebra(&self) -> Option<QuaternionAlgebra<RationalField, DivisionAlgebra>> {
+        self.is_division_algebra().then(|| self.as_division_algebra_unchecked())
+    }
+}
+
+impl<F: Field, K: AlgebraKind> QuaternionAlgebra<F, K> {
+
+    /// Return the parameters `(a, b)`, the squares of `i` and `j`.
+    #[inline]
+    pub fn parameters(&self) -> (&Elem<F>, &Elem<F>) {
+        (&self.a, &self.b)
+    }
+
+    /// Return the basis elements `[1, i, j, k]`.
+    pub fn basis(&self) -> [Quaternion<F, K>; 4] {
+        let (zero, one) = (self.field.zero(), self.field.one());
+        std::array::from_fn(|n| {
+            let mut coeffs = std::array::from_fn(|_| zero.clone());
+            coeffs[n] = one.clone();
+            self.elem(coeffs)
+        })
+    }
+
+    /// Return the quaternion with the given coordinates in the basis `1, i, j, k`.
+    #[inline]
+    fn elem(&self, coeffs: [Elem<F>; 4]) -> Quaternion<F, K> {
+        Quaternion { parent: self.clone(), coeffs }
+    }
+}
+
+impl<F: Field, K: AlgebraKind> Quaternion<F, K> {
+    /// Return the coordinates `[x_0, x_1, x_2, x_3]` in the basis `1, i, j, k`.
+    #[inline]
+    pub fn coefficients(&self) -> &[Elem<F>; 4] {
+        &self.coeffs
+    }
+
+    /// Return the conjugate `x_0 - x_1 i - x_2 j - x_3 k`.
+    pub fn conjugate(&self) -> Self {
+        let mut res = self.clone();
+        for c in res.coeffs.iter_mut().skip(1) {
+            c.neg_assign();
+        }
+        res
+    }
+
+    /// Return the reduced norm `x x̄ = x_0² - a x_1² - b x_2² + ab x_3²`, which
+    /// is multiplicative.
+    pub fn norm(&self) -> Elem<F> {
+        let (a, b) = (&self.parent.a, &self.parent.b);
+        let [x0, x1, x2, x3] = &self.coeffs;
+        let m = |x: &Elem<F>, y: &Elem<F>| mul::<F>(x, y);
+        let mut res = m(x0, x0);
+        res -= &m(a, &m(x1, x1));
+        res -= &m(b, &m(x2, x2));
+        res += &m(&m(a, b), &m(x3, x3));
+        res
+    }
+
+    /// Return the reduced trace `x + x̄ = 2 x_0`.
+    pub fn trace(&self) -> Elem<F> {
+        let mut res = self.coeffs[0].clone();
+        res += &self.coeffs[0];
+        res
+    }
+
+    /// Return the inverse `x̄ / N(x)`, or `None` if the norm of `self` is zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let n = self.norm();
+        if n.is_zero() {
+            return None;
+        }
+        let mut n = n;
+        n.inv_assign();
+        let mut res = self.conjugate();
+        for c in res.coeffs.iter_mut() {
+            *c *= &n;
+        }
+        Some(res)
+    }
+}
+
+impl<F: Field, K: AlgebraKind> PartialEq for Quaternion<F, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+impl<F: Field, K: AlgebraKind> fmt::Debug for QuaternionAlgebra<F, K>
+where
+    F: fmt::Debug,
+    <F as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QuaternionAlgebra")
+            .field("field", &self.field)
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<F: Field, K: AlgebraKind> fmt::Debug for Quaternion<F, K>
+where
+    <F as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Quaternion")
+            .field("coeffs", &self.coeffs)
+            .finish()
+    }
+}
+
+impl<F: Field, K: AlgebraKind> fmt::Display for Quaternion<F, K>
+where
+    <F as Ring>::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms: Vec<String> = self.coeffs.iter()
+            .zip(["", "*i", "*j", "*k"])
+            .filter(|(c, _)| !c.is_zero())
+            .map(|(c, v)| format!("{}{}", c, v))
+            .collect();
+        if terms.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", terms.join(" + "))
+        }
+    }
+}
+
+impl<F: Field, K: AlgebraKind> Parent for QuaternionAlgebra<F, K> {
+    type Element = Quaternion<F, K>;
+}
+
+impl<F: Field, K: AlgebraKind> Element for Quaternion<F, K> {
+    type Parent = QuaternionAlgebra<F, K>;
+
+    #[inline]
+    fn parent(&self) -> QuaternionAlgebra<F, K> {
+        self.parent.clone()
+    }
+}
+
+/// Construct the quaternion with the given coordinates in the basis `1, i, j, k`.
+impl<F: Field, K: AlgebraKind> NewElement<[Elem<F>; 4]> for QuaternionAlgebra<F, K> {
+    #[inline]
+    fn new(&self, src: [Elem<F>; 4]) -> Quaternion<F, K> {
+        self.elem(src)
+    }
+}
+
+// Additive properties
+
+impl<F: Field, K: AlgebraKind> Operation<Additive> for Quaternion<F, K> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let mut res = self.clone();
+        for (c, d) in res.coeffs.iter_mut().zip(rhs.coeffs.iter()) {
+            *c += d;
+        }
+        res
+    }
+}
+
+impl<F: Field, K: AlgebraKind> Identity<Additive> for QuaternionAlgebra<F, K> {
+    #[inline]
+    fn identity(&self) -> Quaternion<F, K> {
+        self.elem(std::array::from_fn(|_| self.field.zero()))
+    }
+}
+
+impl<F: Field, K: AlgebraKind> IsIdentity<Additive> for Quaternion<F, K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs.iter().all(|c| c.is_zero())
+    }
+}
+
+impl<F: Field, K: AlgebraKind> TwoSidedInverse<Additive> for Quaternion<F, K> {
+    fn two_sided_inverse(&self) -> Self {
+        let mut res = self.clone();
+        for c in res.coeffs.iter_mut() {
+            c.neg_assign();
+        }
+        res
+    }
+}
+
+impl<F: Field, K: AlgebraKind> Divisible<Additive> for QuaternionAlgebra<F, K> {}
+
+impl<F: Field, K: AlgebraKind> Associative<Additive> for QuaternionAlgebra<F, K> {}
+
+impl<F: Field, K: AlgebraKind> Commutative<Additive> for QuaternionAlgebra<F, K> {}
+
+// Multiplicative properties
+
+impl<F: Field, K: AlgebraKind> Operation<Multiplicative> for Quaternion<F, K> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let (a, b) = (&self.parent.a, &self.parent.b);
+        let ab = mul::<F>(a, b);
+        let [x0, x1, x2, x3] = &self.coeffs;
+        let [y0, y1, y2, y3] = &rhs.coeffs;
+        let m = |x: &Elem<F>, y: &Elem<F>| mul::<F>(x, y);
+
+        let mut z0 = m(x0, y0);
+        z0 += &m(a, &m(x1, y1));
+        z0 += &m(b, &m(x2, y2));
+        z0 -= &m(&ab, &m(x3, y3));
+
+        let mut z1 = m(x0, y1);
+        z1 += &m(x1, y0);
+        z1 -= &m(b, &m(x2, y3));
+        z1 += &m(b, &m(x3, y2));
+
+        let mut z2 = m(x0, y2);
+        z2 += &m(x2, y0);
+        z2 += &m(a, &m(x1, y3));
+        z2 -= &m(a, &m(x3, y1));
+
+        let mut z3 = m(x0, y3);
+        z3 += &m(x3, y0);
+        z3 += &m(x1, y2);
+        z3 -= &m(x2, y1);
+
+        self.parent.elem([z0, z1, z2, z3])
+    }
+}
+
+impl<F: Field, K: AlgebraKind> Identity<Multiplicative> for QuaternionAlgebra<F, K> {
+    #[inline]
+    fn identity(&self) -> Quaternion<F, K> {
+        self.embed_base(&self.field.one())
+    }
+}
+
+impl<F: Field, K: AlgebraKind> IsIdentity<Multiplicative> for Quaternion<F, K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.coeffs[0].is_one() && self.coeffs[1..].iter().all(|c| c.is_zero())
+    }
+}
+
+impl<F: Field, K: AlgebraKind> TwoSidedInverse<Multiplicative> for Quaternion<F, K> {
+    /// Panics if the norm of `self` is zero.
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.inverse().expect("division by an element of norm zero")
+    }
+}
+
+impl<F: Field> Divisible<Multiplicative> for QuaternionAlgebra<F, DivisionAlgebra> {}
+
+impl<F: Field, K: AlgebraKind> Associative<Multiplicative> for QuaternionAlgebra<F, K> {}
+
+// Ring-like properties
+
+impl<F: Field, K: AlgebraKind> Distributive for QuaternionAlgebra<F, K> {}
+
+impl_ring_ops!([F: Field, K: AlgebraKind] Quaternion<F, K>);
+impl_field_ops!([F: Field, K: AlgebraKind] Quaternion<F, K>);
+
+impl<F: Field, K: AlgebraKind> Algebra<F> for QuaternionAlgebra<F, K> {
+    fn embed_base(&self, x: &Elem<F>) -> Quaternion<F, K> {
+        let zero = self.field.zero();
+        self.elem([x.clone(), zero.clone(), zero.clone(), zero])
+    }
+}
+
+impl<F: Field, K: AlgebraKind> StarAlgebra<F> for QuaternionAlgebra<F, K> {
+    #[inline]
+    fn base_field(&self) -> &F {
+        &self.field
+    }
+
+    #[inline]
+    fn conjugate(&self, x: &Quaternion<F, K>) -> Quaternion<F, K> {
+        x.conjugate()
+    }
+
+    #[inline]
+    fn norm(&self, x: &Quaternion<F, K>) -> Elem<F> {
+        x.norm()
+    }
+}
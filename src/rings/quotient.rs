@@ -0,0 +1,318 @@
+use crate::*;
+use crate::ops::*;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// The kind of ideal a `QuotientRing` is taken by, which determines at the type
+/// level whether the quotient is a `Field`.
+pub trait IdealKind: Clone + Copy + fmt::Debug + Default + PartialEq + Eq {}
+
+/// An ideal which is not known to be maximal. The quotient is a `Ring`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AnyIdeal;
+
+/// A maximal ideal. The quotient is a `Field`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MaximalIdeal;
+
+impl IdealKind for AnyIdeal {}
+
+impl IdealKind for MaximalIdeal {}
+
+/// The quotient `R/(m)` of a Euclidean domain by the principal ideal generated
+/// by a nonzero element `m`, such as `Z/nZ` or `F[x]/(f)`. The parent holds the
+/// modulus, and elements are reduced to canonical representatives using
+/// `EuclideanDomain::reduce`.
+#[derive(Clone, PartialEq)]
+pub struct QuotientRing<R: EuclideanDomain, K: IdealKind = AnyIdeal> {
+    ring: R,
+    modulus: Elem<R>,
+    kind: PhantomData<K>,
+}
+
+/// The quotient of a Euclidean domain by a maximal ideal, for example a prime
+/// field `Z/pZ`. Obtained from a `QuotientRing` using `as_field`.
+pub type QuotientField<R> = QuotientRing<R, MaximalIdeal>;
+
+/// An element of a `QuotientRing`, stored as the canonical representative of
+/// its class.
+#[derive(Clone)]
+pub struct Residue<R: EuclideanDomain, K: IdealKind = AnyIdeal> {
+    parent: QuotientRing<R, K>,
+    value: Elem<R>,
+}
+
+impl<R: EuclideanDomain> QuotientRing<R> {
+    /// Initialize the quotient of `ring` by the ideal generated by `modulus`.
+    /// Panics if `modulus` is zero.
+    pub fn init(ring: &R, modulus: &Elem<R>) -> Self {
+        assert!(!modulus.is_zero(), "quotient by the zero ideal");
+        QuotientRing { ring: ring.clone(), modulus: ring.normalize(modulus), kind: PhantomData }
+    }
+
+    /// Return the quotient as a field, or `None` if the ideal is not maximal or
+    /// maximality cannot be decided.
+    pub fn as_field(&self) -> Option<QuotientField<R>> {
+        match self.is_maximal() {
+            Some(true) => Some(self.as_field_unchecked()),
+            _ => None,
+        }
+    }
+
+    /// Return the quotient as a field without checking that the ideal is maximal.
+    /// If it is not, inverting a zero divisor panics.
+    pub fn as_field_unchecked(&self) -> QuotientField<R> {
+        QuotientRing { ring: self.ring.clone(), modulus: self.modulus.clone(), kind: PhantomData }
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> QuotientRing<R, K> {
+    /// Return a reference to the ring being divided by the ideal.
+    #[inline]
+    pub fn base_ring(&self) -> &R {
+        &self.ring
+    }
+
+    /// Return the canonical generator of the ideal.
+    #[inline]
+    pub fn modulus(&self) -> &Elem<R> {
+        &self.modulus
+    }
+
+    /// Return `Some(true)` if the ideal is maximal, that is, the generator is
+    /// irreducible, and `None` if this cannot be decided.
+    #[inline]
+    pub fn is_maximal(&self) -> Option<bool> {
+        self.ring.is_irreducible(&self.modulus)
+    }
+
+    #[inline]
+    fn residue(&self, value: Elem<R>) -> Residue<R, K> {
+        Residue { parent: self.clone(), value }
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> Residue<R, K> {
+    /// Return a reference to the canonical representative.
+    #[inline]
+    pub fn lift(&self) -> &Elem<R> {
+        &self.value
+    }
+
+    /// Return `true` if the element is invertible.
+    pub fn is_unit(&self) -> bool {
+        self.inverse().is_some()
+    }
+
+    /// Return the multiplicative inverse, or `None` if the element is not a unit.
+    pub fn inverse(&self) -> Option<Self> {
+        let p = &self.parent;
+        p.ring.inverse_mod(&self.value, &p.modulus).map(|v| p.residue(v))
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> PartialEq for Residue<R, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> Eq for Residue<R, K>
+where
+    <R as Ring>::Element: Eq,
+{}
+
+impl<R: EuclideanDomain, K: IdealKind> Hash for Residue<R, K>
+where
+    <R as Ring>::Element: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> fmt::Debug for QuotientRing<R, K>
+where
+    R: fmt::Debug,
+    <R as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QuotientRing")
+            .field("ring", &self.ring)
+            .field("modulus", &self.modulus)
+            .field("kind", &K::default())
+            .finish()
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> fmt::Debug for Residue<R, K>
+where
+    <R as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Residue")
+            .field("value", &self.value)
+            .field("modulus", &self.parent.modulus)
+            .finish()
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> fmt::Display for Residue<R, K>
+where
+    <R as Ring>::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> Parent for QuotientRing<R, K> {
+    type Element = Residue<R, K>;
+}
+
+impl<R: EuclideanDomain, K: IdealKind> Element for Residue<R, K> {
+    type Parent = QuotientRing<R, K>;
+
+    #[inline]
+    fn parent(&self) -> QuotientRing<R, K> {
+        self.parent.clone()
+    }
+}
+
+/// Construct the class of an element of the base ring.
+impl<R: EuclideanDomain, K: IdealKind> NewElement<Elem<R>> for QuotientRing<R, K> {
+    #[inline]
+    fn new(&self, src: Elem<R>) -> Residue<R, K> {
+        self.residue(self.ring.reduce(&src, &self.modulus))
+    }
+}
+
+/// The residues `0, ..., |m| - 1` of `Z/mZ`.
+impl<K: IdealKind> Finite for QuotientRing<IntegerRing, K> {
+    fn elements(&self) -> Vec<Residue<IntegerRing, K>> {
+        (0..self.modulus.abs()).map(|v| self.residue(v)).collect()
+    }
+
+    #[inline]
+    fn cardinality(&self) -> usize {
+        self.modulus.unsigned_abs() as usize
+    }
+}
+
+/// The residues of `F[x]/(f)` over a finite field, the polynomials of degree
+/// less than that of `f`.
+impl<F: Field + Finite, K: IdealKind> Finite for QuotientRing<GenericPolyRing<F>, K> {
+    fn elements(&self) -> Vec<Residue<GenericPolyRing<F>, K>> {
+        let coeffs = self.ring.base_ring().elements();
+        let d = self.modulus.degree() as usize;
+        let q = coeffs.len();
+        let mut digits = vec![0; d];
+        let mut res = vec![];
+        loop {
+            let c: Vec<_> = digits.iter().map(|&i| coeffs[i].clone()).collect();
+            res.push(self.residue(self.ring.new(c)));
+            match digits.iter().position(|&i| i + 1 < q) {
+                Some(k) => {
+                    digits[k] += 1;
+                    digits[..k].iter_mut().for_each(|i| *i = 0);
+                }
+                None => return res,
+            }
+        }
+    }
+
+    #[inline]
+    fn cardinality(&self) -> usize {
+        self.ring.base_ring().cardinality().pow(self.modulus.degree() as u32)
+    }
+}
+
+// Additive properties
+
+impl<R: EuclideanDomain, K: IdealKind> Operation<Additive> for Residue<R, K> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let p = &self.parent;
+        p.residue(p.ring.add_mod(&self.value, &rhs.value, &p.modulus))
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> Identity<Additive> for QuotientRing<R, K> {
+    #[inline]
+    fn identity(&self) -> Residue<R, K> {
+        self.residue(self.ring.zero())
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> IsIdentity<Additive> for Residue<R, K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.value.is_zero()
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> TwoSidedInverse<Additive> for Residue<R, K> {
+    fn two_sided_inverse(&self) -> Self {
+        let mut v = self.value.clone();
+        v.neg_assign();
+        self.parent.new(v)
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> Divisible<Additive> for QuotientRing<R, K> {}
+
+impl<R: EuclideanDomain, K: IdealKind> Associative<Additive> for QuotientRing<R, K> {}
+
+impl<R: EuclideanDomain, K: IdealKind> Commutative<Additive> for QuotientRing<R, K> {}
+
+// Multiplicative properties
+
+impl<R: EuclideanDomain, K: IdealKind> Operation<Multiplicative> for Residue<R, K> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let p = &self.parent;
+        p.residue(p.ring.mul_mod(&self.value, &rhs.value, &p.modulus))
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> Identity<Multiplicative> for QuotientRing<R, K> {
+    #[inline]
+    fn identity(&self) -> Residue<R, K> {
+        self.new(self.ring.one())
+    }
+}
+
+impl<R: EuclideanDomain, K: IdealKind> IsIdentity<Multiplicative> for Residue<R, K> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        *self == self.parent.one()
+    }
+}
+
+impl<R: EuclideanDomain> TwoSidedInverse<Multiplicative> for Residue<R, MaximalIdeal> {
+    /// Panics if `self` is zero.
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.inverse().expect("division by zero")
+    }
+}
+
+impl<R: EuclideanDomain> Divisible<Multiplicative> for QuotientField<R> {}
+
+impl<R: EuclideanDomain, K: IdealKind> Associative<Multiplicative> for QuotientRing<R, K> {}
+
+impl<R: EuclideanDomain, K: IdealKind> Commutative<Multiplicative> for QuotientRing<R, K> {}
+
+// Ring-like properties
+
+impl<R: EuclideanDomain, K: IdealKind> Distributive for QuotientRing<R, K> {}
+
+impl_ring_ops!([R: EuclideanDomain, K: IdealKind] Residue<R, K>);
+impl_field_ops!([R: EuclideanDomain] Residue<R, MaximalIdeal>);
+
+impl<R: EuclideanDomain, K: IdealKind> Algebra<R> for QuotientRing<R, K> {
+    #[inline]
+    fn embed_base(&self, x: &Elem<R>) -> Residue<R, K> {
+        self.new(x.clone())
+    }
+}
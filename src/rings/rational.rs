@@ -0,0 +1,230 @@
+use crate::*;
+use crate::ops::*;
+
+use std::cmp::Ordering;
+use std::fmt;
+
+/// The field of rational numbers, with elements represented by a pair of `i64`s
+/// in lowest terms.
+///
+/// Intermediate results are computed with 128-bit integers and reduced before
+/// being stored; arithmetic panics if a reduced numerator or denominator does
+/// not fit in 64 bits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RationalField;
+
+/// An element of the `RationalField`. The denominator is always positive and
+/// coprime to the numerator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i64,
+    den: i64,
+}
+
+#[inline]
+pub(crate) fn gcd_i128(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+impl RationalField {
+    pub fn init() -> Self {
+        RationalField
+    }
+}
+
+impl Rational {
+    /// Return `num/den` in lowest terms. Panics if `den` is zero.
+    pub fn new(num: i64, den: i64) -> Self {
+        Rational::from_i128(num as i128, den as i128)
+    }
+
+    fn from_i128(num: i128, den: i128) -> Self {
+        assert!(den != 0, "division by zero");
+        let g = gcd_i128(num, den);
+        let (mut num, mut den) = (num / g, den / g);
+        if den < 0 {
+            num = -num;
+            den = -den;
+        }
+        Rational {
+            num: num.try_into().expect("rational overflow"),
+            den: den.try_into().expect("rational overflow"),
+        }
+    }
+
+    #[inline]
+    pub fn numerator(&self) -> i64 {
+        self.num
+    }
+
+    #[inline]
+    pub fn denominator(&self) -> i64 {
+        self.den
+    }
+
+    /// Return `true` if the denominator is one.
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+}
+
+impl From<i64> for Rational {
+    #[inline]
+    fn from(src: i64) -> Self {
+        Rational { num: src, den: 1 }
+    }
+}
+
+impl PartialOrd for Rational {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num as i128 * other.den as i128).cmp(&(other.num as i128 * self.den as i128))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Parent for RationalField {
+    type Element = Rational;
+}
+
+impl Element for Rational {
+    type Parent = RationalField;
+
+    #[inline]
+    fn parent(&self) -> RationalField {
+        RationalField
+    }
+}
+
+impl NewElement<i64> for RationalField {
+    #[inline]
+    fn new(&self, src: i64) -> Rational {
+        Rational::from(src)
+    }
+}
+
+/// Construct the rational number `num/den` from `(num, den)`.
+impl NewElement<(i64, i64)> for RationalField {
+    #[inline]
+    fn new(&self, src: (i64, i64)) -> Rational {
+        Rational::new(src.0, src.1)
+    }
+}
+
+// Additive properties
+
+impl Operation<Additive> for Rational {
+    fn operate(&self, rhs: &Self) -> Self {
+        if self.den == rhs.den {
+            return Rational::from_i128(self.num as i128 + rhs.num as i128, self.den as i128);
+        }
+        Rational::from_i128(
+            self.num as i128 * rhs.den as i128 + rhs.num as i128 * self.den as i128,
+            self.den as i128 * rhs.den as i128,
+        )
+    }
+}
+
+impl Identity<Additive> for RationalField {
+    #[inline]
+    fn identity(&self) -> Rational {
+        Rational { num: 0, den: 1 }
+    }
+}
+
+impl IsIdentity<Additive> for Rational {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.num == 0
+    }
+}
+
+impl TwoSidedInverse<Additive> for Rational {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        Rational { num: -self.num, den: self.den }
+    }
+}
+
+impl Divisible<Additive> for RationalField {}
+
+impl Associative<Additive> for RationalField {}
+
+impl Commutative<Additive> for RationalField {}
+
+// Multiplicative properties
+
+impl Operation<Multiplicative> for Rational {
+    fn operate(&self, rhs: &Self) -> Self {
+        Rational::from_i128(
+            self.num as i128 * rhs.num as i128,
+            self.den as i128 * rhs.den as i128,
+        )
+    }
+}
+
+impl Identity<Multiplicative> for RationalField {
+    #[inline]
+    fn identity(&self) -> Rational {
+        Rational { num: 1, den: 1 }
+    }
+}
+
+impl IsIdentity<Multiplicative> for Rational {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.num == 1 && self.den == 1
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for Rational {
+    /// Panics if `self` is zero.
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        Rational::new(self.den, self.num)
+    }
+}
+
+impl Divisible<Multiplicative> for RationalField {}
+
+impl Associative<Multiplicative> for RationalField {}
+
+impl Commutative<Multiplicative> for RationalField {}
+
+// Ring-like properties
+
+impl Distributive for RationalField {}
+
+impl_ring_ops!([] Rational);
+impl_field_ops!([] Rational);
+
+impl Algebra<IntegerRing> for RationalField {
+    #[inline]
+    fn embed_base(&self, x: &i64) -> Rational {
+        Rational::from(*x)
+    }
+}
@@ -0,0 +1,500 @@
+use crate::*;
+use crate::ops::*;
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Sparse univariate polynomials over an arbitrary ring, suitable for polynomials
+/// of very high degree with few nonzero terms such as `x^(2^61) + x + 1`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparsePolyRing<T: Ring> {
+    ring: T,
+    var: Arc<str>,
+}
+
+/// An element of a `SparsePolyRing`, stored as `(exponent, coefficient)` pairs
+/// sorted by increasing exponent. Zero coefficients are never stored.
+#[derive(Clone)]
+pub struct SparsePoly<T: Ring> {
+    parent: SparsePolyRing<T>,
+    terms: Vec<(u64, Elem<T>)>,
+}
+
+impl<T: Ring> SparsePolyRing<T> {
+    /// Return the generator of the polynomial ring.
+    pub fn gen(&self) -> SparsePoly<T> {
+        SparsePoly { parent: self.clone(), terms: vec![(1, self.ring.one())] }
+    }
+
+    /// Return the monomial `c*x^e`.
+    pub fn monomial(&self, c: Elem<T>, e: u64) -> SparsePoly<T> {
+        SparsePoly::from_terms(self, vec![(e, c)])
+    }
+}
+
+impl<T: Ring> SparsePoly<T> {
+    /// Construct a polynomial from terms in any order, combining repeated exponents
+    /// and dropping zero coefficients.
+    fn from_terms(parent: &SparsePolyRing<T>, mut terms: Vec<(u64, Elem<T>)>) -> Self {
+        terms.sort_by_key(|t| t.0);
+        let mut res: Vec<(u64, Elem<T>)> = Vec::with_capacity(terms.len());
+        for (e, c) in terms {
+            match res.last_mut() {
+                Some(last) if last.0 == e => last.1 += &c,
+                _ => res.push((e, c)),
+            }
+        }
+        res.retain(|t| !t.1.is_zero());
+        SparsePoly { parent: parent.clone(), terms: res }
+    }
+
+    /// Return a reference to the nonzero terms, sorted by increasing exponent.
+    #[inline]
+    pub fn terms(&self) -> &[(u64, Elem<T>)] {
+        &self.terms
+    }
+
+    /// Return the number of nonzero terms.
+    #[inline]
+    pub fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Return the degree as an unsigned integer, or `None` for the zero polynomial.
+    /// Unlike `degree` this cannot overflow.
+    #[inline]
+    pub fn udegree(&self) -> Option<u64> {
+        self.terms.last().map(|t| t.0)
+    }
+
+    /// Return the polynomial in the dense representation. This allocates
+    /// `degree + 1` coefficients, so it is only suitable for polynomials of
+    /// moderate degree.
+    pub fn to_dense(&self, ring: &GenericPolyRing<T>) -> GenericPoly<T> {
+        let len = self.udegree().map_or(0, |d| d as usize + 1);
+        let mut coeffs = vec![ring.base_ring().zero(); len];
+        for (e, c) in self.terms.iter() {
+            coeffs[*e as usize] = c.clone();
+        }
+        ring.new(coeffs)
+    }
+}
+
+impl<T: Ring> PartialEq for SparsePoly<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.terms == other.terms
+    }
+}
+
+impl<T: Ring> fmt::Debug for SparsePoly<T>
+where
+    <T as Ring>::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SparsePoly")
+            .field("var", &self.parent.var)
+            .field("terms", &self.terms)
+            .finish()
+    }
+}
+
+impl<T: Ring> fmt::Display for SparsePoly<T>
+where
+    <T as Ring>::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+        for (k, (e, c)) in self.terms.iter().rev().enumerate() {
+            if k > 0 {
+                write!(f, " + ")?;
+            }
+            match e {
+                0 => write!(f, "{}", c)?,
+                1 => write!(f, "{}*{}", c, self.parent.var)?,
+                _ => write!(f, "{}*{}^{}", c, self.parent.var, e)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Ring> Parent for SparsePolyRing<T> {
+    type Element = SparsePoly<T>;
+}
+
+impl<T: Ring> Element for SparsePoly<T> {
+    type Parent = SparsePolyRing<T>;
+
+    #[inline]
+    fn parent(&self) -> SparsePolyRing<T> {
+        self.parent.clone()
+    }
+}
+
+/// Construct a polynomial from `(exponent, coefficient)` pairs in any order.
+impl<T: Ring> NewElement<Vec<(u64, Elem<T>)>> for SparsePolyRing<T> {
+    #[inline]
+    fn new(&self, src: Vec<(u64, Elem<T>)>) -> SparsePoly<T> {
+        SparsePoly::from_terms(self, src)
+    }
+}
+
+/// Convert from the dense representation.
+impl<'a, T: Ring> NewElement<&'a GenericPoly<T>> for SparsePolyRing<T> {
+    fn new(&self, src: &'a GenericPoly<T>) -> SparsePoly<T> {
+        let terms = src.coefficients().iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(i, c)| (i as u64, c.clone()))
+            .collect();
+        SparsePoly { parent: self.clone(), terms }
+    }
+}
+
+// Additive properties
+
+impl<T: Ring> Operation<Additive> for SparsePoly<T> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let mut terms = Vec::with_capacity(self.terms.len() + rhs.terms.len());
+        let (mut i, mut j) = (0, 0);
+        while i < self.terms.len() && j < rhs.terms.len() {
+            let (a, b) = (&self.terms[i], &rhs.terms[j]);
+            match a.0.cmp(&b.0) {
+                Ordering::Less => {
+                    terms.push(a.clone());
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    terms.push(b.clone());
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    let mut c = a.1.clone();
+                    c += &b.1;
+                    if !c.is_zero() {
+                        terms.push((a.0, c));
+                    }
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        terms.extend_from_slice(&self.terms[i..]);
+        terms.extend_from_slice(&rhs.terms[j..]);
+        SparsePoly { parent: self.parent.clone(), terms }
+    }
+}
+
+impl<T: Ring> Identity<Additive> for SparsePolyRing<T> {
+    #[inline]
+    fn identity(&self) -> SparsePoly<T> {
+        SparsePoly { parent: self.clone(), terms: vec![] }
+    }
+}
+
+impl<T: Ring> IsIdentity<Additive> for SparsePoly<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<T: Ring> TwoSidedInverse<Additive> for SparsePoly<T> {
+    fn two_sided_inverse(&self) -> Self {
+        let mut res = self.clone();
+        for t in res.terms.iter_mut() {
+            t.1.neg_assign();
+        }
+        res
+    }
+}
+
+impl<T: Ring> Divisible<Additive> for SparsePolyRing<T> {}
+
+impl<T: Ring> Associative<Additive> for SparsePolyRing<T> {}
+
+impl<T: Ring> Commutative<Additive> for SparsePolyRing<T> {}
+
+// Multiplicative properties
+
+/// Multiplication uses Johnson's heap algorithm: the partial products
+/// `a_i*b_j*x^(e_i+f_j)` are merged in order of increasing exponent using a heap
+/// holding at most one pending product per term of the shorter factor. This uses
+/// `O(n)` extra space and `O(nm log n)` comparisons for factors with `n <= m`
+/// terms, and produces the result already sorted.
+impl<T: Ring> Operation<Multiplicative> for SparsePoly<T> {
+    fn operate(&self, rhs: &Self) -> Self {
+        let (a, b) = if self.terms.len() <= rhs.terms.len() {
+            (&self.terms, &rhs.terms)
+        } else {
+            (&rhs.terms, &self.terms)
+        };
+        if a.is_empty() {
+            return self.parent.zero();
+        }
+
+        let exp = |i: usize, j: usize| -> u64 {
+            a[i].0.checked_add(b[j].0).expect("exponent overflow")
+        };
+
+        let mut heap = BinaryHeap::with_capacity(a.len());
+        for i in 0..a.len() {
+            heap.push(Reverse((exp(i, 0), i, 0)));
+        }
+
+        let mut terms: Vec<(u64, Elem<T>)> = Vec::new();
+        while let Some(Reverse((e, i, j))) = heap.pop() {
+            let mut t = a[i].1.clone();
+            t *= &b[j].1;
+            match terms.last_mut() {
+                Some(last) if last.0 == e => last.1 += &t,
+                _ => {
+                    if terms.last().is_some_and(|last| last.1.is_zero()) {
+                        terms.pop();
+                    }
+                    terms.push((e, t));
+                }
+            }
+            if j + 1 < b.len() {
+                heap.push(Reverse((exp(i, j + 1), i, j + 1)));
+            }
+        }
+        if terms.last().is_some_and(|last| last.1.is_zero()) {
+            terms.pop();
+        }
+        SparsePoly { parent: self.parent.clone(), terms }
+    }
+}
+
+impl<T: Ring> Identity<Multiplicative> for SparsePolyRing<T> {
+    #[inline]
+    fn identity(&self) -> SparsePoly<T> {
+        SparsePoly::from_terms(self, vec![(0, self.ring.one())])
+    }
+}
+
+impl<T: Ring> IsIdentity<Multiplicative> for SparsePoly<T> {
+    #[inline]
+    fn is_identity(&self) -> bool {
+        self.terms.len() == 1 && self.terms[0].0 == 0 && self.terms[0].1.is_one()
+    }
+}
+
+impl<T: Ring> Associative<Multiplicative> for SparsePolyRing<T> {}
+
+impl<T: Ring> Commutative<Multiplicative> for SparsePolyRing<T> {}
+
+// Ring-like properties
+
+impl<T: Ring> Distributive for SparsePolyRing<T> {}
+
+impl_ring_ops!([T: Ring] SparsePoly<T>);
+
+impl<T: Ring> Pow<u64> for &SparsePoly<T> {
+    type Output = SparsePoly<T>;
+
+    #[inline]
+    fn pow(self, rhs: u64) -> SparsePoly<T> {
+        self.parent.power(Multiplicative, self, rhs)
+    }
+}
+
+impl<T: Ring> Pow<u64> for SparsePoly<T> {
+    type Output = SparsePoly<T>;
+
+    #[inline]
+    fn pow(self, rhs: u64) -> SparsePoly<T> {
+        (&self).pow(rhs)
+    }
+}
+
+impl<T: Ring> PowAssign<u64> for SparsePoly<T> {
+    #[inline]
+    fn pow_assign(&mut self, rhs: u64) {
+        *self = (&*self).pow(rhs);
+    }
+}
+
+impl<T: Ring> Algebra<T> for SparsePolyRing<T> {
+    #[inline]
+    fn embed_base(&self, x: &Elem<T>) -> SparsePoly<T> {
+        SparsePoly::from_terms(self, vec![(0, x.clone())])
+    }
+}
+
+impl<T: Ring> PolynomialRing<T> for SparsePolyRing<T> {
+    type Element = SparsePoly<T>;
+
+    fn init<S: Into<String>>(ring: &T, var: S) -> Self {
+        SparsePolyRing { ring: ring.clone(), var: var.into().into() }
+    }
+
+    #[inline]
+    fn base_ring(&self) -> &T {
+        &self.ring
+    }
+
+    #[inline]
+    fn var(&self) -> String {
+        self.var.to_string()
+    }
+
+    #[inline]
+    fn set_var<S: Into<String>>(&mut self, var: S) {
+        self.var = var.into().into();
+    }
+
+    #[inline]
+    fn is_generic(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Ring> PolynomialRingElement<T> for SparsePoly<T> {
+    type Parent = SparsePolyRing<T>;
+
+    type Borrow<'a> = &'a Elem<T> where T: 'a;
+    type BorrowMut<'a> = &'a mut Elem<T> where T: 'a;
+
+    #[inline]
+    fn base_ring(&self) -> &T {
+        &self.parent.ring
+    }
+
+    #[inline]
+    fn var(&self) -> String {
+        self.parent.var.to_string()
+    }
+
+    /// Return the degree + 1. Panics if this does not fit in a `usize`; use
+    /// `udegree` for polynomials of huge degree.
+    #[inline]
+    fn len(&self) -> usize {
+        self.udegree().map_or(0, |d| {
+            usize::try_from(d).ok().and_then(|d| d.checked_add(1)).expect("degree too large")
+        })
+    }
+
+    fn get_coefficient(&self, i: usize) -> Elem<T> {
+        match self.terms.binary_search_by_key(&(i as u64), |t| t.0) {
+            Ok(k) => self.terms[k].1.clone(),
+            Err(_) => self.parent.ring.zero(),
+        }
+    }
+
+    fn set_coefficient(&mut self, i: usize, coeff: Elem<T>) {
+        let e = i as u64;
+        match self.terms.binary_search_by_key(&e, |t| t.0) {
+            Ok(k) if coeff.is_zero() => {
+                self.terms.remove(k);
+            }
+            Ok(k) => self.terms[k].1 = coeff,
+            Err(_) if coeff.is_zero() => {}
+            Err(k) => self.terms.insert(k, (e, coeff)),
+        }
+    }
+
+    /// Return the dense vector of coefficients. See the note on converting to the
+    /// dense representation.
+    fn get_coefficients(&self) -> Vec<Elem<T>> {
+        let mut coeffs = vec![self.parent.ring.zero(); self.len()];
+        for (e, c) in self.terms.iter() {
+            coeffs[*e as usize] = c.clone();
+        }
+        coeffs
+    }
+
+    #[inline]
+    fn is_generic(&self) -> bool {
+        true
+    }
+}
+
+// Evaluation
+
+/// Evaluate the polynomial at any element of an algebra over the base ring. Each
+/// power of `x` is computed by repeated squaring, so this is efficient even for
+/// huge exponents.
+impl<'a, T: Ring, X> Evaluate<&'a X> for SparsePoly<T>
+where
+    X: Element + AddOps + MulOps,
+    Par<X>: Algebra<T> + AbstractMonoid<Multiplicative, Element = X>,
+{
+    type Output = X;
+
+    fn evaluate(&self, x: &'a X) -> X {
+        let mut res = x.parent().embed_base(&self.parent.ring.zero());
+        res.assign_evaluate(self, x);
+        res
+    }
+}
+
+impl<'a, 'b, T: Ring, X> AssignEvaluate<&'a SparsePoly<T>, &'b X> for X
+where
+    X: Element + AddOps + MulOps,
+    Par<X>: Algebra<T> + AbstractMonoid<Multiplicative, Element = X>,
+{
+    fn assign_evaluate(&mut self, expr: &'a SparsePoly<T>, x: &'b X) {
+        let alg = x.parent();
+        *self = alg.embed_base(&expr.parent.ring.zero());
+
+        // Walk the terms upwards, multiplying the running power of `x` by
+        // `x^(e_k - e_{k-1})` at each step.
+        let mut xpow = alg.embed_base(&expr.parent.ring.one());
+        let mut prev = 0;
+        for (e, c) in expr.terms.iter() {
+            xpow *= &alg.power(Multiplicative, x, e - prev);
+            prev = *e;
+            let mut t = alg.embed_base(c);
+            t *= &xpow;
+            *self += &t;
+        }
+    }
+}
+
+/// Evaluate an integer polynomial modulo `modulus`, returning the least
+/// non-negative residue. Powers are computed by modular exponentiation with
+/// 128-bit intermediates, so this never overflows.
+impl<'a, 'b> EvaluateMod<&'a i64, &'b i64> for SparsePoly<IntegerRing> {
+    type Output = i64;
+
+    fn evaluate_mod(&self, x: &'a i64, modulus: &'b i64) -> i64 {
+        let mut res = 0;
+        res.assign_evaluate_mod(self, x, modulus);
+        res
+    }
+}
+
+impl<'a, 'b, 'c> AssignEvaluateMod<&'a SparsePoly<IntegerRing>, &'b i64, &'c i64> for i64 {
+    fn assign_evaluate_mod(
+        &mut self,
+        expr: &'a SparsePoly<IntegerRing>,
+        x: &'b i64,
+        modulus: &'c i64
+    ) {
+        assert!(*modulus > 0, "modulus must be positive");
+        let m = *modulus as i128;
+        let x = (*x as i128).rem_euclid(m);
+        let mut res = 0i128;
+        let mut xpow = 1i128 % m;
+        let mut prev = 0;
+        for (e, c) in expr.terms.iter() {
+            let mut n = e - prev;
+            let mut base = x;
+            while n > 0 {
+                if n & 1 == 1 {
+                    xpow = xpow * base % m;
+                }
+                base = base * base % m;
+                n >>= 1;
+            }
+            prev = *e;
+            res = (res + xpow * (*c as i128).rem_euclid(m)) % m;
+        }
+        *self = res as i64;
+    }
+}
@@ -205,6 +205,24 @@ pub trait AbstractMonoid<O: Operator>:
 {
     type Element: AbstractMonoidElement<O, Parent=Self>;
     fn is_abstract_monoid(&self, _: O) -> bool { true }
+
+    /// Return `x ∘ x ∘ ... ∘ x` (`n` times), or the identity if `n` is zero,
+    /// computed by repeated squaring.
+    fn power(&self, _: O, x: &Elem<Self>, n: u64) -> Elem<Self> {
+        let mut res = self.identity();
+        let mut base = x.clone();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                res = res.operate(&base);
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.operate(&base);
+            }
+        }
+        res
+    }
 }
 
 pub trait AbstractMonoidElement<O: Operator>: 
@@ -4,8 +4,20 @@ pub use grouplike::*;
 pub use ringlike::*;
 pub use poly::*;
 pub use mat::*;
+pub use algebra::*;
+pub use mpoly::*;
+pub use euclidean::*;
+pub use ideal::*;
+pub use vector_space::*;
+pub use finite::*;
 
 mod grouplike;
 mod ringlike;
 mod poly;
 mod mat;
+mod algebra;
+mod mpoly;
+mod euclidean;
+mod ideal;
+mod vector_space;
+mod finite;
@@ -0,0 +1,35 @@
+use crate::*;
+
+/// An algebra over the commutative ring `T`: a ring together with a structure map
+/// sending elements of `T` into it. This is what allows, for example, polynomials 
+/// over `T` to be evaluated at elements of the algebra.
+///
+/// Every ring is an algebra over itself.
+pub trait Algebra<T: Ring>: Parent {
+    /// Return the image of `x` under the structure map `T → Self`.
+    fn embed_base(&self, x: &Elem<T>) -> Elem<Self>;
+}
+
+impl<T: Ring> Algebra<T> for T {
+    #[inline]
+    fn embed_base(&self, x: &Elem<T>) -> Elem<T> {
+        x.clone()
+    }
+}
+
+/// An algebra over the field `F` with an involution `x ↦ x̄` which fixes `F` and
+/// reverses products, such that the norm `x x̄` lies in `F`. Examples are the
+/// quaternion algebras, and these are the algebras which can be doubled by the
+/// Cayley-Dickson construction.
+///
+/// The multiplication is not required to be associative.
+pub trait StarAlgebra<F: Field>: Algebra<F> + Parent<Element: RingOps> {
+    /// Return a reference to the base field.
+    fn base_field(&self) -> &F;
+
+    /// Return the conjugate `x̄` of `x`.
+    fn conjugate(&self, x: &Elem<Self>) -> Elem<Self>;
+
+    /// Return the norm `x x̄` of `x` as an element of the base field.
+    fn norm(&self, x: &Elem<Self>) -> Elem<F>;
+}
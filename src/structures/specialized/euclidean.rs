@@ -0,0 +1,197 @@
+use crate::*;
+use crate::ops::*;
+
+/// A Euclidean domain: an integral domain with a division with remainder whose
+/// remainders are smaller than the divisor, so that gcds can be computed with the
+/// Euclidean algorithm. Every ideal of a Euclidean domain is principal.
+pub trait EuclideanDomain: Ring {
+    /// Return `(q, r)` such that `a = q*b + r` and `r` is zero or smaller than
+    /// `b`. Panics if `b` is zero.
+    fn div_rem(&self, a: &Elem<Self>, b: &Elem<Self>) -> (Elem<Self>, Elem<Self>);
+
+    /// Return a unit `u` such that `u*a` is the canonical associate of `a`, for
+    /// example the sign of an integer or the inverse of the leading coefficient of
+    /// a polynomial. Returns one if `a` is zero.
+    fn canonical_unit(&self, a: &Elem<Self>) -> Elem<Self>;
+
+    /// Return `Some(true)` if `a` is irreducible, `Some(false)` if it is not and
+    /// `None` if this cannot be decided. Zero and units are not irreducible.
+    #[inline]
+    fn is_irreducible(&self, _a: &Elem<Self>) -> Option<bool> {
+        None
+    }
+
+    /// Return the remainder of `a` divided by `b`.
+    #[inline]
+    fn rem(&self, a: &Elem<Self>, b: &Elem<Self>) -> Elem<Self> {
+        self.div_rem(a, b).1
+    }
+
+    /// Return the canonical representative of the class of `a` modulo `m`, so
+    /// that `reduce(a, m) == reduce(b, m)` whenever `a - b` is a multiple of `m`.
+    /// Rings whose remainders are not unique must override this.
+    #[inline]
+    fn reduce(&self, a: &Elem<Self>, m: &Elem<Self>) -> Elem<Self> {
+        self.rem(a, m)
+    }
+
+    /// Return the canonical representative of `a + b` modulo `m`. Rings whose sums
+    /// can overflow before reduction should override this.
+    #[inline]
+    fn add_mod(&self, a: &Elem<Self>, b: &Elem<Self>, m: &Elem<Self>) -> Elem<Self> {
+        let mut t = a.clone();
+        t += b;
+        self.reduce(&t, m)
+    }
+
+    /// Return the canonical representative of `a*b` modulo `m`. Rings whose
+    /// products can overflow before reduction should override this.
+    #[inline]
+    fn mul_mod(&self, a: &Elem<Self>, b: &Elem<Self>, m: &Elem<Self>) -> Elem<Self> {
+        let mut t = a.clone();
+        t *= b;
+        self.reduce(&t, m)
+    }
+
+    /// Return the canonical associate of `a`.
+    #[inline]
+    fn normalize(&self, a: &Elem<Self>) -> Elem<Self> {
+        let mut u = self.canonical_unit(a);
+        u *= a;
+        u
+    }
+
+    /// Return `true` if `a` is a unit.
+    #[inline]
+    fn is_unit(&self, a: &Elem<Self>) -> bool {
+        !a.is_zero() && self.rem(&self.one(), a).is_zero()
+    }
+
+    /// Return `true` if `b` divides `a`.
+    #[inline]
+    fn divides(&self, b: &Elem<Self>, a: &Elem<Self>) -> bool {
+        if b.is_zero() {
+            a.is_zero()
+        } else {
+            self.rem(a, b).is_zero()
+        }
+    }
+
+    /// Return the exact quotient `a/b`. Panics if `b` does not divide `a`.
+    fn divexact(&self, a: &Elem<Self>, b: &Elem<Self>) -> Elem<Self> {
+        let (q, r) = self.div_rem(a, b);
+        assert!(r.is_zero(), "inexact division");
+        q
+    }
+
+    /// Return the exact quotient `(a*b - c*d)/e`, the step of fraction-free
+    /// elimination. Rings whose products can overflow even though the quotient
+    /// does not should override this. Panics if the division is inexact.
+    fn mul_sub_divexact(&self, a: &Elem<Self>, b: &Elem<Self>, c: &Elem<Self>, d: &Elem<Self>, e: &Elem<Self>) -> Elem<Self> {
+        let mut t = a.clone();
+        t *= b;
+        let mut u = c.clone();
+        u *= d;
+        t -= &u;
+        self.divexact(&t, e)
+    }
+
+    /// Return the determinant of a nonsingular matrix formed by `n` rows of the
+    /// `m × n` matrix with row-major entries `a`, or `None` if its rank is less
+    /// than `n`. This is the modulus of the modular Hermite normal form.
+    ///
+    /// The default uses fraction-free elimination, whose intermediate entries are
+    /// minors which can be much larger than the result. Rings whose elements can
+    /// overflow should override this, and may also return `None` when the result
+    /// cannot be computed.
+    fn lattice_det(&self, a: &[Elem<Self>], m: usize, n: usize) -> Option<Elem<Self>> {
+        let mat = GenericMatrixSpace::init(self, m as u64, n as u64).new(a.to_vec());
+        // Independent rows are the pivot columns of the transpose.
+        let (_, rows) = mat.transpose().fraction_free_echelon();
+        if rows.len() < n {
+            return None;
+        }
+        let sub = rows.iter().flat_map(|&i| a[i * n..(i + 1) * n].iter().cloned()).collect();
+        Some(GenericMatrixSpace::init(self, n as u64, n as u64).new(sub).det_bareiss())
+    }
+
+    /// Return the row-major entries of the matrix `X` with `X A = B`, where `A`
+    /// is the nonsingular `n × n` matrix with entries `a` and `B` has `n` columns
+    /// and entries `b`, given that `X` has entries in the ring. Panics if it does
+    /// not.
+    ///
+    /// The default computes `B (d A^-1) / d` by fraction-free elimination. Rings
+    /// whose elements can overflow should override this, and may return `None`
+    /// when the result cannot be computed.
+    fn solve_left_exact(&self, a: &[Elem<Self>], b: &[Elem<Self>], n: usize) -> Option<Vec<Elem<Self>>> {
+        if n == 0 {
+            return Some(vec![]);
+        }
+        let mat = GenericMatrixSpace::init(self, n as u64, n as u64).new(a.to_vec());
+        let (d, x) = mat.scaled_inverse_bareiss(false).expect("matrix is singular");
+        let k = (b.len() / n) as u64;
+        let y = GenericMatrixSpace::init(self, k, n as u64).new(b.to_vec()).mul_matrix(&x);
+        Some(y.entries().iter().map(|e| self.divexact(e, &d)).collect())
+    }
+
+    /// Return the canonical greatest common divisor of `a` and `b`.
+    fn gcd(&self, a: &Elem<Self>, b: &Elem<Self>) -> Elem<Self> {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        while !b.is_zero() {
+            let r = self.rem(&a, &b);
+            a = b;
+            b = r;
+        }
+        self.normalize(&a)
+    }
+
+    /// Return `(g, s, t)` where `g` is the canonical greatest common divisor of
+    /// `a` and `b` and `g = s*a + t*b`.
+    fn xgcd(&self, a: &Elem<Self>, b: &Elem<Self>) -> (Elem<Self>, Elem<Self>, Elem<Self>) {
+        let (mut r0, mut r1) = (a.clone(), b.clone());
+        let (mut s0, mut s1) = (self.one(), self.zero());
+        let (mut t0, mut t1) = (self.zero(), self.one());
+        while !r1.is_zero() {
+            let (q, r) = self.div_rem(&r0, &r1);
+            r0 = std::mem::replace(&mut r1, r);
+            let mut qs = q.clone();
+            qs *= &s1;
+            s0 -= &qs;
+            std::mem::swap(&mut s0, &mut s1);
+            let mut qt = q;
+            qt *= &t1;
+            t0 -= &qt;
+            std::mem::swap(&mut t0, &mut t1);
+        }
+        let u = self.canonical_unit(&r0);
+        r0 *= &u;
+        s0 *= &u;
+        t0 *= &u;
+        (r0, s0, t0)
+    }
+
+    /// Return the canonical least common multiple of `a` and `b`.
+    fn lcm(&self, a: &Elem<Self>, b: &Elem<Self>) -> Elem<Self> {
+        if a.is_zero() || b.is_zero() {
+            return self.zero();
+        }
+        let mut l = self.divexact(a, &self.gcd(a, b));
+        l *= b;
+        self.normalize(&l)
+    }
+
+    /// Return the inverse of `a` modulo `m`, or `None` if `a` is not a unit
+    /// modulo `m`.
+    fn inverse_mod(&self, a: &Elem<Self>, m: &Elem<Self>) -> Option<Elem<Self>> {
+        let (g, s, _) = self.xgcd(a, m);
+        if self.is_unit(&g) {
+            let mut s = s;
+            if !g.is_one() {
+                s = self.mul_mod(&s, &self.divexact(&self.one(), &g), m);
+            }
+            Some(self.reduce(&s, m))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::*;
+
+/// A parent with finitely many elements, all of which can be listed. This
+/// allows properties such as associativity to be checked exhaustively.
+pub trait Finite: Parent {
+    /// Return all elements, each exactly once.
+    fn elements(&self) -> Vec<Elem<Self>>;
+
+    /// Return the number of elements.
+    #[inline]
+    fn cardinality(&self) -> usize {
+        self.elements().len()
+    }
+}
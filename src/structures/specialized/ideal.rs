@@ -0,0 +1,60 @@
+use crate::*;
+
+/// An ideal of the commutative ring `R`, described by a finite set of generators.
+///
+/// Operations return ideals of the same type. Questions which are not decidable
+/// for a particular kind of ideal return `None`.
+pub trait Ideal<R: Ring>: Clone + PartialEq {
+    /// Return a reference to the ring containing the ideal.
+    fn ring(&self) -> &R;
+
+    /// Return a set of generators of the ideal.
+    fn gens(&self) -> Vec<Elem<R>>;
+
+    /// Return `true` if `x` lies in the ideal.
+    fn contains(&self, x: &Elem<R>) -> bool;
+
+    /// Return the sum `I + J`, the smallest ideal containing both.
+    fn sum(&self, other: &Self) -> Self;
+
+    /// Return the product `IJ`, generated by the products of generators.
+    fn product(&self, other: &Self) -> Self;
+
+    /// Return the intersection of the ideals.
+    fn intersection(&self, other: &Self) -> Self;
+
+    /// Return the ideal quotient `(I : J) = { x : xJ ⊆ I }`.
+    fn quotient(&self, other: &Self) -> Self;
+
+    /// Return `Some(true)` if the ideal is prime, `Some(false)` if it is not and
+    /// `None` if this cannot be decided.
+    #[inline]
+    fn is_prime(&self) -> Option<bool> {
+        None
+    }
+
+    /// Return `Some(true)` if the ideal is maximal, `Some(false)` if it is not
+    /// and `None` if this cannot be decided.
+    #[inline]
+    fn is_maximal(&self) -> Option<bool> {
+        None
+    }
+
+    /// Return `true` if the ideal is zero.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.gens().iter().all(|g| g.is_zero())
+    }
+
+    /// Return `true` if the ideal is the whole ring.
+    #[inline]
+    fn is_whole_ring(&self) -> bool {
+        self.contains(&self.ring().one())
+    }
+
+    /// Return `true` if `other` is contained in `self`.
+    #[inline]
+    fn contains_ideal(&self, other: &Self) -> bool {
+        other.gens().iter().all(|g| self.contains(g))
+    }
+}
@@ -0,0 +1,131 @@
+use crate::*;
+use std::cmp::Ordering;
+
+/// A monomial ordering, used to determine the leading term of a multivariate
+/// polynomial. Variables are ordered by index, so the first variable is the
+/// largest.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MonomialOrdering {
+    /// Lexicographic order.
+    #[default]
+    Lex,
+    /// Graded lexicographic order: total degree first, ties broken by `Lex`.
+    Grlex,
+    /// Graded reverse lexicographic order: total degree first, ties broken by
+    /// the smallest exponent in the last variable where the monomials differ.
+    Grevlex,
+}
+
+impl MonomialOrdering {
+    /// Compare the monomials with exponent vectors `a` and `b`, which must have
+    /// the same length.
+    pub fn cmp(&self, a: &[u64], b: &[u64]) -> Ordering {
+        debug_assert_eq!(a.len(), b.len());
+        let lex = || a.iter().cmp(b.iter());
+        let deg = || a.iter().sum::<u64>().cmp(&b.iter().sum::<u64>());
+        match self {
+            MonomialOrdering::Lex => lex(),
+            MonomialOrdering::Grlex => deg().then_with(lex),
+            MonomialOrdering::Grevlex => deg().then_with(|| {
+                a.iter().rev().zip(b.iter().rev())
+                    .find(|(x, y)| x != y)
+                    .map_or(Ordering::Equal, |(x, y)| y.cmp(x))
+            }),
+        }
+    }
+}
+
+pub trait MultivariatePolynomialRing<T: Ring>:
+    Ring<Element=<Self as MultivariatePolynomialRing<T>>::Element>
+{
+    type Element: MultivariatePolynomialRingElement<T, Parent=Self>;
+
+    /// Initialize the `MultivariatePolynomialRing` from a base ring, the names of
+    /// the variables used for printing and a monomial ordering.
+    fn init<I, S>(ring: &T, vars: I, ordering: MonomialOrdering) -> Self
+    where
+        I: IntoIterator<Item=S>,
+        S: Into<String>;
+
+    /// Return a reference to the base ring.
+    fn base_ring(&self) -> &T;
+
+    /// Return the variables as `String`s.
+    fn vars(&self) -> Vec<String>;
+
+    fn nvars(&self) -> i64;
+
+    fn ordering(&self) -> MonomialOrdering;
+
+    /// Return the `i`-th variable as an element of the ring.
+    fn gen(&self, i: usize) -> <Self as MultivariatePolynomialRing<T>>::Element;
+
+    #[inline]
+    fn is_generic(&self) -> bool {
+        false
+    }
+}
+
+pub trait MultivariatePolynomialRingElement<T: Ring>:
+    RingElement<Parent=<Self as MultivariatePolynomialRingElement<T>>::Parent>
+{
+    type Parent: MultivariatePolynomialRing<T, Element=Self>;
+
+    /// Return a reference to the base ring.
+    fn base_ring(&self) -> &T;
+
+    /// Return the variables as `String`s.
+    fn vars(&self) -> Vec<String>;
+
+    fn nvars(&self) -> i64;
+
+    /// Return the number of nonzero terms.
+    fn num_terms(&self) -> usize;
+
+    /// Return the total degree, or -1 for the zero polynomial.
+    fn total_degree(&self) -> i64;
+
+    /// Return the degree in the `i`-th variable, or -1 for the zero polynomial.
+    fn degree_in(&self, i: usize) -> i64;
+
+    /// Return the coefficient of the monomial with exponent vector `exps`.
+    fn get_coefficient(&self, exps: &[u64]) -> Elem<T>;
+
+    #[inline]
+    fn get_coeff(&self, exps: &[u64]) -> Elem<T> {
+        self.get_coefficient(exps)
+    }
+
+    fn set_coefficient(&mut self, exps: &[u64], coeff: Elem<T>);
+
+    #[inline]
+    fn set_coeff(&mut self, exps: &[u64], coeff: Elem<T>) {
+        self.set_coefficient(exps, coeff);
+    }
+
+    /// Return the nonzero terms as `(exponents, coefficient)` pairs, in decreasing
+    /// order with respect to the monomial ordering of the parent.
+    fn get_terms(&self) -> Vec<(Vec<u64>, Elem<T>)>;
+
+    /// Return the exponent vector of the leading monomial, or `None` for the zero
+    /// polynomial.
+    fn leading_monomial(&self) -> Option<Vec<u64>>;
+
+    /// Return the leading coefficient, or `None` for the zero polynomial.
+    fn leading_coefficient(&self) -> Option<Elem<T>>;
+
+    /// Return the leading term (coefficient times monomial). The leading term of
+    /// zero is zero.
+    fn leading_term(&self) -> Self;
+
+    /// Substitute `value` for the `i`-th variable.
+    fn evaluate_var(&self, i: usize, value: &Elem<T>) -> Self;
+
+    /// Substitute the polynomial `value` for the `i`-th variable.
+    fn substitute(&self, i: usize, value: &Self) -> Self;
+
+    #[inline]
+    fn is_generic(&self) -> bool {
+        false
+    }
+}
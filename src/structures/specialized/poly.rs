@@ -49,7 +49,7 @@ pub trait PolynomialRingElement<T: Ring>:
     /// Return the degree of the polynomial, which is always the length - 1.
     #[inline]
     fn degree(&self) -> i64 {
-        (self.len() - 1).try_into().unwrap()
+        self.len() as i64 - 1
     }
 
     /// Return the length of the polynomial, which is always the degree + 1.
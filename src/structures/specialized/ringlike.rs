@@ -75,6 +75,11 @@ where
 
 pub trait FieldOps: RingOps + InvOps + DivOps {}
 
+impl<T> FieldOps for T
+where
+    T: RingOps + InvOps + DivOps
+{}
+
 pub trait Field:
     AbstractField<Element=<Self as Field>::Element>
 {
@@ -0,0 +1,29 @@
+use crate::*;
+
+/// A finite dimensional vector space over the field `F`, with a distinguished
+/// basis in which elements have coordinates.
+pub trait VectorSpace<F: Field>: AbstractGroupAbelian<Additive> {
+    /// Return a reference to the field of scalars.
+    fn base_field(&self) -> &F;
+
+    /// Return the dimension over the base field.
+    fn dimension(&self) -> usize;
+
+    /// Return the scalar multiple `c x`.
+    fn scale(&self, c: &Elem<F>, x: &Elem<Self>) -> Elem<Self>;
+
+    /// Return the distinguished basis.
+    fn basis(&self) -> Vec<Elem<Self>>;
+
+    /// Return the coordinates of `x` in the distinguished basis.
+    fn coordinates(&self, x: &Elem<Self>) -> Vec<Elem<F>>;
+
+    /// Return the linear combination of the basis with the given coefficients.
+    /// Panics if the number of coefficients is not the dimension.
+    fn linear_combination(&self, coeffs: &[Elem<F>]) -> Elem<Self> {
+        assert_eq!(coeffs.len(), self.dimension(), "wrong number of coefficients");
+        self.basis().iter()
+            .zip(coeffs)
+            .fold(self.identity(), |acc, (b, c)| acc.operate(&self.scale(c, b)))
+    }
+}
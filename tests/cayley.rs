@@ -0,0 +1,138 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+fn check_isomorphism<G, H>(f: &GroupIsomorphism<G, H, Multiplicative>)
+where
+    G: AbstractGroup<Multiplicative> + Finite,
+    H: AbstractGroup<Multiplicative> + Finite,
+    <G as Parent>::Element: Eq + std::hash::Hash,
+    <H as Parent>::Element: Eq + std::hash::Hash,
+{
+    let elems = f.domain().elements();
+    for a in elems.iter() {
+        for b in elems.iter() {
+            assert!(f.prop_preserves_operation((a.clone(), b.clone())));
+        }
+    }
+    assert!(f.is_isomorphism());
+    let g = f.inverse();
+    assert!(elems.iter().all(|x| g.apply(&f.apply(x)) == *x));
+}
+
+#[test]
+fn cayley_tables() {
+    let c3 = CyclicGroup::init(3);
+    assert_eq!(cayley_table(Multiplicative, &c3), vec![vec![0, 1, 2], vec![1, 2, 0], vec![2, 0, 1]]);
+
+    let q8 = QuaternionGroup::init();
+    let t = TableMagma::from_parent(Multiplicative, &q8);
+    assert_eq!(t.order(), 8);
+    assert!(t.is_latin_square() && t.is_associative() && !t.is_commutative());
+    assert_eq!(t.identity_index(), Some(0));
+    let g = t.as_group().unwrap();
+    assert!(g.is_abstract_group(Multiplicative));
+    for a in g.elements() {
+        assert!(a.operate(&a.two_sided_inverse()).is_identity());
+        for b in g.elements() {
+            for c in g.elements() {
+                assert!(TableGroup::prop_is_associative((a.clone(), b.clone(), c.clone())));
+            }
+        }
+    }
+    assert_eq!(format!("{}", g.new(3)), "e3");
+    assert_eq!(cayley_table(Multiplicative, &g), t.table());
+}
+
+#[test]
+fn exotic_tables() {
+    // The smallest loop which is not a group.
+    let l = TableMagma::init(&[
+        vec![0, 1, 2, 3, 4],
+        vec![1, 0, 3, 4, 2],
+        vec![2, 4, 0, 1, 3],
+        vec![3, 2, 4, 0, 1],
+        vec![4, 3, 1, 2, 0],
+    ]);
+    assert!(l.is_latin_square());
+    assert!(!l.is_associative());
+    assert!(l.as_group().is_none());
+    let l = l.as_loop().unwrap();
+    assert!(l.is_abstract_loop(Multiplicative));
+    for a in l.elements() {
+        assert!(a.operate(&a).is_identity());
+        assert!(a.operate(&a.two_sided_inverse()).is_identity());
+        for b in l.elements() {
+            let x = l.left_division(&a, &b).unwrap();
+            assert_eq!(a.operate(&x), b);
+            let y = l.right_division(&b, &a).unwrap();
+            assert_eq!(y.operate(&a), b);
+        }
+    }
+
+    // x * y = -x - y mod 3 is a commutative quasigroup without identity.
+    let q = TableMagma::init(&[vec![0, 2, 1], vec![2, 1, 0], vec![1, 0, 2]]);
+    assert!(q.is_latin_square() && q.is_commutative() && !q.is_associative());
+    assert_eq!(q.identity_index(), None);
+    assert!(q.as_loop().is_none());
+    assert!(q.is_abstract_magma(Multiplicative));
+
+    // Rock, paper, scissors: each pair is won by one of its members.
+    let rps = TableMagma::init(&[vec![0, 1, 0], vec![1, 1, 2], vec![0, 2, 2]]);
+    assert!(rps.is_commutative() && !rps.is_associative() && !rps.is_latin_square());
+    assert_eq!(rps.left_division(&rps.new(0), &rps.new(0)), None);
+    let (r, p, s) = (rps.new(0), rps.new(1), rps.new(2));
+    assert_eq!(r.operate(&p).operate(&s), s);
+    assert_eq!(r.operate(&p.operate(&s)), r);
+}
+
+#[test]
+#[should_panic]
+fn table_out_of_range() {
+    TableMagma::init(&[vec![0, 1], vec![1, 2]]);
+}
+
+#[test]
+fn isomorphisms() {
+    let s3 = SymmetricGroup::init(3);
+    let d3 = DihedralGroup::init(3);
+    let f = find_isomorphism(Multiplicative, &d3, &s3).unwrap();
+    check_isomorphism(&f);
+
+    let d4 = DihedralGroup::init(4);
+    let q8 = QuaternionGroup::init();
+    let c8 = CyclicGroup::init(8);
+    assert!(!is_isomorphic(Multiplicative, &d4, &q8));
+    assert!(!is_isomorphic(Multiplicative, &c8, &q8));
+    assert!(!is_isomorphic(Multiplicative, &KleinFourGroup::init(), &CyclicGroup::init(4)));
+    assert!(!is_isomorphic(Multiplicative, &c8, &CyclicGroup::init(6)));
+    let f = find_isomorphism(Multiplicative, &d4, &d4).unwrap();
+    check_isomorphism(&f);
+
+    let s4 = SymmetricGroup::init(4);
+    let table = TableMagma::from_parent(Multiplicative, &s4).as_group().unwrap();
+    assert_eq!(table.order(), 24);
+    assert!(is_isomorphic(Multiplicative, &table, &s4));
+
+    // The symmetries of the square as permutations of its vertices.
+    let square = PermutationGroup::init(&s4, &[s4.new("(0,1,2,3)"), s4.new("(1,3)")]);
+    let d4_table = TableMagma::from_parent(Multiplicative, &d4).as_group().unwrap();
+    let sub: Vec<Permutation> = square.elements().collect();
+    let sub_table = TableMagma::init(&{
+        let index = |p: &Permutation| sub.iter().position(|q| q == p).unwrap();
+        sub.iter().map(|a| sub.iter().map(|b| index(&a.operate(b))).collect()).collect::<Vec<_>>()
+    }).as_group().unwrap();
+    check_isomorphism(&find_isomorphism(Multiplicative, &d4_table, &sub_table).unwrap());
+
+    // A4 and D6 have order 12 but are not isomorphic, nor is either to C12.
+    let a4 = AlternatingGroup::init(4);
+    let d6 = DihedralGroup::init(6);
+    assert!(!is_isomorphic(Multiplicative, &a4, &d6));
+    assert!(!is_isomorphic(Multiplicative, &a4, &CyclicGroup::init(12)));
+    let a4_table = TableMagma::from_parent(Multiplicative, &a4).as_group().unwrap();
+    check_isomorphism(&find_isomorphism(Multiplicative, &a4, &a4_table).unwrap());
+
+    let a5 = AlternatingGroup::init(5);
+    let t = TableMagma::from_parent(Multiplicative, &a5).as_group().unwrap();
+    let f = find_isomorphism(Multiplicative, &t, &a5).unwrap();
+    check_isomorphism(&f);
+}
@@ -0,0 +1,95 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn rationals(v: &[i64]) -> Vec<Rational> {
+    v.iter().map(|&c| Rational::from(c)).collect()
+}
+
+fn cl(form: &[i64]) -> CliffordAlgebra<RationalField> {
+    CliffordAlgebra::init(&RationalField::init(), &rationals(form))
+}
+
+#[test]
+fn euclidean_space() {
+    let g = cl(&[1, 1, 1]);
+    assert!(g.is_ncring());
+    assert_eq!(g.dimension(), 8);
+    let e = g.gens();
+    assert_eq!(&e[0] * &e[0], g.one());
+    assert_eq!(&e[0] * &e[1], -&(&e[1] * &e[0]));
+    assert_eq!(&e[0] * &e[1], g.blade(0b011));
+
+    // The pseudoscalar squares to -1 and commutes with everything in 3d.
+    let i = &(&e[0] * &e[1]) * &e[2];
+    assert_eq!(&i * &i, -g.one());
+    assert_eq!(&i * &e[1], &e[1] * &i);
+    assert_eq!(i.reverse(), -&i);
+    assert_eq!(i.involute(), -&i);
+    assert_eq!(i.grades(), vec![3]);
+
+    // For vectors, ab = a ⌋ b + a ∧ b.
+    let a = g.vector(&rationals(&[1, 2, 3]));
+    let b = g.vector(&rationals(&[4, 5, 6]));
+    let ab = &a * &b;
+    assert_eq!(a.left_contraction(&b), g.embed_base(&Rational::from(32)));
+    assert_eq!(a.scalar_product(&b), Rational::from(32));
+    assert_eq!(ab, &a.left_contraction(&b) + &a.wedge(&b));
+    assert_eq!(ab.grade(2), a.wedge(&b));
+    assert_eq!(a.wedge(&a), g.zero());
+    assert_eq!(e[0].left_contraction(&g.blade(0b011)), e[1]);
+    assert_eq!(g.blade(0b011).right_contraction(&e[1]), e[0]);
+    assert_eq!(format!("{}", &g.one() + &ab), "33 + -3*e1*e2 + -6*e1*e3 + -3*e2*e3");
+
+    // Versors act by conjugation: reflection of a in the plane orthogonal to e1.
+    let r = ab.versor_inverse().unwrap();
+    assert_eq!(&ab * &r, g.one());
+    assert_eq!(&r * &ab, g.one());
+    let refl = -&(&(&e[0] * &a) * &e[0].versor_inverse().unwrap());
+    assert_eq!(refl, g.vector(&rationals(&[-1, 2, 3])));
+    assert_eq!((&g.one() + &e[0]).versor_inverse(), None);
+
+    let coords = g.coordinates(&ab);
+    assert_eq!(g.linear_combination(&coords), ab);
+    assert_eq!(g.scale(&Rational::from(2), &ab), &ab + &ab);
+}
+
+#[test]
+fn quaternions_and_exterior_algebra() {
+    // Cl(0, 2) is the Hamilton quaternions with k = e1 e2.
+    let h = cl(&[-1, -1]);
+    let e = h.gens();
+    let k = &e[0] * &e[1];
+    assert_eq!(&k * &k, -h.one());
+    assert_eq!(&k * &e[0], e[1]);
+
+    let ext = CliffordAlgebra::exterior(&RationalField::init(), 3);
+    let e = ext.gens();
+    assert!((&e[0] * &e[0]).is_zero());
+    assert_eq!(&e[0] * &e[1], e[0].wedge(&e[1]));
+    assert_eq!(&e[1] * &e[0], -&(&e[0] * &e[1]));
+    assert_eq!(e[0].versor_inverse(), None);
+}
+
+quickcheck! {
+    fn clifford_laws(x: Vec<i8>, y: Vec<i8>, z: Vec<i8>) -> bool {
+        let g = cl(&[1, -1, 2]);
+        let e = |v: Vec<i8>| {
+            let mut c = vec![Rational::from(0); 8];
+            for (c, v) in c.iter_mut().zip(v) {
+                *c = Rational::from(v as i64);
+            }
+            g.new(c)
+        };
+        let (x, y, z) = (e(x), e(y), e(z));
+        let graded = (0..4).fold(g.zero(), |acc, k| &acc + &x.grade(k));
+        &(&x * &y) * &z == &x * &(&y * &z)
+            && &x * &(&y + &z) == &(&x * &y) + &(&x * &z)
+            && (&x * &y).reverse() == &y.reverse() * &x.reverse()
+            && (&x * &y).involute() == &x.involute() * &y.involute()
+            && x.wedge(&y).wedge(&z) == x.wedge(&y.wedge(&z))
+            && graded == x
+    }
+}
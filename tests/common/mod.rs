@@ -0,0 +1,61 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use inertia_algebra::*;
+
+/// Detects structure traits by autoref specialization: the impls on `Probe` are
+/// preferred when they apply, otherwise the blanket impls on `&Probe` are used.
+/// Call the checks as `probe(&x).is_field()` with the traits in scope.
+pub struct Probe<T>(std::marker::PhantomData<T>);
+
+pub fn probe<T>(_: &T) -> &'static Probe<T> {
+    &Probe(std::marker::PhantomData)
+}
+
+pub trait IsField {
+    fn is_field(&self) -> bool;
+}
+
+impl<T: AbstractField> IsField for Probe<T> {
+    fn is_field(&self) -> bool { true }
+}
+
+pub trait NotField {
+    fn is_field(&self) -> bool;
+}
+
+impl<T> NotField for &Probe<T> {
+    fn is_field(&self) -> bool { false }
+}
+
+pub trait IsDivisible {
+    fn is_divisible(&self) -> bool;
+}
+
+impl<T: AbstractQuasigroup<Multiplicative>> IsDivisible for Probe<T> {
+    fn is_divisible(&self) -> bool { true }
+}
+
+pub trait NotDivisible {
+    fn is_divisible(&self) -> bool;
+}
+
+impl<T> NotDivisible for &Probe<T> {
+    fn is_divisible(&self) -> bool { false }
+}
+
+pub trait IsRing {
+    fn is_ring(&self) -> bool;
+}
+
+impl<T: AbstractNCRing> IsRing for Probe<T> {
+    fn is_ring(&self) -> bool { true }
+}
+
+pub trait NotRing {
+    fn is_ring(&self) -> bool;
+}
+
+impl<T> NotRing for &Probe<T> {
+    fn is_ring(&self) -> bool { false }
+}
@@ -0,0 +1,157 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn gf(p: i64) -> QuotientField<IntegerRing> {
+    QuotientRing::init(&IntegerRing::init(), &p).as_field().unwrap()
+}
+
+fn q(n: i64, d: i64) -> Rational {
+    Rational::new(n, d)
+}
+
+/// All points of `y^2 = x^3 + a x + b` over `GF(p)`, by brute force.
+fn points(e: &EllipticCurve<QuotientField<IntegerRing>>, p: i64) -> Vec<EllipticCurvePoint<QuotientField<IntegerRing>>> {
+    let f = e.base_field();
+    let mut res = vec![e.infinity()];
+    for x in 0..p {
+        for y in 0..p {
+            if let Some(pt) = e.point(&f.new(x), &f.new(y)) {
+                res.push(pt);
+            }
+        }
+    }
+    res
+}
+
+#[test]
+fn rational_points() {
+    // y^2 = x^3 - 2 has the point (3, 5) of infinite order.
+    let qq = RationalField::init();
+    let e = EllipticCurve::init(&qq, &q(0, 1), &q(-2, 1));
+    assert!(e.is_additive_group_abelian());
+    assert_eq!(e.discriminant(), q(-1728, 1));
+    assert_eq!(e.j_invariant(), q(0, 1));
+    assert_eq!(format!("{}", e), "y^2 = x^3 + 0*x + -2");
+
+    let p = e.new((q(3, 1), q(5, 1)));
+    assert!(e.point(&q(3, 1), &q(4, 1)).is_none());
+    let p2 = &p + &p;
+    assert_eq!(p2.affine(), Some((q(129, 100), q(-383, 1000))));
+    assert_eq!(p2, p.double());
+    assert_eq!(p.scalar_mul(2), p2);
+    assert_eq!(format!("{}", p2), "(129/100 : -383/1000 : 1)");
+    assert!((&p + &(-&p)).is_infinity());
+    assert_eq!(format!("{}", e.infinity()), "(0 : 1 : 0)");
+    assert_eq!(p.scalar_mul(-2), -&p2);
+    assert_eq!(p.scalar_mul(0), e.zero());
+
+    // y^2 = x^3 + 1 has the torsion point (2, 3) of order 6.
+    let e = EllipticCurve::init(&qq, &q(0, 1), &q(1, 1));
+    let t = e.new((q(2, 1), q(3, 1)));
+    assert_eq!(t.scalar_mul(2).affine(), Some((q(0, 1), q(1, 1))));
+    assert_eq!(t.scalar_mul(3).affine(), Some((q(-1, 1), q(0, 1))));
+    let (t2, t3) = (t.scalar_mul(2).normalize(), t.scalar_mul(3).normalize());
+    assert_eq!(&t2 + &t3, -&t);
+    assert!(t3.double().is_infinity());
+}
+
+#[test]
+fn coordinates() {
+    let qq = RationalField::init();
+    let e = EllipticCurve::init(&qq, &q(0, 1), &q(1, 1));
+    let p = e.new((q(2, 1), q(3, 1)));
+    let p3 = p.double();
+    assert_ne!(p3.jacobian().2, &q(1, 1));
+
+    let (x, y, z) = p3.jacobian();
+    assert_eq!(e.from_jacobian(x, y, z), Some(p3.clone()));
+    let (x, y, z) = p3.projective();
+    assert_eq!(e.from_projective(&x, &y, &z), Some(p3.clone()));
+    let n = p3.normalize();
+    assert_eq!(n.jacobian().2, &q(1, 1));
+    assert_eq!(n, p3);
+
+    // Scaling Jacobian coordinates by (λ^2, λ^3, λ) gives the same point.
+    let (x, y, z) = p3.jacobian();
+    let l = q(2, 3);
+    let (l2, l3) = (l * l, l * l * l);
+    let scaled = e.from_jacobian(&(x * &l2), &(y * &l3), &(z * &l));
+    assert_eq!(scaled, Some(p3.clone()));
+
+    assert!(e.from_jacobian(&q(1, 1), &q(2, 1), &q(1, 1)).is_none());
+    assert_eq!(e.from_jacobian(&q(4, 1), &q(8, 1), &q(0, 1)), Some(e.infinity()));
+    assert_eq!(e.from_projective(&q(0, 1), &q(3, 1), &q(0, 1)), Some(e.infinity()));
+    assert!(e.from_projective(&q(1, 1), &q(1, 1), &q(0, 1)).is_none());
+    assert_eq!(e.infinity().projective(), (q(0, 1), q(1, 1), q(0, 1)));
+    assert_eq!(e.infinity().affine(), None);
+}
+
+#[test]
+#[should_panic]
+fn singular_curve() {
+    // y^2 = x^3 - 3x + 2 = (x - 1)^2 (x + 2) has a node.
+    let qq = RationalField::init();
+    EllipticCurve::init(&qq, &q(-3, 1), &q(2, 1));
+}
+
+#[test]
+#[should_panic(expected = "characteristic two or three")]
+fn characteristic_three() {
+    // The discriminant of y^2 = x^3 + x + 1 is -16 * 31, a unit mod 3.
+    let f = gf(3);
+    EllipticCurve::init(&f, &f.one(), &f.one());
+}
+
+#[test]
+#[should_panic]
+fn point_off_curve() {
+    let qq = RationalField::init();
+    let e = EllipticCurve::init(&qq, &q(0, 1), &q(-2, 1));
+    e.new((q(0, 1), q(0, 1)));
+}
+
+#[test]
+fn finite_field_group_laws() {
+    let p = 13;
+    let f = gf(p);
+    let e = EllipticCurve::init(&f, &f.new(2), &f.new(3));
+    let pts = points(&e, p);
+    // Hasse bound: |#E - (p + 1)| <= 2 sqrt(p).
+    let n = pts.len() as i64;
+    assert!((n - p - 1).pow(2) <= 4 * p);
+
+    for a in &pts {
+        assert!(pts.contains(&-a));
+        assert!(a.scalar_mul(n).is_infinity());
+        for b in &pts {
+            let s = a + b;
+            assert!(pts.contains(&s));
+            assert_eq!(s, b + a);
+            assert_eq!(&s - b, *a);
+            for c in pts.iter().step_by(3) {
+                assert_eq!(&s + c, a + &(b + c));
+            }
+        }
+    }
+}
+
+quickcheck! {
+    fn scalar_mul_is_repeated_addition(x: u8, k: i8) -> bool {
+        let p = 101;
+        let f = gf(p);
+        let e = EllipticCurve::init(&f, &f.new(1), &f.new(7));
+        let pts = points(&e, p);
+        let pt = &pts[x as usize % pts.len()];
+        let mut sum = e.zero();
+        for _ in 0..(k as i64).abs() {
+            sum += pt;
+        }
+        if k < 0 {
+            sum = -sum;
+        }
+        pt.scalar_mul(k as i64) == sum
+    }
+}
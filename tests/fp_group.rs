@@ -0,0 +1,98 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn presentation(names: &[&str], relators: &[&str]) -> FinitelyPresentedGroup {
+    let f = FreeGroup::init(names);
+    let relators: Vec<FreeWord> = relators.iter().map(|&r| f.new(r)).collect();
+    FinitelyPresentedGroup::init(&f, &relators)
+}
+
+#[test]
+fn free_groups() {
+    let f = FreeGroup::init(&["a", "b"]);
+    assert!(f.is_abstract_group(Multiplicative));
+    let [a, b]: [FreeWord; 2] = f.gens().try_into().unwrap();
+    let w = a.operate(&b).operate(&b.two_sided_inverse()).operate(&a);
+    assert_eq!(w, f.new("a^2"));
+    assert_eq!(format!("{}", f.new("a*a*b^-2*a^0*b*a")), "a^2*b^-1*a");
+    assert_eq!(f.new("a*b*b^-1*a^-1"), f.identity());
+    assert_eq!(format!("{}", f.identity()), "1");
+    assert_eq!(f.new("a^2*b^-1*a").len(), 4);
+    assert!(f.identity().is_empty() && !f.new("a").is_empty());
+    assert_eq!(f.new("a^2*b^-1*a").syllables(), vec![(0, 2), (1, -1), (0, 1)]);
+    assert_eq!(f.new("a^2*b^-1*a").exponent_sums(), vec![3, -1]);
+    assert_eq!(f.new("a*b").two_sided_inverse(), f.new("b^-1*a^-1"));
+    assert_eq!(a.conjugate(&b), f.new("b^-1*a*b"));
+    assert!(!f.new("a*b*a^-1").is_cyclically_reduced());
+    assert_eq!(f.power(Multiplicative, &f.new("a*b"), 3), f.new("a*b*a*b*a*b"));
+    assert!(f.new("a*b") != f.new("b*a"));
+}
+
+#[test]
+fn finite_presentations() {
+    let s3 = presentation(&["a", "b"], &["a^2", "b^3", "a*b*a*b"]);
+    assert_eq!(s3.order(), Some(6));
+    assert_eq!(format!("{}", s3), "<a, b | a^2, b^3, a*b*a*b>");
+    assert_eq!(s3.new("a*b*a"), s3.new("b^-1"));
+    assert!(s3.new("b^3").is_identity());
+    assert!(!s3.new("b").is_identity());
+    assert_eq!(s3.new("a*b").order(), Some(2));
+
+    let a5 = presentation(&["a", "b"], &["a^2", "b^3", "a*b*a*b*a*b*a*b*a*b"]);
+    assert_eq!(a5.order(), Some(60));
+    let f = a5.free_group().clone();
+    assert_eq!(a5.index(&[f.new("b")]), Some(20));
+    assert_eq!(a5.index(&[f.new("a"), f.new("b")]), Some(1));
+
+    // The action on the 5 cosets of A4 = <b, a*b*a*b^-1*a> is the natural one.
+    let a4 = [f.new("b"), f.new("a*b^-1*a*b*a")];
+    assert_eq!(a5.index(&a4), Some(5));
+    let perms = a5.permutation_representation(&a4).unwrap();
+    let g = PermutationGroup::init(&SymmetricGroup::init(5), &perms);
+    assert_eq!(g.order(), 60);
+
+    let q8 = presentation(&["i", "j"], &["i^4", "i^2*j^-2", "j^-1*i*j*i"]);
+    assert_eq!(q8.order(), Some(8));
+    assert_eq!(q8.new("i^2"), q8.new("j^2"));
+    assert_eq!(q8.new("i*j"), q8.new("j*i^-1"));
+
+    let psl27 = presentation(&["a", "b"], &[
+        "a^2", "b^3", "a*b*a*b*a*b*a*b*a*b*a*b*a*b",
+        "a^-1*b^-1*a*b*a^-1*b^-1*a*b*a^-1*b^-1*a*b*a^-1*b^-1*a*b",
+    ]);
+    assert_eq!(psl27.order(), Some(168));
+
+    // Redundant and trivializing relators.
+    let c6 = presentation(&["x", "y"], &["x^6", "x^2*y^-1", "x*y*x^-1*y^-1"]);
+    assert_eq!(c6.order(), Some(6));
+    let trivial = presentation(&["x", "y"], &["x^2", "x^3", "y*x^-1"]);
+    assert_eq!(trivial.order(), Some(1));
+    let cyclic = presentation(&["x"], &["x^12"]);
+    assert_eq!(cyclic.order(), Some(12));
+
+    // The enumeration gives up on infinite groups.
+    let z2 = presentation(&["a", "b"], &["a*b*a^-1*b^-1"]);
+    assert_eq!(z2.coset_table(&[], 1000), None);
+    assert_eq!(z2.index(&[z2.free_group().new("a"), z2.free_group().new("b^3")]), Some(3));
+}
+
+quickcheck! {
+    fn regular_representation(x: Vec<(bool, i8)>, y: Vec<(bool, i8)>) -> bool {
+        let a5 = presentation(&["a", "b"], &["a^2", "b^3", "a*b*a*b*a*b*a*b*a*b"]);
+        let f = a5.free_group().clone();
+        let word = |v: &Vec<(bool, i8)>| {
+            let syllables: Vec<(usize, i64)> = v.iter().map(|&(g, e)| (g as usize, e as i64)).collect();
+            a5.new(&f.new(syllables.as_slice()))
+        };
+        let (x, y) = (word(&x), word(&y));
+        let (px, py) = (x.regular_permutation().unwrap(), y.regular_permutation().unwrap());
+        let xy = x.operate(&y);
+        xy.regular_permutation().unwrap() == px.operate(&py)
+            && 60 % x.order().unwrap() == 0
+            && x.operate(&x.two_sided_inverse()).is_identity()
+            && (x == y) == (px == py)
+    }
+}
@@ -0,0 +1,129 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn qxyz(vars: &[&str], ordering: MonomialOrdering) -> SparseMPolyRing<RationalField> {
+    SparseMPolyRing::init(&RationalField::init(), vars.iter().copied(), ordering)
+}
+
+fn poly(r: &SparseMPolyRing<RationalField>, terms: &[(&[u64], i64)]) -> SparseMPoly<RationalField> {
+    r.new(terms.iter().map(|(m, c)| (m.to_vec(), Rational::from(*c))).collect::<Vec<_>>())
+}
+
+#[test]
+fn rational_field() {
+    let q = RationalField::init();
+    assert!(q.is_field());
+
+    let a = q.new((6, -4));
+    assert_eq!(a, Rational::new(-3, 2));
+    assert_eq!(a.numerator(), -3);
+    assert_eq!(a.denominator(), 2);
+    assert_eq!(a + q.new((1, 2)), q.new(-1));
+    assert_eq!(a * a.inv(), q.one());
+    assert_eq!(a / q.new(3), q.new((-1, 2)));
+    assert_eq!(format!("{}", a), "-3/2");
+}
+
+#[test]
+fn grlex_example() {
+    // Cox, Little, O'Shea, Chapter 2, Section 7.
+    let r = qxyz(&["x", "y"], MonomialOrdering::Grlex);
+    let f1 = poly(&r, &[(&[3, 0], 1), (&[1, 1], -2)]);
+    let f2 = poly(&r, &[(&[2, 1], 1), (&[0, 2], -2), (&[1, 0], 1)]);
+
+    let expected = [
+        r.new(vec![(vec![0, 2], Rational::from(1)), (vec![1, 0], Rational::new(-1, 2))]),
+        poly(&r, &[(&[1, 1], 1)]),
+        poly(&r, &[(&[2, 0], 1)]),
+    ];
+    for alg in [GroebnerAlgorithm::Buchberger, GroebnerAlgorithm::F4] {
+        let gb = GroebnerBasis::with_algorithm(&r, &[f1.clone(), f2.clone()], alg);
+        assert_eq!(gb.basis(), &expected[..]);
+        assert!(gb.contains(&f1) && gb.contains(&f2));
+        assert!(!gb.contains(&poly(&r, &[(&[1, 0], 1)])));
+    }
+}
+
+#[test]
+fn lex_elimination() {
+    // x^2 + y + z - 1, x + y^2 + z - 1, x + y + z^2 - 1
+    let r = qxyz(&["x", "y", "z"], MonomialOrdering::Grevlex);
+    let gens = [
+        poly(&r, &[(&[2, 0, 0], 1), (&[0, 1, 0], 1), (&[0, 0, 1], 1), (&[0, 0, 0], -1)]),
+        poly(&r, &[(&[1, 0, 0], 1), (&[0, 2, 0], 1), (&[0, 0, 1], 1), (&[0, 0, 0], -1)]),
+        poly(&r, &[(&[1, 0, 0], 1), (&[0, 1, 0], 1), (&[0, 0, 2], 1), (&[0, 0, 0], -1)]),
+    ];
+    let gb = GroebnerBasis::new(&r, &gens);
+
+    let lex = r.with_ordering(MonomialOrdering::Lex);
+    let z = gb.elimination_ideal(2);
+    assert_eq!(z.ring(), &lex);
+    assert_eq!(z.basis(), &[poly(&lex, &[
+        (&[0, 0, 6], 1), (&[0, 0, 4], -4), (&[0, 0, 3], 4), (&[0, 0, 2], -1)
+    ])]);
+    assert_eq!(gb.elimination_ideal(0).basis(), GroebnerBasis::new(&lex, &gens).basis());
+}
+
+#[test]
+fn trivial_ideals() {
+    let r = qxyz(&["x", "y"], MonomialOrdering::Lex);
+    let x = poly(&r, &[(&[1, 0], 1)]);
+    let y = poly(&r, &[(&[0, 1], 1)]);
+
+    let unit = GroebnerBasis::new(&r, &[&x * &y - r.one(), x.clone()]);
+    assert!(unit.is_unit_ideal());
+    assert!(unit.contains(&y));
+
+    let zero = GroebnerBasis::new(&r, &[r.zero()]);
+    assert!(zero.is_zero_ideal());
+    assert_eq!(zero.reduce(&x), x);
+}
+
+#[test]
+fn buchberger_agrees_with_f4() {
+    // Cyclic 3 and the twisted cubic in every ordering.
+    let orderings = [MonomialOrdering::Lex, MonomialOrdering::Grlex, MonomialOrdering::Grevlex];
+    for ordering in orderings {
+        let r = qxyz(&["x", "y", "z"], ordering);
+        let systems = [
+            vec![
+                poly(&r, &[(&[1, 0, 0], 1), (&[0, 1, 0], 1), (&[0, 0, 1], 1)]),
+                poly(&r, &[(&[1, 1, 0], 1), (&[0, 1, 1], 1), (&[1, 0, 1], 1)]),
+                poly(&r, &[(&[1, 1, 1], 1), (&[0, 0, 0], -1)]),
+            ],
+            vec![
+                poly(&r, &[(&[0, 1, 0], 1), (&[2, 0, 0], -1)]),
+                poly(&r, &[(&[0, 0, 1], 1), (&[3, 0, 0], -1)]),
+                poly(&r, &[(&[0, 2, 0], 2), (&[1, 0, 1], -3), (&[0, 0, 0], 5)]),
+            ],
+        ];
+        for gens in systems.iter() {
+            let b = GroebnerBasis::with_algorithm(&r, gens, GroebnerAlgorithm::Buchberger);
+            let f = GroebnerBasis::with_algorithm(&r, gens, GroebnerAlgorithm::F4);
+            assert_eq!(b.basis(), f.basis());
+            assert!(gens.iter().all(|g| b.contains(g)));
+        }
+    }
+}
+
+fn small_poly(r: &SparseMPolyRing<RationalField>, v: &[(u8, u8, i8)]) -> SparseMPoly<RationalField> {
+    r.new(v.iter().take(4)
+        .map(|&(a, b, c)| (vec![(a % 3) as u64, (b % 3) as u64], Rational::from((c % 5) as i64)))
+        .collect::<Vec<_>>())
+}
+
+quickcheck! {
+    fn ideal_membership(a: Vec<(u8, u8, i8)>, b: Vec<(u8, u8, i8)>, c: Vec<(u8, u8, i8)>) -> bool {
+        let r = qxyz(&["x", "y"], MonomialOrdering::Grevlex);
+        let g1 = poly(&r, &[(&[2, 0], 1), (&[0, 1], -1)]);
+        let g2 = poly(&r, &[(&[1, 1], 1), (&[0, 0], -2)]);
+        let gb = GroebnerBasis::new(&r, &[g1.clone(), g2.clone()]);
+
+        let (a, b, c) = (small_poly(&r, &a), small_poly(&r, &b), small_poly(&r, &c));
+        let f = &(&a * &g1) + &(&b * &g2);
+        gb.contains(&f) && gb.reduce(&(&c + &f)) == gb.reduce(&c)
+    }
+}
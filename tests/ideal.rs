@@ -0,0 +1,113 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn qxy(ordering: MonomialOrdering) -> SparseMPolyRing<RationalField> {
+    SparseMPolyRing::init(&RationalField::init(), ["x", "y"], ordering)
+}
+
+fn poly(r: &SparseMPolyRing<RationalField>, terms: &[([u64; 2], i64)]) -> SparseMPoly<RationalField> {
+    r.new(terms.iter().map(|(m, c)| (m.to_vec(), Rational::from(*c))).collect::<Vec<_>>())
+}
+
+fn ideal(r: &SparseMPolyRing<RationalField>, gens: &[&[([u64; 2], i64)]]) -> MPolyIdeal<RationalField> {
+    let gens: Vec<_> = gens.iter().map(|g| poly(r, g)).collect();
+    MPolyIdeal::new(r, &gens)
+}
+
+#[test]
+fn integer_ideals() {
+    let zz = IntegerRing::init();
+    let i = |n: i64| PrincipalIdeal::new(&zz, &n);
+
+    assert_eq!(i(-4).generator(), &4);
+    assert_eq!(&i(4) + &i(6), i(2));
+    assert_eq!(&i(4) * &i(6), i(24));
+    assert_eq!(i(4).intersection(&i(6)), i(12));
+    assert_eq!(i(12).quotient(&i(8)), i(3));
+    assert_eq!(i(0).quotient(&i(5)), i(0));
+    assert!(i(5).quotient(&i(0)).is_whole_ring());
+
+    assert!(i(6).contains(&-18));
+    assert!(!i(6).contains(&8));
+    assert!(i(2).contains_ideal(&i(6)));
+    assert_eq!(i(7).is_prime(), Some(true));
+    assert_eq!(i(7).is_maximal(), Some(true));
+    assert_eq!(i(6).is_prime(), Some(false));
+    assert_eq!(i(0).is_prime(), Some(true));
+    assert_eq!(i(0).is_maximal(), None);
+    assert!(i(0).is_zero());
+    assert_eq!(i(6).quotient_ring().modulus(), &6);
+}
+
+#[test]
+fn polynomial_ideals() {
+    let r = qxy(MonomialOrdering::Grevlex);
+    let i = ideal(&r, &[&[([2, 0], 1)], &[([0, 1], 1)]]);
+    let j = ideal(&r, &[&[([1, 0], 1)], &[([0, 2], 1)]]);
+
+    assert_eq!(&i + &j, ideal(&r, &[&[([1, 0], 1)], &[([0, 1], 1)]]));
+    assert_eq!(&i * &j, ideal(&r, &[&[([3, 0], 1)], &[([1, 1], 1)], &[([0, 3], 1)]]));
+    assert_eq!(i.intersection(&j), ideal(&r, &[&[([2, 0], 1)], &[([1, 1], 1)], &[([0, 2], 1)]]));
+
+    let x = ideal(&r, &[&[([1, 0], 1)]]);
+    let y = ideal(&r, &[&[([0, 1], 1)]]);
+    assert_eq!(x.intersection(&y), ideal(&r, &[&[([1, 1], 1)]]));
+
+    // (x^2, xy) : (x) = (x, y)
+    let k = ideal(&r, &[&[([2, 0], 1)], &[([1, 1], 1)]]);
+    assert_eq!(k.quotient(&x), &x + &y);
+    assert!(k.quotient(&MPolyIdeal::new(&r, &[])).is_whole_ring());
+
+    // (x^2 - y^2) : (x - y) = (x + y)
+    let d = ideal(&r, &[&[([2, 0], 1), ([0, 2], -1)]]);
+    let e = ideal(&r, &[&[([1, 0], 1), ([0, 1], -1)]]);
+    assert_eq!(d.quotient(&e), ideal(&r, &[&[([1, 0], 1), ([0, 1], 1)]]));
+
+    assert!(i.contains(&poly(&r, &[([3, 0], 2), ([1, 1], -1)])));
+    assert!(!i.contains(&poly(&r, &[([1, 0], 1)])));
+    assert!(j.contains_ideal(&i.intersection(&j)));
+
+    // Equality does not depend on the monomial ordering.
+    let lex = qxy(MonomialOrdering::Lex);
+    assert_eq!(ideal(&lex, &[&[([1, 0], 1)], &[([0, 2], 1)]]), j);
+}
+
+#[test]
+fn prime_and_maximal() {
+    let r = qxy(MonomialOrdering::Grlex);
+    let point = ideal(&r, &[&[([1, 0], 1), ([0, 0], -1)], &[([0, 1], 1), ([0, 0], -2)]]);
+    assert_eq!(point.is_maximal(), Some(true));
+    assert_eq!(point.is_prime(), Some(true));
+
+    let line = ideal(&r, &[&[([1, 0], 1), ([0, 1], -1)]]);
+    assert_eq!(line.is_prime(), Some(true));
+    assert_eq!(line.is_maximal(), Some(false));
+
+    let whole = &point + &ideal(&r, &[&[([1, 0], 1)]]);
+    assert!(whole.is_whole_ring());
+    assert_eq!(whole.is_prime(), Some(false));
+
+    assert_eq!(MPolyIdeal::new(&r, &[]).is_prime(), Some(true));
+    assert_eq!(ideal(&r, &[&[([2, 0], 1)]]).is_prime(), None);
+}
+
+quickcheck! {
+    fn principal_lattice(a: i16, b: i16, c: i16) -> bool {
+        let zz = IntegerRing::init();
+        let (a, b, c) = (
+            PrincipalIdeal::new(&zz, &(a as i64)),
+            PrincipalIdeal::new(&zz, &(b as i64)),
+            PrincipalIdeal::new(&zz, &(c as i64)),
+        );
+        let ab = a.intersection(&b);
+        a.contains_ideal(&ab)
+            && b.contains_ideal(&ab)
+            && (&a + &b).contains_ideal(&a)
+            && (&a * &(&b + &c)) == (&(&a * &b) + &(&a * &c))
+            && ab.contains_ideal(&(&a * &b))
+            && a.quotient(&b).product(&b).intersection(&a) == a.quotient(&b).product(&b)
+    }
+}
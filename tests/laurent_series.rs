@@ -0,0 +1,127 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn qx(prec: u64) -> LaurentSeriesRing<RationalField> {
+    LaurentSeriesRing::init(&RationalField::init(), "x", prec)
+}
+
+fn ints(coeffs: &[i64]) -> Vec<Rational> {
+    coeffs.iter().map(|&c| Rational::from(c)).collect()
+}
+
+#[test]
+fn structure() {
+    let r = qx(6);
+    assert!(r.is_field());
+
+    let x = r.gen();
+    let y = (&x).inv();
+    assert_eq!(y.valuation(), -1);
+    assert_eq!(&x * &y, r.one());
+    assert_eq!(format!("{}", &y + &r.one()), "1*x^-1 + 1 + O(x^5)");
+    assert_eq!(y.derivative(), -(&y * &y));
+}
+
+#[test]
+fn exact_zero() {
+    let r = qx(6);
+    let zero = r.zero();
+    assert_eq!((zero.valuation(), zero.prec()), (i64::MAX, i64::MAX));
+    assert_eq!(format!("{}", zero), "0");
+
+    // Adding the exact zero loses no precision, and high powers of x are nonzero.
+    let x10 = r.series(10, ints(&[1]), i64::MAX);
+    assert!(!x10.is_zero());
+    assert_ne!(x10, zero);
+    assert_eq!(&zero + &x10, x10);
+    assert_eq!((&zero + &x10).valuation(), 10);
+    assert!((&zero * &(&x10).inv()).is_zero());
+    assert_eq!((&zero * &(&x10).inv()).prec(), i64::MAX);
+    assert_eq!(zero.shift(3), zero);
+    assert_eq!(zero.derivative().prec(), i64::MAX);
+    assert_eq!(zero.truncate(4).prec(), 4);
+    let ps = PowerSeriesRing::init(&RationalField::init(), "x", 6);
+    assert_eq!(zero.power_series(&ps), Some(ps.zero()));
+}
+
+#[test]
+fn inversion_and_residues() {
+    let r = qx(8);
+    let x = r.gen();
+
+    // 1/(x^2 - x^3) = x^-2 + x^-1 + 1 + x + ...
+    let f = &(&x * &x) - &(&(&x * &x) * &x);
+    let g = (&f).inv();
+    assert_eq!(g.valuation(), -2);
+    assert_eq!(g.relative_prec(), 8);
+    assert_eq!(g.coefficients(), &ints(&[1; 8])[..]);
+    assert_eq!(g.residue(), Rational::from(1));
+    assert_eq!(&r.one() / &f, g);
+
+    // Inversion keeps the relative precision.
+    let h = r.series(-2, ints(&[1, 1]), 3);
+    assert_eq!(h.relative_prec(), 5);
+    let k = (&h).inv();
+    assert_eq!((k.valuation(), k.prec()), (2, 7));
+    assert_eq!(k.coefficients(), &ints(&[1, -1, 1, -1, 1])[..]);
+    assert_eq!(&h * &k, r.one());
+}
+
+#[test]
+fn precision_tracking() {
+    let r = qx(10);
+    let a = r.series(-1, ints(&[2, 3]), 2);
+    let b = r.series(0, ints(&[1]), 1);
+
+    assert_eq!((&a + &b).prec(), 1);
+    assert_eq!((&a * &b).prec(), 0);
+    assert_eq!((&a * &b).coefficients(), &ints(&[2])[..]);
+    assert_eq!(a.truncate(0).coefficients(), &ints(&[2])[..]);
+    assert_eq!(a.shift(3).valuation(), 2);
+
+    // Cancellation raises the valuation but keeps the absolute precision.
+    let c = &a - &r.series(-1, ints(&[2]), 5);
+    assert_eq!((c.valuation(), c.prec()), (0, 2));
+    assert_eq!(c.coeff(0), Rational::from(3));
+}
+
+#[test]
+fn series_interop() {
+    let q = RationalField::init();
+    let ps = PowerSeriesRing::init(&q, "x", 6);
+    let r = qx(6);
+
+    let e = ps.gen().exp();
+    let f = r.new(&e);
+    assert_eq!(f.prec(), 6);
+    assert_eq!(f.power_series(&ps), Some(e.clone()));
+    assert_eq!((&f).inv().power_series(&ps), Some(e.inverse()));
+    assert_eq!((&r.gen()).inv().power_series(&ps), None);
+
+    let qx = GenericPolyRing::init(&q, "x");
+    let p = qx.new(ints(&[0, 0, 1, 2]));
+    let g = r.new(&p);
+    assert_eq!(g.valuation(), 2);
+    assert_eq!(g.coefficients(), &ints(&[1, 2])[..]);
+}
+
+fn small_series(r: &LaurentSeriesRing<RationalField>, val: i8, v: &[i8]) -> LaurentSeries<RationalField> {
+    let coeffs = std::iter::once(1).chain(v.iter().take(5).map(|&c| (c % 4) as i64));
+    r.series((val % 4) as i64, coeffs.map(Rational::from).collect(), i64::MAX)
+}
+
+quickcheck! {
+    fn field_laws(va: i8, a: Vec<i8>, vb: i8, b: Vec<i8>) -> bool {
+        let r = qx(6);
+        let (a, b) = (small_series(&r, va, &a), small_series(&r, vb, &b));
+        let q = &a / &b;
+        q.valuation() == a.valuation() - b.valuation()
+            && &q * &b == a
+            && (&(&a * &b)).inv() == (&a).inv() * (&b).inv()
+            && &r.zero() + &a == a
+            && (&r.zero() + &a).prec() == a.prec()
+    }
+}
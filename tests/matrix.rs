@@ -0,0 +1,181 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+mod common;
+use common::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn qmat(m: usize, n: usize, entries: &[i64]) -> GenericMatrix<RationalField> {
+    let qq = RationalField::init();
+    GenericMatrixSpace::init(&qq, m as u64, n as u64).new(entries.iter().map(|&x| Rational::new(x, 1)).collect())
+}
+
+fn zmat(m: usize, n: usize, entries: &[i64]) -> GenericMatrix<IntegerRing> {
+    GenericMatrixSpace::init(&IntegerRing::init(), m as u64, n as u64).new(entries.to_vec())
+}
+
+#[test]
+fn echelon_forms() {
+    let a = qmat(3, 4, &[1, 2, 1, 0, 2, 4, 0, 2, 3, 6, 1, 2]);
+    let (r, pivots) = a.rref();
+    assert_eq!(pivots, vec![0, 2]);
+    assert_eq!(r, qmat(3, 4, &[1, 2, 0, 1, 0, 0, 1, -1, 0, 0, 0, 0]));
+    assert_eq!(a.rank(), 2);
+    assert_eq!(a.transpose().rank(), 2);
+
+    let (e, pivots) = zmat(3, 4, &[1, 2, 1, 0, 2, 4, 0, 2, 3, 6, 1, 2]).fraction_free_echelon();
+    assert_eq!(pivots, vec![0, 2]);
+    assert_eq!(e, zmat(3, 4, &[1, 2, 1, 0, 0, 0, -2, 2, 0, 0, 0, 0]));
+    assert_eq!(zmat(2, 3, &[0, 0, 0, 0, 0, 0]).rank_bareiss(), 0);
+}
+
+/// A 6 × 6 integer matrix whose Bareiss elimination multiplies minors to about
+/// `2^66` before dividing, although its determinant needs only 41 bits.
+fn wide_matrix() -> GenericMatrix<IntegerRing> {
+    zmat(6, 6, &[
+        -18, -62, 1, 66, -88, -82,
+        37, -76, -7, 49, -86, 29,
+        -46, -91, -78, 11, 7, -83,
+        -39, -77, 41, 8, -85, 44,
+        -69, -43, 61, 60, 49, -85,
+        47, 49, 1, -88, -44, -89,
+    ])
+}
+
+#[test]
+fn bareiss_intermediates_do_not_overflow() {
+    let a = wide_matrix();
+    assert_eq!(a.det_bareiss(), -1583028252904);
+    assert_eq!(a.transpose().det_bareiss(), -1583028252904);
+    assert_eq!(a.rank_bareiss(), 6);
+    let (e, pivots) = a.fraction_free_echelon();
+    assert_eq!(pivots, (0..6).collect::<Vec<_>>());
+    assert_eq!(*e.entry(5, 5), -1583028252904);
+    assert_eq!(a.inverse_bareiss(), None);
+}
+
+#[test]
+fn solving() {
+    let a = qmat(3, 3, &[2, 1, -1, -3, -1, 2, -2, 1, 2]);
+    let b = qmat(3, 1, &[8, -11, -3]);
+    assert_eq!(a.solve(&b), Some(qmat(3, 1, &[2, 3, -1])));
+    let x = a.solve(&a.parent().scalar(&Rational::from(1))).unwrap();
+    assert_eq!(Some(x), a.inverse());
+    assert_eq!(a.det(), Rational::from(-1));
+    assert_eq!(qmat(0, 0, &[]).inverse(), Some(qmat(0, 0, &[])));
+    assert_eq!(qmat(2, 2, &[0, 1, 1, 0]).det(), Rational::from(-1));
+
+    // A singular system with a one-dimensional nullspace.
+    let a = qmat(3, 3, &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(a.det(), Rational::new(0, 1));
+    assert_eq!(a.solve(&qmat(3, 1, &[1, 0, 0])), None);
+    let b = qmat(3, 2, &[6, 1, 15, 1, 24, 1]);
+    let x = a.solve(&b).unwrap();
+    assert_eq!(a.mul_matrix(&x), b);
+    let k = a.nullspace();
+    assert_eq!(k, qmat(3, 1, &[1, -2, 1]));
+    assert!(a.mul_matrix(&k).is_zero());
+
+    let id = qmat(2, 2, &[1, 0, 0, 1]);
+    assert_eq!(id.nullspace().ncols(), 0);
+    assert_eq!(qmat(2, 3, &[0, 0, 0, 0, 0, 0]).nullspace(), qmat(3, 3, &[1, 0, 0, 0, 1, 0, 0, 0, 1]));
+}
+
+#[test]
+fn determinants_over_rings() {
+    // Vandermonde determinant ∏_{i < j} (x_j - x_i).
+    let xs: [i64; 5] = [2, 3, 5, 7, 11];
+    let entries: Vec<i64> = xs.iter().flat_map(|&x| (0..5).map(move |k| x.pow(k))).collect();
+    let v = zmat(5, 5, &entries);
+    let mut expected = 1;
+    for i in 0..5 {
+        for j in i + 1..5 {
+            expected *= xs[j] - xs[i];
+        }
+    }
+    assert_eq!(v.det_bareiss(), expected);
+    assert_eq!(zmat(3, 3, &[0, 1, 0, 1, 0, 0, 0, 0, 1]).det_bareiss(), -1);
+    assert_eq!(zmat(2, 2, &[1, 2, 2, 4]).det_bareiss(), 0);
+    assert_eq!(zmat(0, 0, &[]).det_bareiss(), 1);
+
+    // The characteristic polynomial det(x I - A) over Q[x].
+    let qq = RationalField::init();
+    let r = GenericPolyRing::init(&qq, "x");
+    let c = |x: i64| r.new(vec![Rational::new(x, 1)]);
+    let x = r.gen();
+    let m = GenericMatrixSpace::init(&r, 2u64, 2u64).new(vec![&x - &c(1), -c(2), -c(3), &x - &c(4)]);
+    assert_eq!(m.det_bareiss(), r.new(vec![Rational::new(-2, 1), Rational::new(-5, 1), Rational::new(1, 1)]));
+
+    // Unimodular matrices are invertible over Z, others only over Q.
+    let u = zmat(3, 3, &[2, 3, 1, 1, 2, 1, 1, 1, 1]);
+    assert_eq!(u.det_bareiss(), 1);
+    let v = u.inverse_bareiss().unwrap();
+    assert_eq!(u.mul_matrix(&v), u.parent().scalar(&1));
+    let p = zmat(2, 2, &[0, 1, 1, 0]);
+    assert_eq!(p.inverse_bareiss(), Some(p.clone()));
+    assert_eq!(zmat(2, 2, &[2, 0, 0, 1]).inverse_bareiss(), None);
+    assert_eq!(zmat(2, 2, &[1, 2, 2, 4]).inverse_bareiss(), None);
+}
+
+#[test]
+fn matrix_algebras() {
+    let zz = IntegerRing::init();
+    let m = MatrixAlgebra::init(&zz, 2);
+    assert!(probe(&m).is_ring());
+    assert!(!probe(m.matrix_space()).is_ring());
+    assert!(!probe(&GenericMatrixSpace::init(&zz, 2u64, 3u64)).is_ring());
+
+    let a = m.new(vec![1, 2, 3, 4]);
+    let b = m.new(zmat(2, 2, &[0, 1, 1, 0]));
+    assert_eq!(&a * &b, m.new(vec![2, 1, 4, 3]));
+    assert_ne!(&a * &b, &b * &a);
+    assert_eq!(a.matrix().mul_matrix(b.matrix()), zmat(2, 2, &[2, 1, 4, 3]));
+    assert!((&b * &b).is_one());
+    assert_eq!(&a + &m.embed_base(&2), m.new(vec![3, 2, 3, 6]));
+    assert_eq!(m.one(), m.embed_base(&1));
+    assert!((&a - &a).is_zero());
+}
+
+#[test]
+#[should_panic(expected = "wrong matrix dimensions")]
+fn matrix_algebra_rejects_other_shapes() {
+    let m = MatrixAlgebra::init(&IntegerRing::init(), 2);
+    m.new(zmat(2, 3, &[1, 2, 3, 4, 5, 6]));
+}
+
+fn square(entries: &[i8], n: usize) -> Vec<i64> {
+    (0..n * n).map(|k| entries.get(k).map_or(0, |&x| x as i64 % 10)).collect()
+}
+
+quickcheck! {
+    fn bareiss_agrees_with_gaussian_elimination(entries: Vec<i8>, n: u8) -> bool {
+        let n = n as usize % 5;
+        let e = square(&entries, n);
+        let z = zmat(n, n, &e);
+        let q = qmat(n, n, &e);
+        let inverse = q.inverse().is_none_or(|b| b.mul_matrix(&q) == q.parent().scalar(&Rational::from(1)));
+        Rational::new(z.det_bareiss(), 1) == q.det() && z.rank_bareiss() == q.rank()
+            && q.inverse().is_some() != q.det().is_zero() && inverse
+    }
+
+    fn solutions_and_nullspace(entries: Vec<i8>, rhs: Vec<i8>) -> bool {
+        let a = qmat(3, 4, &square(&entries, 4)[..12]);
+        let b = qmat(3, 1, &square(&rhs, 2)[..3]);
+        let k = a.nullspace();
+        let solved = a.solve(&b).is_none_or(|x| a.mul_matrix(&x) == b);
+        solved && k.ncols() == 4 - a.rank() && a.mul_matrix(&k).is_zero() && k.rank() == k.ncols()
+    }
+
+    fn inverse_over_integers(entries: Vec<i8>) -> bool {
+        let a = zmat(3, 3, &square(&entries, 3));
+        match a.inverse_bareiss() {
+            Some(b) => {
+                let id = a.parent().scalar(&1);
+                a.mul_matrix(&b) == id && b.mul_matrix(&a) == id
+            }
+            None => a.det_bareiss().abs() != 1,
+        }
+    }
+}
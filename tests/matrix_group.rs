@@ -0,0 +1,142 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+fn gf(p: i64) -> QuotientField<IntegerRing> {
+    QuotientRing::init(&IntegerRing::init(), &p).as_field().unwrap()
+}
+
+fn gf4() -> QuotientField<GenericPolyRing<QuotientField<IntegerRing>>> {
+    let f2 = gf(2);
+    let r = GenericPolyRing::init(&f2, "x");
+    let f = r.new(vec![f2.new(1), f2.new(1), f2.new(1)]);
+    QuotientRing::init(&r, &f).as_field_unchecked()
+}
+
+#[test]
+fn order_formulas() {
+    let f2 = gf(2);
+    let f3 = gf(3);
+    assert_eq!(GeneralLinearGroup::init(&f2, 2).order(), Some(6));
+    assert_eq!(GeneralLinearGroup::init(&f3, 2).order(), Some(48));
+    assert_eq!(SpecialLinearGroup::init(&f3, 2).order(), Some(24));
+    assert_eq!(GeneralLinearGroup::init(&f2, 3).order(), Some(168));
+    let q = 7u128;
+    let gl47 = (q.pow(4) - 1) * (q.pow(4) - q) * (q.pow(4) - q.pow(2)) * (q.pow(4) - q.pow(3));
+    assert_eq!(GeneralLinearGroup::init(&gf(7), 4).order(), Some(gl47));
+    assert_eq!(SpecialLinearGroup::init(&gf(7), 4).order(), Some(gl47 / 6));
+    assert_eq!(GeneralLinearGroup::init(&gf(101), 40).order(), None);
+    assert_eq!(OrthogonalGroup::init(&f2, 2).order(), None);
+    assert_eq!(GeneralLinearGroup::init(&gf4(), 2).order(), Some(180));
+
+    // Check the formulas against enumeration of all matrices.
+    let count = |order: Option<u128>, elements: usize| assert_eq!(order, Some(elements as u128));
+    for (field, n) in [(gf(2), 2), (gf(2), 3), (gf(3), 2), (gf(5), 2)] {
+        let gl = GeneralLinearGroup::init(&field, n);
+        let sl = SpecialLinearGroup::init(&field, n);
+        let o = OrthogonalGroup::init(&field, n);
+        count(gl.order(), gl.cardinality());
+        count(sl.order(), sl.cardinality());
+        if field.cardinality() % 2 == 1 {
+            count(o.order(), o.cardinality());
+        }
+    }
+    let o3 = OrthogonalGroup::init(&f3, 3);
+    assert_eq!(o3.order(), Some(48));
+    count(o3.order(), o3.cardinality());
+    let k = gf4();
+    assert_eq!(k.cardinality(), 4);
+    count(SpecialLinearGroup::init(&k, 2).order(), SpecialLinearGroup::init(&k, 2).cardinality());
+}
+
+#[test]
+fn exceptional_isomorphisms() {
+    let f2 = gf(2);
+    let f3 = gf(3);
+    let gl22 = GeneralLinearGroup::init(&f2, 2);
+    assert!(gl22.is_abstract_group(Multiplicative));
+    assert!(is_isomorphic(Multiplicative, &gl22, &SymmetricGroup::init(3)));
+
+    // SL(2, 3) is not S4, but its quotient by the center {±1} is A4.
+    let sl23 = SpecialLinearGroup::init(&f3, 2);
+    assert!(!is_isomorphic(Multiplicative, &sl23, &SymmetricGroup::init(4)));
+    let minus_one = sl23.new(vec![f3.new(-1), f3.new(0), f3.new(0), f3.new(-1)]);
+    let center = Subgroup::init(Multiplicative, &sl23, &[minus_one]);
+    assert!(center.is_normal());
+    let psl23 = center.quotient();
+    assert!(is_isomorphic(Multiplicative, &psl23, &AlternatingGroup::init(4)));
+
+    // SL(2, 4) is A5.
+    let sl24 = SpecialLinearGroup::init(&gf4(), 2);
+    let f = find_isomorphism(Multiplicative, &sl24, &AlternatingGroup::init(5)).unwrap();
+    for a in sl24.elements().iter().step_by(7) {
+        for b in sl24.elements().iter().step_by(5) {
+            assert!(f.prop_preserves_operation((a.clone(), b.clone())));
+        }
+    }
+}
+
+#[test]
+fn matrices_over_q() {
+    let qq = RationalField::init();
+    let r = |n, d| Rational::new(n, d);
+    let gl = GeneralLinearGroup::init(&qq, 2);
+    let a = gl.new(vec![r(1, 1), r(2, 1), r(3, 1), r(4, 1)]);
+    assert_eq!(a.det(), r(-2, 1));
+    let b = a.two_sided_inverse();
+    assert_eq!(b.matrix().entries(), &[r(-2, 1), r(1, 1), r(3, 2), r(-1, 2)]);
+    assert!(a.operate(&b).is_identity());
+    assert!(!gl.contains(&gl.matrix_space().new(vec![r(1, 1), r(2, 1), r(2, 1), r(4, 1)])));
+
+    let sl = SpecialLinearGroup::init(&qq, 3);
+    assert!(sl.is_abstract_group(Multiplicative));
+    let u = sl.new(vec![
+        r(1, 1), r(5, 1), r(0, 1),
+        r(0, 1), r(1, 1), r(-1, 3),
+        r(0, 1), r(0, 1), r(1, 1),
+    ]);
+    let v = sl.new(vec![
+        r(2, 1), r(0, 1), r(0, 1),
+        r(1, 1), r(1, 2), r(0, 1),
+        r(7, 1), r(0, 1), r(1, 1),
+    ]);
+    assert!(sl.contains(u.operate(&v).matrix()));
+    assert_eq!(u.operate(&v).two_sided_inverse(), v.two_sided_inverse().operate(&u.two_sided_inverse()));
+    assert_eq!(*u.entry(1, 2), r(-1, 3));
+
+    let o = OrthogonalGroup::init(&qq, 2);
+    let rot = o.new(vec![r(3, 5), r(-4, 5), r(4, 5), r(3, 5)]);
+    assert_eq!(rot.two_sided_inverse().matrix(), &rot.matrix().transpose());
+    assert!(!o.contains(a.matrix()));
+}
+
+#[test]
+#[should_panic]
+fn not_special() {
+    let qq = RationalField::init();
+    SpecialLinearGroup::init(&qq, 2).new(vec![Rational::new(2, 1), qq.zero(), qq.zero(), qq.one()]);
+}
+
+#[test]
+fn random_elements() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let f5 = gf(5);
+    let gl = GeneralLinearGroup::init(&f5, 3);
+    let sl = SpecialLinearGroup::init(&f5, 3);
+    for _ in 0..50 {
+        let g = gl.random_element(&mut rng);
+        assert!(!g.det().is_zero());
+        assert!(g.operate(&g.two_sided_inverse()).is_identity());
+        let s = sl.random_element(&mut rng);
+        assert!(s.det().is_one());
+        assert!(sl.contains(s.matrix()));
+    }
+    let sl24 = SpecialLinearGroup::init(&gf4(), 2);
+    let mut seen = std::collections::HashSet::new();
+    for _ in 0..2000 {
+        seen.insert(sl24.random_element(&mut rng));
+    }
+    assert_eq!(seen.len(), 60);
+}
@@ -0,0 +1,150 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn zn(n: i64) -> QuotientRing<IntegerRing> {
+    QuotientRing::init(&IntegerRing::init(), &n)
+}
+
+/// Check that `f` is a homomorphism on every pair of elements of its domain.
+fn is_homomorphism<D, C, F>(f: &F) -> bool
+where
+    D: AbstractGroup<Multiplicative> + Finite,
+    C: AbstractGroup<Multiplicative>,
+    <D as Parent>::Element: Eq,
+    <C as Parent>::Element: Eq,
+    F: GroupHomomorphism<D, C, Multiplicative>,
+{
+    let elems = f.domain().elements();
+    f.prop_preserves_identity()
+        && elems.iter().all(|a| f.prop_preserves_inverse((a.clone(),)))
+        && elems.iter().all(|a| elems.iter().all(|b| f.prop_preserves_operation((a.clone(), b.clone()))))
+}
+
+#[test]
+fn group_homomorphisms() {
+    let s4 = SymmetricGroup::init(4);
+    let c2 = CyclicGroup::init(2);
+    let sign = FnMorphism::group_homomorphism(Multiplicative, &s4, &c2, |p: &Permutation| {
+        c2.new(if p.is_even() { 0 } else { 1 })
+    });
+    assert!(is_homomorphism(&sign));
+    let a4 = sign.kernel();
+    assert_eq!(a4.order(), 12);
+    assert!(a4.is_normal());
+    assert!(a4.elements().iter().all(|p| p.is_even()));
+    assert_eq!(sign.image().order(), 2);
+    assert!(sign.is_surjective());
+    assert!(!sign.is_injective());
+
+    let c12 = CyclicGroup::init(12);
+    let c4 = CyclicGroup::init(4);
+    let f = FnMorphism::group_homomorphism(Multiplicative, &c12, &c4, |x: &CyclicElement| {
+        c4.new(x.exponent() as i64)
+    });
+    let g = FnMorphism::group_homomorphism(Multiplicative, &c4, &c2, |x: &CyclicElement| {
+        c2.new(x.exponent() as i64)
+    });
+    assert!(is_homomorphism(&f) && is_homomorphism(&g));
+    assert_eq!(f.kernel().order(), 3);
+    let h = f.clone().then(g.clone());
+    assert!(is_homomorphism(&h));
+    assert_eq!(h.kernel().order(), 6);
+    assert_eq!(h.apply(&c12.new(5)), c2.new(1));
+    assert_eq!(h.domain(), &c12);
+    assert_eq!(h.codomain(), &c2);
+    assert!(f.kernel().is_subgroup_of(&h.kernel()));
+
+    // Multiplication by 5 is an automorphism of C12, by 2 is not.
+    let auto = FnMorphism::group_homomorphism(Multiplicative, &c12, &c12, |x: &CyclicElement| {
+        c12.new(5 * x.exponent() as i64)
+    });
+    assert!(is_homomorphism(&auto));
+    assert!(auto.is_isomorphism());
+    let double = FnMorphism::group_homomorphism(Multiplicative, &c12, &c12, |x: &CyclicElement| {
+        c12.new(2 * x.exponent() as i64)
+    });
+    assert!(!double.is_isomorphism());
+    assert_eq!(double.image().order(), 6);
+
+    let id = IdentityMorphism::init(&s4);
+    assert!(is_homomorphism(&id));
+    assert!(GroupHomomorphism::<_, _, Multiplicative>::is_isomorphism(&id));
+    assert_eq!(GroupHomomorphism::<_, _, Multiplicative>::kernel(&id).order(), 1);
+
+    // Squaring is not a homomorphism of a nonabelian group.
+    let square = FnMorphism::group_homomorphism(Multiplicative, &s4, &s4, |p: &Permutation| {
+        p.operate(p)
+    });
+    assert!(!is_homomorphism(&square));
+    let plain = FnMorphism::init(&s4, &s4, |p: &Permutation| p.operate(p));
+    assert_eq!(plain.apply(&s4.new("(0,1,2,3)")), s4.new("(0,2)(1,3)"));
+}
+
+#[test]
+fn projections() {
+    let d6 = DihedralGroup::init(6);
+    let r = d6.rotation();
+    let n = Subgroup::init(Multiplicative, &d6, &[d6.power(Multiplicative, &r, 2)]);
+    let q = n.quotient();
+    let p = FnMorphism::group_homomorphism(Multiplicative, &d6, &q, |x: &DihedralElement| {
+        q.project(x)
+    });
+    assert!(is_homomorphism(&p));
+    assert_eq!(p.kernel(), n);
+    assert!(p.is_surjective());
+    assert_eq!(p.kernel().order() * p.image().order(), d6.cardinality());
+
+    // Forgetting reflections does not respect the relation s r = r^-1 s.
+    let c3 = CyclicGroup::init(3);
+    let f = FnMorphism::group_homomorphism(Multiplicative, &d6, &c3, |x: &DihedralElement| {
+        c3.new(if x.is_reflection() { 0 } else { x.parts().0 as i64 })
+    });
+    assert!(!is_homomorphism(&f));
+}
+
+#[test]
+fn ring_homomorphisms() {
+    let zz = IntegerRing::init();
+    let (z12, z4) = (zn(12), zn(4));
+    let f = FnMorphism::ring_homomorphism(&z12, &z4, |x: &Residue<IntegerRing>| z4.new(*x.lift()));
+    assert!(f.prop_preserves_one());
+    for a in z12.elements() {
+        for b in z12.elements() {
+            assert!(f.prop_preserves_operation((a.clone(), b.clone())));
+            assert!(f.prop_preserves_multiplication((a.clone(), b.clone())));
+        }
+    }
+    let k = f.kernel();
+    assert_eq!(k.order(), 3);
+    assert!(k.contains(&z12.new(8)));
+    assert!(f.is_surjective());
+
+    let reduce = FnMorphism::ring_homomorphism(&zz, &z12, |x: &i64| z12.new(*x));
+    let g = reduce.clone().then(f);
+    assert!(g.prop_preserves_one());
+    assert_eq!(g.apply(&-1), z4.new(3));
+
+    // Z/4 → Z/12, x ↦ 3x preserves addition but not the unit.
+    let h = FnMorphism::ring_homomorphism(&z4, &z12, |x: &Residue<IntegerRing>| z12.new(3 * *x.lift()));
+    assert!(!h.prop_preserves_one());
+    assert!(z4.elements().iter().all(|a| h.prop_preserves_operation((a.clone(), z4.new(3)))));
+    assert!(!h.prop_preserves_multiplication((z4.new(1), z4.new(1))));
+
+    let id = IdentityMorphism::init(&z12);
+    assert!(RingHomomorphism::prop_preserves_one(&id));
+    assert!(RingHomomorphism::prop_preserves_multiplication(&id, (z12.new(5), z12.new(7))));
+}
+
+quickcheck! {
+    fn reduction_is_a_ring_homomorphism(a: i32, b: i32) -> bool {
+        let z6 = zn(6);
+        let f = FnMorphism::ring_homomorphism(&IntegerRing::init(), &z6, |x: &i64| z6.new(*x));
+        let (a, b) = (a as i64, b as i64);
+        f.prop_preserves_operation((a, b))
+            && f.prop_preserves_multiplication((a, b))
+            && f.prop_preserves_inverse((a,))
+    }
+}
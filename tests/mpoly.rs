@@ -0,0 +1,124 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+use std::cmp::Ordering;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn zxyz(ordering: MonomialOrdering) -> SparseMPolyRing<IntegerRing> {
+    SparseMPolyRing::init(&IntegerRing::init(), ["x", "y", "z"], ordering)
+}
+
+#[test]
+fn monomial_orderings() {
+    use MonomialOrdering::*;
+
+    // x*y^2 vs y^3*z (Cox, Little, O'Shea examples).
+    assert_eq!(Lex.cmp(&[1, 2, 0], &[0, 3, 4]), Ordering::Greater);
+    assert_eq!(Grlex.cmp(&[1, 2, 3], &[3, 2, 0]), Ordering::Greater);
+    assert_eq!(Grlex.cmp(&[1, 2, 4], &[1, 1, 5]), Ordering::Greater);
+    assert_eq!(Grevlex.cmp(&[4, 7, 1], &[4, 2, 3]), Ordering::Greater);
+    assert_eq!(Grevlex.cmp(&[1, 5, 2], &[4, 1, 3]), Ordering::Greater);
+    assert_eq!(Lex.cmp(&[1, 5, 2], &[4, 1, 3]), Ordering::Less);
+}
+
+#[test]
+fn leading_terms_depend_on_ordering() {
+    // f = 4xy^2z + 4z^2 - 5x^3 + 7x^2z^2
+    let terms = vec![(vec![1, 2, 1], 4), (vec![0, 0, 2], 4), (vec![3, 0, 0], -5), (vec![2, 0, 2], 7)];
+
+    let lex = zxyz(MonomialOrdering::Lex);
+    let f = lex.new(terms.clone());
+    assert!(lex.is_ring());
+    assert_eq!(f.leading_monomial(), Some(vec![3, 0, 0]));
+    assert_eq!(f.leading_coefficient(), Some(-5));
+    assert_eq!(f.total_degree(), 4);
+    assert_eq!(f.degree_in(2), 2);
+
+    let grlex = zxyz(MonomialOrdering::Grlex);
+    assert_eq!(grlex.new(&f).leading_monomial(), Some(vec![2, 0, 2]));
+
+    let grevlex = zxyz(MonomialOrdering::Grevlex);
+    let g = grevlex.new(terms);
+    assert_eq!(g.leading_term(), grevlex.monomial(4, &[1, 2, 1]));
+    assert_eq!(g.get_coeff(&[0, 0, 2]), 4);
+    assert_eq!(g.get_coeff(&[0, 0, 1]), 0);
+    assert_eq!(lex.new(&g), f);
+}
+
+#[test]
+fn multiplication_by_terms() {
+    let r = zxyz(MonomialOrdering::Grevlex);
+    let f = r.new(vec![(vec![1, 0, 0], 1), (vec![0, 1, 0], -1)]);
+    let g = f.mul_term(&3, &[0, 2, 1]);
+    assert_eq!(g, r.new(vec![(vec![1, 2, 1], 3), (vec![0, 3, 1], -3)]));
+    assert_eq!(g, &f * &r.monomial(3, &[0, 2, 1]));
+}
+
+#[test]
+#[should_panic(expected = "wrong number of exponents")]
+fn mul_term_checks_exponent_length() {
+    let r = zxyz(MonomialOrdering::Lex);
+    r.gen(0).mul_term(&1, &[1, 1]);
+}
+
+#[test]
+fn partial_evaluation_and_substitution() {
+    let r = zxyz(MonomialOrdering::Grevlex);
+    let (x, y, z) = (r.gen(0), r.gen(1), r.gen(2));
+
+    // f = x^2*y + y*z - 3
+    let f = &(&(&(&x * &x) * &y) + &(&y * &z)) - &Algebra::<IntegerRing>::embed_base(&r, &3);
+    assert_eq!(f.num_terms(), 3);
+
+    // f(x, 2, z) = 2x^2 + 2z - 3
+    let g = f.evaluate_var(1, &2);
+    assert_eq!(g, r.new(vec![(vec![2, 0, 0], 2), (vec![0, 0, 1], 2), (vec![0, 0, 0], -3)]));
+
+    // f(x, x + z, z) = x^3 + x^2*z + x*z + z^2 - 3
+    let h = f.substitute(1, &(&x + &z));
+    assert_eq!(
+        h,
+        r.new(vec![
+            (vec![3, 0, 0], 1),
+            (vec![2, 0, 1], 1),
+            (vec![1, 0, 1], 1),
+            (vec![0, 0, 2], 1),
+            (vec![0, 0, 0], -3),
+        ])
+    );
+    assert_eq!(f.evaluate(&[1, 2, 3][..]), 2 + 6 - 3);
+    assert_eq!(h.evaluate(&[x.clone(), y.clone(), z.clone()][..]), h);
+    assert_eq!(format!("{}", g), "2*x^2 + 2*z + -3");
+
+    // Substituting a constant agrees with partial evaluation.
+    assert_eq!(f.substitute(1, &Algebra::<IntegerRing>::embed_base(&r, &2)), g);
+
+    // In no variables, evaluation into a target gives the constant term.
+    let zz = IntegerRing::init();
+    let r0 = SparseMPolyRing::init(&zz, Vec::<String>::new(), MonomialOrdering::Lex);
+    let mut c = 0;
+    c.assign_evaluate(&r0.new(vec![(vec![], 5)]), &[][..]);
+    assert_eq!(c, 5);
+    c.assign_evaluate(&r0.zero(), &[][..]);
+    assert_eq!(c, 0);
+}
+
+quickcheck! {
+    fn multiplication_agrees_with_evaluation(
+        a: Vec<((u8, u8, u8), i8)>,
+        b: Vec<((u8, u8, u8), i8)>
+    ) -> bool {
+        let r = zxyz(MonomialOrdering::Grevlex);
+        let new = |v: Vec<((u8, u8, u8), i8)>| r.new(
+            v.into_iter()
+                .take(5)
+                .map(|((i, j, k), c)| (vec![u64::from(i % 4), u64::from(j % 4), u64::from(k % 4)], i64::from(c)))
+                .collect::<Vec<_>>()
+        );
+        let (f, g) = (new(a), new(b));
+        let p = [1i64, -1, 2];
+        let h = &f * &g;
+        h == &g * &f && h.evaluate(&p[..]) == f.evaluate(&p[..]) * g.evaluate(&p[..])
+    }
+}
@@ -0,0 +1,203 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn zmat(m: usize, n: usize, entries: &[i64]) -> GenericMatrix<IntegerRing> {
+    GenericMatrixSpace::init(&IntegerRing::init(), m as u64, n as u64).new(entries.to_vec())
+}
+
+fn small_zmat(m: usize, n: usize, xs: &[i8]) -> GenericMatrix<IntegerRing> {
+    let entries: Vec<i64> = (0..m * n).map(|k| xs.get(k).map_or(0, |&x| x as i64 % 10)).collect();
+    zmat(m, n, &entries)
+}
+
+/// An `m × n` matrix with pseudorandom entries in `[-100, 100]`.
+fn random_zmat(m: usize, n: usize, seed: u64) -> GenericMatrix<IntegerRing> {
+    let mut x = seed;
+    let entries: Vec<i64> = (0..m * n)
+        .map(|_| {
+            x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (x >> 33) as i64 % 201 - 100
+        })
+        .collect();
+    zmat(m, n, &entries)
+}
+
+/// Check that `h` is in Hermite normal form with pivots on the diagonal.
+fn is_hermite(h: &GenericMatrix<IntegerRing>) -> bool {
+    let n = h.ncols();
+    (0..h.nrows()).all(|i| {
+        (0..n).all(|j| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => *h.entry(i, j) == 0,
+            std::cmp::Ordering::Equal => *h.entry(i, i) > 0,
+            std::cmp::Ordering::Less => (0..*h.entry(j, j)).contains(h.entry(i, j)),
+        })
+    })
+}
+
+/// Return the entries of `U A V` for `n × n` matrices, computed with 128-bit
+/// intermediates so that large transforms can be checked.
+fn product3(n: usize, u: &GenericMatrix<IntegerRing>, a: &GenericMatrix<IntegerRing>, v: &GenericMatrix<IntegerRing>) -> Vec<i128> {
+    let mul = |x: &[i128], y: &[i128]| -> Vec<i128> {
+        (0..n * n).map(|k| (0..n).map(|l| x[k / n * n + l] * y[l * n + k % n]).sum()).collect()
+    };
+    let wide = |m: &GenericMatrix<IntegerRing>| m.entries().iter().map(|&x| x as i128).collect::<Vec<_>>();
+    mul(&mul(&wide(u), &wide(a)), &wide(v))
+}
+
+fn qx() -> GenericPolyRing<RationalField> {
+    GenericPolyRing::init(&RationalField::init(), "x")
+}
+
+fn poly(r: &GenericPolyRing<RationalField>, coeffs: &[i64]) -> GenericPoly<RationalField> {
+    r.new(coeffs.iter().map(|&c| Rational::new(c, 1)).collect::<Vec<_>>())
+}
+
+/// The characteristic matrix `x I - A` of an integer matrix over `Q[x]`.
+fn char_matrix(r: &GenericPolyRing<RationalField>, n: usize, a: &[i64]) -> GenericMatrix<GenericPolyRing<RationalField>> {
+    let entries = (0..n * n).map(|k| poly(r, &[-a[k], if k / n == k % n { 1 } else { 0 }])).collect();
+    GenericMatrixSpace::init(r, n as u64, n as u64).new(entries)
+}
+
+#[test]
+fn hermite_forms_over_integers() {
+    // Full column rank, computed modulo the determinant.
+    let a = zmat(2, 2, &[1, 2, 3, 4]);
+    assert_eq!(a.hermite_form(), zmat(2, 2, &[1, 0, 0, 2]));
+    let a = zmat(3, 2, &[4, 0, 0, 6, 2, 3]);
+    assert_eq!(a.hermite_form(), zmat(3, 2, &[2, 3, 0, 6, 0, 0]));
+    let a = zmat(2, 2, &[-3, 5, 7, 9]);
+    assert_eq!(a.hermite_form(), zmat(2, 2, &[1, 19, 0, 62]));
+
+    // Rank deficient.
+    let a = zmat(2, 2, &[2, 4, 3, 6]);
+    let (h, u) = a.hermite_form_with_transform();
+    assert_eq!(h, zmat(2, 2, &[1, 2, 0, 0]));
+    assert_eq!(a.hermite_form(), h);
+    assert_eq!(u.mul_matrix(&a), h);
+    assert!(u.det_bareiss().abs() == 1);
+    assert_eq!(zmat(2, 3, &[0; 6]).hermite_form(), zmat(2, 3, &[0; 6]));
+}
+
+#[test]
+fn smith_forms_over_integers() {
+    let a = zmat(2, 2, &[4, 0, 0, 6]);
+    assert_eq!(a.smith_form(), zmat(2, 2, &[2, 0, 0, 12]));
+    assert_eq!(a.invariant_factors(), vec![2, 12]);
+    assert_eq!(zmat(2, 2, &[1, 2, 3, 4]).invariant_factors(), vec![1, 2]);
+    assert_eq!(zmat(3, 2, &[4, 0, 0, 6, 2, 3]).invariant_factors(), vec![1, 12]);
+
+    let a = zmat(2, 3, &[2, 4, 6, 4, 8, 12]);
+    let (s, u, v) = a.smith_form_with_transform();
+    assert_eq!(s, zmat(2, 3, &[2, 0, 0, 0, 0, 0]));
+    assert_eq!(u.mul_matrix(&a).mul_matrix(&v), s);
+    assert_eq!(a.smith_form(), s);
+
+    // The abelian group <a, b, c | 2a + 4b + 4c, -6a + 6b + 12c, 10a - 4b - 16c>.
+    let rel = zmat(3, 3, &[2, 4, 4, -6, 6, 12, 10, -4, -16]);
+    let d = rel.det_bareiss().abs();
+    let inv = rel.invariant_factors();
+    assert_eq!(inv.iter().product::<i64>(), d);
+    assert!(inv.windows(2).all(|w| w[1] % w[0] == 0));
+
+    // Z^3 modulo a rank two relation module has a free part.
+    let rel = zmat(2, 3, &[3, 0, 0, 0, 5, 0]);
+    assert_eq!(rel.invariant_factors(), vec![1, 15]);
+}
+
+#[test]
+fn normal_forms_over_polynomials() {
+    let r = qx();
+    // A Jordan block has a single nontrivial invariant factor, its minimal polynomial.
+    let m = char_matrix(&r, 2, &[2, 1, 0, 2]);
+    assert_eq!(m.invariant_factors(), vec![r.one(), poly(&r, &[4, -4, 1])]);
+    let m = char_matrix(&r, 2, &[2, 0, 0, 2]);
+    assert_eq!(m.invariant_factors(), vec![poly(&r, &[-2, 1]); 2]);
+
+    // The companion matrix of x^3 - 2x + 1.
+    let m = char_matrix(&r, 3, &[0, 0, -1, 1, 0, 2, 0, 1, 0]);
+    let (s, u, v) = m.smith_form_with_transform();
+    assert_eq!(u.mul_matrix(&m).mul_matrix(&v), s);
+    assert_eq!(m.smith_form(), s);
+    assert_eq!(m.invariant_factors(), vec![r.one(), r.one(), poly(&r, &[1, -2, 0, 1])]);
+
+    let (h, u) = m.hermite_form_with_transform();
+    assert_eq!(u.mul_matrix(&m), h);
+    assert_eq!(m.hermite_form(), h);
+    assert_eq!(h.entry(2, 2), &poly(&r, &[1, -2, 0, 1]));
+}
+
+#[test]
+fn normal_forms_of_larger_matrices() {
+    // Entries in [-100, 100] with a determinant of about 2^40, whose
+    // elimination overflows an i64 without a modulus.
+    let a = zmat(6, 6, &[
+        -18, -62, 1, 66, -88, -82, 37, -76, -7, 49, -86, 29, -46, -91, -78, 11, 7, -83,
+        -39, -77, 41, 8, -85, 44, -69, -43, 61, 60, 49, -85, 47, 49, 1, -88, -44, -89,
+    ]);
+    let det = a.det_bareiss();
+    assert_eq!(det, -1583028252904);
+    let (h, u) = a.hermite_form_with_transform();
+    assert!(is_hermite(&h));
+    assert_eq!(a.hermite_form(), h);
+    assert_eq!(u.mul_matrix(&a), h);
+    assert_eq!((0..6).map(|i| h.entry(i, i)).product::<i64>(), det.abs());
+    let (s, u, v) = a.smith_form_with_transform();
+    assert_eq!(a.smith_form(), s);
+    assert_eq!(u.mul_matrix(&a).mul_matrix(&v), s);
+    assert_eq!(a.invariant_factors().iter().product::<i64>(), det.abs());
+
+    for seed in 0..4 {
+        let a = random_zmat(8, 8, seed);
+        let det = a.det_bareiss();
+        let (h, u) = a.hermite_form_with_transform();
+        assert!(is_hermite(&h));
+        assert_eq!(a.hermite_form(), h);
+        assert_eq!(u.mul_matrix(&a), h);
+        assert_eq!((0..8).map(|i| h.entry(i, i)).product::<i64>(), det.abs());
+        let factors = a.invariant_factors();
+        assert_eq!(factors.iter().product::<i64>(), det.abs());
+        if factors[..7].iter().all(|&f| f == 1) {
+            let (s, u, v) = a.smith_form_with_transform();
+            assert_eq!(s, a.smith_form());
+            assert_eq!(product3(8, &u, &a, &v), s.entries().iter().map(|&x| x as i128).collect::<Vec<_>>());
+        }
+
+        // More rows than columns: the lattice determinant divides that of any
+        // eight of the rows.
+        let b = random_zmat(16, 8, seed);
+        let h = b.hermite_form();
+        assert!(is_hermite(&h));
+        assert!((8..16).all(|i| (0..8).all(|j| *h.entry(i, j) == 0)));
+        let top = GenericMatrixSpace::init(&IntegerRing::init(), 8u64, 8u64).new(b.entries()[..64].to_vec());
+        assert_eq!(top.det_bareiss() % (0..8).map(|i| h.entry(i, i)).product::<i64>(), 0);
+        assert_eq!(b.invariant_factors().iter().product::<i64>(), (0..8).map(|i| h.entry(i, i)).product::<i64>());
+    }
+}
+
+quickcheck! {
+    fn hermite_transform(xs: Vec<i8>, shape: u8) -> bool {
+        let (m, n) = [(3, 3), (4, 3), (3, 4), (2, 4)][shape as usize % 4];
+        let a = small_zmat(m, n, &xs);
+        let (h, u) = a.hermite_form_with_transform();
+        u.mul_matrix(&a) == h && u.det_bareiss().abs() == 1 && a.hermite_form() == h
+    }
+
+    fn smith_transform(xs: Vec<i8>, shape: u8) -> bool {
+        let (m, n) = [(3, 3), (4, 3), (3, 4), (2, 4)][shape as usize % 4];
+        let a = small_zmat(m, n, &xs);
+        let (s, u, v) = a.smith_form_with_transform();
+        let diag: Vec<i64> = (0..m.min(n)).map(|i| *s.entry(i, i)).collect();
+        let off_diagonal_zero = (0..m).all(|i| (0..n).all(|j| i == j || *s.entry(i, j) == 0));
+        let divides = diag.windows(2).all(|w| if w[0] == 0 { w[1] == 0 } else { w[1] % w[0] == 0 });
+        u.mul_matrix(&a).mul_matrix(&v) == s
+            && u.det_bareiss().abs() == 1
+            && v.det_bareiss().abs() == 1
+            && off_diagonal_zero
+            && divides
+            && diag.iter().all(|&d| d >= 0)
+            && a.smith_form() == s
+    }
+}
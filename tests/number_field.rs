@@ -0,0 +1,87 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn qpoly(coeffs: &[i64]) -> GenericPoly<RationalField> {
+    let qx = GenericPolyRing::init(&RationalField::init(), "x");
+    qx.new(coeffs.iter().map(|&c| Rational::from(c)).collect())
+}
+
+fn close(a: (f64, f64), b: (f64, f64)) -> bool {
+    (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9
+}
+
+#[test]
+fn quadratic_fields() {
+    let k = NumberField::init(&qpoly(&[-2, 0, 1]), "a");
+    assert!(k.is_field());
+    assert_eq!(k.degree(), 2);
+    let a = k.gen();
+    assert_eq!(&a * &a, k.new(2));
+
+    let x = &k.one() + &a;
+    assert_eq!((&x).inv(), &a - &k.one());
+    assert_eq!(x.norm(), Rational::from(-1));
+    assert_eq!(x.trace(), Rational::from(2));
+    assert_eq!(a.charpoly(), qpoly(&[-2, 0, 1]));
+    assert_eq!(k.new(3).charpoly(), qpoly(&[9, -6, 1]));
+    assert_eq!(k.new(3).minpoly(), qpoly(&[-3, 1]));
+    assert_eq!(format!("{}", x), "1*a + 1");
+
+    assert_eq!(k.signature(), (2, 0));
+    let s = 2f64.sqrt();
+    let emb = x.complex_embeddings();
+    assert!(close(emb[0], (1.0 - s, 0.0)) && close(emb[1], (1.0 + s, 0.0)));
+
+    // Defining polynomials are made monic.
+    let k2 = NumberField::init(&qpoly(&[2, 0, 2]), "i");
+    assert_eq!(k2.defining_polynomial(), &qpoly(&[1, 0, 1]));
+    assert_eq!(k2.signature(), (0, 1));
+    let emb = k2.gen().complex_embeddings();
+    assert!(close(emb[0], (0.0, 1.0)) && close(emb[1], (0.0, -1.0)));
+}
+
+#[test]
+fn cubic_field() {
+    let k = NumberField::init(&qpoly(&[-2, 0, 0, 1]), "a");
+    let a = k.gen();
+    assert_eq!(a.norm(), Rational::from(2));
+    assert_eq!(a.trace(), Rational::from(0));
+    assert_eq!((&a).inv(), &(&a * &a) / &k.new(2));
+    assert_eq!((&a + &k.one()).charpoly(), qpoly(&[-3, 3, -3, 1]));
+    assert_eq!((&a * &a).minpoly(), qpoly(&[-4, 0, 0, 1]));
+    assert_eq!(a.coefficients(), vec![Rational::from(0), Rational::from(1), Rational::from(0)]);
+
+    let m = a.representation_matrix();
+    assert_eq!(m.entry(0, 2), &Rational::from(2));
+    assert_eq!(m.entry(1, 0), &Rational::from(1));
+
+    assert_eq!(k.signature(), (1, 1));
+    let c = 2f64.cbrt();
+    let roots = k.complex_roots();
+    assert!(close(roots[0], (c, 0.0)));
+    assert!(close(roots[1], (-c / 2.0, c * 3f64.sqrt() / 2.0)));
+    assert!(close(roots[2], (-c / 2.0, -c * 3f64.sqrt() / 2.0)));
+
+    // Rational polynomials evaluate at the generator.
+    assert_eq!(k.new(qpoly(&[1, 0, 0, 0, 1])), &k.one() + &(&a * &k.new(2)));
+}
+
+quickcheck! {
+    fn field_laws(x: (i8, i8, i8), y: (i8, i8, i8)) -> bool {
+        let k = NumberField::init(&qpoly(&[-2, 0, 0, 1]), "a");
+        let e = |x: (i8, i8, i8)| k.new(vec![
+            Rational::from(x.0 as i64),
+            Rational::from(x.1 as i64),
+            Rational::from(x.2 as i64),
+        ]);
+        let (x, y) = (e(x), e(y));
+        let xy = &x * &y;
+        xy.norm() == x.norm() * y.norm()
+            && (&x + &y).trace() == x.trace() + y.trace()
+            && x.minpoly().evaluate(&x).is_zero()
+            && (x.is_zero() || &x * &(&x).inv() == k.one())
+    }
+}
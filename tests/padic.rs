@@ -0,0 +1,123 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+#[test]
+fn structure() {
+    let zp = PAdicIntegers::init(5, 6);
+    let qp = zp.fraction_field();
+    assert!(zp.is_ring());
+    assert!(qp.is_field());
+    assert_eq!(qp.integers(), zp);
+    assert_eq!(qp.prime(), 5);
+    assert_eq!(qp.precision_cap(), 6);
+
+    let x = zp.new(-1);
+    assert_eq!(x.digits(), vec![4; 6]);
+    assert_eq!(format!("{}", qp.new(10)), "2*5 + O(5^7)");
+    assert_eq!(format!("{}", qp.new(Rational::new(1, 25))), "1*5^-2 + O(5^4)");
+
+    // 1/3 is a 5-adic integer.
+    let third = zp.new(Rational::new(1, 3));
+    assert_eq!(&third * &zp.new(3), zp.one());
+    assert_eq!(zp.new(3).inverse(), Some(third));
+    assert_eq!(zp.new(5).inverse(), None);
+
+    let fifth = qp.new(Rational::new(2, 5));
+    assert_eq!(fifth.valuation(), -1);
+
+    // Integers are exact, so products and quotients of zero must not overflow.
+    let zero = qp.new(0);
+    assert!((&zero * &zero).is_zero());
+    assert!((&zero / &fifth).is_zero());
+    assert_eq!(fifth.unit_part(), 2);
+    assert_eq!(&fifth * &qp.new(5), qp.new(2));
+    assert_eq!((&qp.new(5)).inv(), qp.new(Rational::new(1, 5)));
+    assert_eq!(fifth.lift(), Rational::new(2, 5));
+    assert_eq!(qp.new(&zp.new(7)), qp.new(7));
+    assert_eq!(qp.uniformizer_pow(-2), qp.new(Rational::new(1, 25)));
+}
+
+#[test]
+fn precision_tracking() {
+    let zp = PAdicIntegers::init(5, 6);
+    let a = zp.new(25);
+    assert_eq!((a.valuation(), a.prec(), a.relative_prec()), (2, 8, 6));
+    let b = zp.one();
+    assert_eq!((&a + &b).prec(), 6);
+    assert_eq!((&a * &a).prec(), 10);
+
+    // Cancellation loses relative precision.
+    let c = &zp.new(1 + 125) - &b;
+    assert_eq!(c.valuation(), 3);
+    assert_eq!(c.relative_prec(), 3);
+    assert_eq!(c, zp.new(125));
+
+    // Numbers agreeing to the precision cap are equal, and their difference is
+    // zero to that precision.
+    let d = &zp.new(1 + 15625) - &b;
+    assert!(d.is_zero());
+    assert_eq!(d.valuation(), 6);
+    assert_eq!(zp.new(1 + 15625), b);
+    assert_eq!(format!("{}", d), "O(5^6)");
+
+    // Zero to a given precision absorbs precision in products.
+    assert_eq!((&d * &zp.new(25)).prec(), 8);
+    assert_eq!(a.truncate(4), zp.new(25 + 625));
+    assert_eq!(a.truncate(4).relative_prec(), 2);
+
+    // The zero of the parent is exact, so sums with it keep their precision.
+    assert_eq!((&d + &zp.zero()).prec(), 6);
+    assert_eq!((&a + &zp.zero()).prec(), 8);
+    assert_eq!((&zp.zero() + &a).prec(), 8);
+    assert_eq!(zp.zero().truncate(3).prec(), 3);
+
+    // Polynomial products start their sums from zero and keep full precision.
+    let r = GenericPolyRing::init(&zp, "x");
+    let f = r.new(vec![zp.new(25), zp.new(125)]);
+    let g = &f * &f;
+    assert_eq!(g.coefficients(), &[zp.new(625), zp.new(2 * 25 * 125), zp.new(125 * 125)][..]);
+    assert!(g.coefficients().iter().all(|c| c.prec() >= 10));
+}
+
+#[test]
+fn hensel_lifting() {
+    let zp = PAdicIntegers::init(5, 10);
+    let r = GenericPolyRing::init(&zp, "x");
+    let f = r.new(vec![zp.one(), zp.zero(), zp.one()]);
+    let i = zp.hensel_lift(&f, &zp.new(2)).unwrap();
+    assert_eq!(&i * &i, -zp.one());
+    assert_eq!(i.digits()[0], 2);
+    let j = zp.hensel_lift(&f, &zp.new(3)).unwrap();
+    assert_eq!(&i + &j, zp.zero());
+    assert!(zp.hensel_lift(&f, &zp.new(1)).is_none());
+
+    // Lifting succeeds when v(f(x)) > 2 v(f'(x)) even if f'(x) is not a unit.
+    let z2 = PAdicIntegers::init(2, 40);
+    let r2 = GenericPolyRing::init(&z2, "x");
+    let g = r2.new(vec![z2.new(-17), z2.zero(), z2.one()]);
+    let s = z2.hensel_lift(&g, &z2.one()).unwrap();
+    assert_eq!(&s * &s, z2.new(17));
+
+    // Roots of polynomials over Q_p.
+    let qp = PAdicField::init(7, 8);
+    let rq = GenericPolyRing::init(&qp, "x");
+    let h = rq.new(vec![qp.new(-2), qp.zero(), qp.one()]);
+    let t = qp.hensel_lift(&h, &qp.new(3)).unwrap();
+    assert_eq!(&t * &t, qp.new(2));
+}
+
+quickcheck! {
+    fn field_laws(a: (i32, u16), b: (i32, u16), c: i32) -> bool {
+        let qp = PAdicField::init(3, 12);
+        let e = |x: (i32, u16)| qp.new(Rational::new(x.0 as i64, x.1 as i64 + 1));
+        let (a, b, c) = (e(a), e(b), qp.new(c as i64));
+        &(&a + &b) - &b == a
+            && &a * &(&b + &c) == &(&a * &b) + &(&a * &c)
+            && (a.is_zero() || &a * &(&a).inv() == qp.one())
+            && (a.is_zero() || b.is_zero()
+                || (&a * &b).valuation() == a.valuation() + b.valuation())
+    }
+}
@@ -0,0 +1,169 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+
+#[macro_use]
+extern crate quickcheck;
+
+#[test]
+fn symmetric_group() {
+    let s4 = SymmetricGroup::init(4);
+    assert!(s4.is_abstract_group(Multiplicative));
+    assert_eq!(s4.order(), 24);
+    assert_eq!(s4.elements().count(), 24);
+
+    let p = s4.new("(0,1,2)");
+    let q = s4.new(vec![1, 0, 2, 3]);
+    assert_eq!(format!("{}", q), "(0,1)");
+    // Permutations act on the right: p * q applies p first.
+    let pq = p.operate(&q);
+    assert_eq!(pq.apply(0), q.apply(p.apply(0)));
+    assert_eq!(pq, s4.new("(1,2)"));
+    assert_eq!(q.operate(&p), s4.new("(0,2)"));
+    assert_eq!(s4.new("(0,1)(0,2)"), s4.new("(0,2,1)").two_sided_inverse());
+    assert_eq!(format!("{}", s4.identity()), "()");
+    assert_eq!(format!("{:?}", s4.new("(3,1)(0,2)")), "Permutation((0,2)(1,3))");
+
+    let r = s4.from_cycles(&[vec![0, 1, 2, 3]]);
+    assert_eq!(r.order(), 4);
+    assert_eq!(r.sign(), -1);
+    assert_eq!(r.cycle_type(), vec![4]);
+    assert_eq!(p.cycle_type(), vec![3, 1]);
+    assert_eq!(p.support(), vec![0, 1, 2]);
+    assert_eq!(r.pow(-1), r.two_sided_inverse());
+    assert_eq!(s4.power(Multiplicative, &r, 3), r.pow(3));
+    assert!(r.pow(4).is_identity());
+    assert!(p.is_even());
+}
+
+#[test]
+fn schreier_sims() {
+    let s7 = SymmetricGroup::init(7);
+    assert_eq!(PermutationGroup::init(&s7, &s7.gens()).order(), 5040);
+    assert_eq!(PermutationGroup::init(&s7, &[]).order(), 1);
+    assert_eq!(PermutationGroup::init(&s7, &[s7.identity()]).order(), 1);
+
+    let s5 = SymmetricGroup::init(5);
+    let a5 = PermutationGroup::init(&s5, &[s5.new("(0,1,2)"), s5.new("(0,1,2,3,4)")]);
+    assert_eq!(a5.order(), 60);
+    assert!(a5.is_transitive());
+    assert!(a5.contains(&s5.new("(0,1)(2,3)")));
+    assert!(!a5.contains(&s5.new("(0,1)")));
+    let elements: HashSet<Permutation> = a5.elements().collect();
+    assert_eq!(elements.len(), 60);
+    assert!(elements.iter().all(|g| g.is_even()));
+
+    // The Mathieu groups M11 and M12.
+    let s11 = SymmetricGroup::init(11);
+    let m11 = PermutationGroup::init(&s11, &[
+        s11.new("(0,1,2,3,4,5,6,7,8,9,10)"),
+        s11.new("(2,6,10,7)(3,9,4,5)"),
+    ]);
+    assert_eq!(m11.order(), 7920);
+    let s12 = SymmetricGroup::init(12);
+    let m12 = PermutationGroup::init(&s12, &[
+        s12.new("(0,1,2,3,4,5,6,7,8,9,10)"),
+        s12.new("(2,6,10,7)(3,9,4,5)"),
+        s12.new("(0,11)(1,10)(2,5)(3,7)(4,8)(6,9)"),
+    ]);
+    assert_eq!(m12.order(), 95040);
+    assert_eq!(m12.basic_orbit_lengths().iter().product::<usize>(), 95040);
+    assert!(!m12.contains(&s12.new("(0,1)")));
+
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..20 {
+        let g = m12.random_element(&mut rng);
+        assert!(m12.contains(&g));
+        assert!(m12.contains(&g.two_sided_inverse()));
+    }
+    let strong = PermutationGroup::init(&s12, &m12.strong_generators());
+    assert_eq!(strong.order(), 95040);
+
+    // A disconnected action: S_3 × S_2 acting on {0, 1, 2} ⊔ {3, 4}.
+    let g = PermutationGroup::init(&s5, &[s5.new("(0,1,2)"), s5.new("(0,1)"), s5.new("(3,4)")]);
+    assert_eq!(g.order(), 12);
+    assert!(!g.is_transitive());
+    assert_eq!(g.orbit(3), vec![3, 4]);
+}
+
+#[test]
+fn permutation_group_parent() {
+    let s4 = SymmetricGroup::init(4);
+    let g = PermutationGroup::init(&s4, &[s4.new("(0,1,2,3)"), s4.new("(0,1)")]);
+    assert!(g.is_abstract_group(Multiplicative));
+    assert_eq!(g.cardinality(), 24);
+    assert_eq!(Finite::elements(&g).len(), 24);
+    let x = g.new("(0,1,2)");
+    assert_eq!(x.parent(), g);
+    assert_eq!(x.order(), 3);
+    assert_eq!(format!("{}", x.operate(&g.new("(2,3)"))), "(0,1,3,2)");
+    assert!(x.operate(&x.two_sided_inverse()).is_identity());
+
+    // The Klein four-group is normal in S4 with quotient S3.
+    let v4 = Subgroup::init(Multiplicative, &g, &[g.new("(0,1)(2,3)"), g.new("(0,2)(1,3)")]);
+    assert_eq!(v4.order(), 4);
+    assert!(v4.is_normal());
+    let q = v4.quotient();
+    assert_eq!(q.order(), 6);
+    assert!(is_isomorphic(Multiplicative, &q, &SymmetricGroup::init(3)));
+
+    let a4 = PermutationGroup::init(&s4, &[s4.new("(0,1,2)"), s4.new("(1,2,3)")]);
+    assert!(find_isomorphism(Multiplicative, &a4, &AlternatingGroup::init(4)).is_some());
+    assert!(!is_isomorphic(Multiplicative, &a4, &DihedralGroup::init(6)));
+}
+
+#[test]
+#[should_panic]
+fn permutation_group_rejects_nonmembers() {
+    let s4 = SymmetricGroup::init(4);
+    let a4 = PermutationGroup::init(&s4, &[s4.new("(0,1,2)"), s4.new("(1,2,3)")]);
+    a4.new("(0,1)");
+}
+
+/// The closure of the generators under multiplication, by breadth-first search.
+fn closure(id: Permutation, gens: &[Permutation]) -> usize {
+    let mut seen = HashSet::new();
+    seen.insert(id.clone());
+    let mut queue = vec![id];
+    while let Some(g) = queue.pop() {
+        for s in gens {
+            let h = g.operate(s);
+            if seen.insert(h.clone()) {
+                queue.push(h);
+            }
+        }
+    }
+    seen.len()
+}
+
+quickcheck! {
+    fn group_laws(seed: u64) -> bool {
+        let s6 = SymmetricGroup::init(6);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let (a, b, c) = (
+            s6.random_element(&mut rng),
+            s6.random_element(&mut rng),
+            s6.random_element(&mut rng),
+        );
+        SymmetricGroup::prop_is_associative((a.clone(), b.clone(), c))
+            && a.operate(&a.two_sided_inverse()).is_identity()
+            && a.operate(&b).sign() == a.sign() * b.sign()
+            && s6.new(format!("{}", a).as_str()) == a
+    }
+
+    fn order_matches_closure(seed: u64) -> bool {
+        let s6 = SymmetricGroup::init(6);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let gens: Vec<Permutation> = (0..2).map(|_| s6.random_element(&mut rng)).collect();
+        let g = PermutationGroup::init(&s6, &gens);
+        let x = s6.random_element(&mut rng);
+        let h = PermutationGroup::init(&s6, &[gens[0].clone(), x.clone()]);
+        g.order() == closure(s6.identity(), &gens) as u128
+            && g.contains(&g.random_element(&mut rng))
+            && h.contains(&x)
+            && g.elements().collect::<HashSet<_>>().len() as u128 == g.order()
+    }
+}
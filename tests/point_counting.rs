@@ -0,0 +1,126 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn gf(p: i64) -> QuotientField<IntegerRing> {
+    QuotientRing::init(&IntegerRing::init(), &p).as_field().unwrap()
+}
+
+fn curve(p: i64, a: i64, b: i64) -> EllipticCurve<QuotientField<IntegerRing>> {
+    let f = gf(p);
+    EllipticCurve::init(&f, &f.new(a), &f.new(b))
+}
+
+/// The group structure read off from the orders of all points.
+fn structure_by_enumeration(e: &EllipticCurve<QuotientField<IntegerRing>>) -> (u64, u64) {
+    let n = e.cardinality() as u64;
+    let exponent = e.elements().iter().map(|pt| pt.order()).max().unwrap();
+    (n / exponent, exponent)
+}
+
+#[test]
+fn small_fields() {
+    // The textbook curve y^2 = x^3 + x + 1 over GF(23) has a cyclic group of
+    // order 28.
+    let e = curve(23, 1, 1);
+    assert_eq!(e.count_points_naive(), 28);
+    assert_eq!(e.count_points_schoof(), 28);
+    assert_eq!(e.elements().len(), 28);
+    assert_eq!(e.group_structure(), (1, 28));
+    let pt = e.new((gf(23).new(3), gf(23).new(10)));
+    assert_eq!(pt.order(), 28);
+    assert_eq!(e.quadratic_twist().count_points(), 2 * 23 + 2 - 28);
+
+    for p in [5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 97, 101, 227, 229, 233, 241] {
+        for (a, b) in [(1, 1), (0, 1), (1, 0), (-1, 0), (2, 3), (-3, 5), (0, 7)] {
+            let f = gf(p);
+            let (a, b) = (f.new(a), f.new(b));
+            let disc = &(&(&a * &a) * &a) * &f.new(4) + &(&b * &b) * &f.new(27);
+            if disc.is_zero() {
+                continue;
+            }
+            let e = EllipticCurve::init(&f, &a, &b);
+            let n = e.elements().len() as u64;
+            assert_eq!(e.count_points_naive(), n);
+            assert_eq!(e.count_points_bsgs(), n);
+            assert_eq!(e.count_points_schoof(), n);
+            let (n1, n2) = e.group_structure();
+            assert_eq!((n1, n2), structure_by_enumeration(&e));
+            assert!(n2.is_multiple_of(n1) && (p as u64 - 1).is_multiple_of(n1));
+        }
+    }
+}
+
+#[test]
+fn full_two_torsion() {
+    // y^2 = x^3 - x has the three points (0, 0), (±1, 0) of order two, and is
+    // supersingular with p + 1 points for p ≡ 3 (mod 4).
+    let p = 1000003;
+    let n = p as u64 + 1;
+    let e = curve(p, -1, 0);
+    assert_eq!(e.count_points(), n);
+    assert_eq!(e.count_points_schoof(), n);
+    assert_eq!(e.group_structure(), (2, n / 2));
+
+    // Over GF(13) the group of y^2 = x^3 - x is Z/2 × Z/4, and over GF(17) it is
+    // Z/4 × Z/4.
+    assert_eq!(curve(13, -1, 0).group_structure(), (2, 4));
+    assert_eq!(curve(17, -1, 0).group_structure(), (4, 4));
+}
+
+#[test]
+fn medium_fields() {
+    for &(p, a, b) in &[(10007, 3, 7), (65537, -3, 1), (1000003, 5, -2), (1000000007, 2, 3)] {
+        let e = curve(p, a, b);
+        let n = e.count_points_bsgs();
+        assert!(((n as i64 - p - 1).pow(2)) <= 4 * p);
+        assert_eq!(e.count_points_schoof(), n);
+        if p < 2000000 {
+            assert_eq!(e.count_points_naive(), n);
+        }
+        let mut rng = StdRng::seed_from_u64(p as u64);
+        for _ in 0..5 {
+            let pt = e.random_element(&mut rng);
+            assert!(e.power(Additive, &pt, n).is_infinity());
+            assert!(n.is_multiple_of(pt.order()));
+        }
+        let twist = e.quadratic_twist();
+        assert_eq!(twist.count_points(), 2 * p as u64 + 2 - n);
+        let (n1, n2) = e.group_structure();
+        assert_eq!(n1 * n2, n);
+    }
+}
+
+#[test]
+fn large_field() {
+    // p = 2^40 + 15.
+    let p = 1099511627791;
+    let e = curve(p, -3, 5);
+    let n = e.count_points();
+    assert_eq!(n, 1099509829262);
+    assert_eq!(e.count_points_schoof(), n);
+    assert_eq!(e.group_structure(), (1, n));
+    let mut rng = StdRng::seed_from_u64(1);
+    let pt = e.random_element(&mut rng);
+    assert!(e.power(Additive, &pt, n).is_infinity());
+}
+
+quickcheck! {
+    fn counts_agree(a: u16, b: u16) -> bool {
+        let p = 1009;
+        let f = gf(p);
+        let (a, b) = (f.new(a as i64), f.new(b as i64));
+        let disc = &(&(&a * &a) * &a) * &f.new(4) + &(&b * &b) * &f.new(27);
+        if disc.is_zero() {
+            return true;
+        }
+        let e = EllipticCurve::init(&f, &a, &b);
+        let n = e.count_points_naive();
+        e.count_points_bsgs() == n && e.count_points_schoof() == n
+    }
+}
@@ -0,0 +1,92 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn zx() -> GenericPolyRing<IntegerRing> {
+    GenericPolyRing::init(&IntegerRing::init(), "x")
+}
+
+#[test]
+fn poly_ring_structure() {
+    let r = zx();
+    assert!(r.is_ring());
+    assert!(r.is_generic());
+
+    let x = r.gen();
+    let f = &(&x * &x) - &r.new(vec![1]);
+    assert_eq!(f.degree(), 2);
+    assert_eq!(f.get_coeffs(), vec![-1, 0, 1]);
+    assert_eq!(r.zero().degree(), -1);
+    assert_eq!(format!("{}", f), "1*x^2 + -1");
+}
+
+#[test]
+fn evaluate_at_integer_and_polynomial() {
+    let r = zx();
+    let f = r.new(vec![1, 2, 3]);
+    assert_eq!(f.evaluate(&2), 17);
+
+    // Composition f(x + 1) = 3x^2 + 8x + 6.
+    let g = r.new(vec![1, 1]);
+    assert_eq!(f.evaluate(&g), r.new(vec![6, 8, 3]));
+
+    let mut out = r.zero();
+    out.assign_evaluate(&f, &g);
+    assert_eq!(out, r.new(vec![6, 8, 3]));
+}
+
+#[test]
+fn evaluate_at_matrix() {
+    let zz = IntegerRing::init();
+    let r = zx();
+    let m = MatrixAlgebra::init(&zz, 2);
+
+    // Characteristic polynomial x^2 - 5x - 2 annihilates [[1, 2], [3, 4]].
+    let a = m.new(vec![1, 2, 3, 4]);
+    let f = r.new(vec![-2, -5, 1]);
+    assert!(f.evaluate(&a).is_zero());
+
+    let g = r.new(vec![1, 1]);
+    let mut out = m.zero();
+    out.assign_evaluate(&g, &a);
+    assert_eq!(out, m.new(vec![2, 2, 3, 5]));
+}
+
+#[test]
+fn evaluate_mod_does_not_overflow() {
+    let r = zx();
+    let m = (1i64 << 61) - 1;
+    let f = r.new(vec![i64::MAX, -1, i64::MIN, i64::MAX]);
+    let x = i64::MAX - 7;
+
+    let expected = f.get_coeffs().iter().rev().fold(0i128, |acc, &c| {
+        (acc * (x as i128) + c as i128).rem_euclid(m as i128)
+    });
+    assert_eq!(f.evaluate_mod(&x, &m) as i128, expected);
+
+    let mut out = 0;
+    out.assign_evaluate_mod(&f, &-1, &7);
+    let expected = i64::MAX as i128 + 1 + i64::MIN as i128 - i64::MAX as i128;
+    assert_eq!(out as i128, expected.rem_euclid(7));
+}
+
+quickcheck! {
+    fn evaluation_is_a_ring_homomorphism(a: Vec<i8>, b: Vec<i8>, x: i8) -> bool {
+        let r = zx();
+        let f = r.new(a.into_iter().take(6).map(i64::from).collect());
+        let g = r.new(b.into_iter().take(6).map(i64::from).collect());
+        let x = i64::from(x) % 4;
+        (&f * &g).evaluate(&x) == f.evaluate(&x) * g.evaluate(&x)
+            && (&f + &g).evaluate(&x) == f.evaluate(&x) + g.evaluate(&x)
+    }
+
+    fn evaluate_mod_agrees_with_evaluate(a: Vec<i8>, x: i8, m: u8) -> bool {
+        let r = zx();
+        let f = r.new(a.into_iter().take(6).map(i64::from).collect());
+        let x = i64::from(x) % 8;
+        let m = i64::from(m) + 1;
+        f.evaluate_mod(&x, &m) == f.evaluate(&x).rem_euclid(m)
+    }
+}
@@ -0,0 +1,130 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn q(n: i64, d: i64) -> Rational {
+    Rational::new(n, d)
+}
+
+fn qx(prec: u64) -> PowerSeriesRing<RationalField> {
+    PowerSeriesRing::init(&RationalField::init(), "x", prec)
+}
+
+fn series(r: &PowerSeriesRing<RationalField>, coeffs: &[i64]) -> PowerSeries<RationalField> {
+    r.new(coeffs.iter().map(|&c| Rational::from(c)).collect::<Vec<_>>())
+}
+
+#[test]
+fn structure() {
+    let zx = PowerSeriesRing::init(&IntegerRing::init(), "x", 8);
+    assert!(zx.is_ring());
+    assert_eq!(zx.precision_cap(), 8);
+
+    let x = zx.gen();
+    let f = &(&x + &zx.one()) * &(&x - &zx.one());
+    assert_eq!(f.coefficients(), &[-1, 0, 1]);
+    assert_eq!(f.prec(), 8);
+    assert_eq!(format!("{}", f), "-1 + 1*x^2 + O(x^8)");
+    assert_eq!(f.derivative().coefficients(), &[0, 2]);
+    assert_eq!(f.derivative().prec(), 7);
+    assert_eq!(zx.power(Multiplicative, &x, 8), zx.zero());
+}
+
+#[test]
+fn precision_tracking() {
+    let r = qx(10);
+    let x = r.gen();
+    let a = r.series(vec![q(1, 1), q(1, 1)], 3);
+    let b = &x * &x;
+
+    assert_eq!(a.valuation(), 0);
+    assert_eq!(b.valuation(), 2);
+    assert_eq!((&a + &b).prec(), 3);
+    assert_eq!((&a * &b).prec(), 5);
+    assert_eq!((&a * &b).coefficients(), &[q(0, 1), q(0, 1), q(1, 1), q(1, 1)]);
+    assert_eq!(r.series(vec![], 4).valuation(), 4);
+
+    // Equality only compares known coefficients.
+    assert_eq!(a, series(&r, &[1, 1, 0, 5]));
+    assert_ne!(a, series(&r, &[1, 2]));
+    assert_eq!(r.series(vec![q(1, 1); 20], 30).prec(), 10);
+}
+
+#[test]
+fn newton_iteration() {
+    let r = qx(12);
+    let x = r.gen();
+
+    let e = x.exp();
+    let mut fact = 1;
+    for k in 0..12 {
+        assert_eq!(e.coeff(k), q(1, fact));
+        fact *= k as i64 + 1;
+    }
+    assert_eq!(e.log(), x);
+
+    // 1/(1 - x) = 1 + x + x^2 + ...
+    let g = (&r.one() - &x).inverse();
+    assert_eq!(g, series(&r, &[1; 12]));
+    assert_eq!(g.prec(), 12);
+
+    // sqrt(1 + x) = 1 + x/2 - x^2/8 + x^3/16 - ...
+    let s = (&r.one() + &x).sqrt();
+    assert_eq!(&s.coefficients()[..4], &[q(1, 1), q(1, 2), q(-1, 8), q(1, 16)]);
+    assert_eq!(&s * &s, &r.one() + &x);
+
+    // log(1 + x) = x - x^2/2 + x^3/3 - ...
+    let l = (&r.one() + &x).log();
+    assert_eq!(l.coeff(5), q(1, 5));
+    assert_eq!(l.derivative(), g.compose(&-&x).truncate(11));
+    assert_eq!(l.derivative().integral(), l);
+}
+
+#[test]
+fn composition() {
+    let r = qx(10);
+    let x = r.gen();
+    let g = &x + &(&x * &x);
+    assert_eq!(x.exp().compose(&g), g.exp());
+
+    let x2 = r.series(vec![q(0, 1), q(0, 1), q(1, 1)], 6);
+    let f = r.series(vec![q(1, 1), q(2, 1), q(3, 1)], 4);
+    let h = f.compose(&x2);
+    assert_eq!(h.prec(), 6);
+    assert_eq!(h, series(&r, &[1, 0, 2, 0, 3]));
+}
+
+#[test]
+fn polynomial_interop() {
+    let zx = GenericPolyRing::init(&IntegerRing::init(), "x");
+    let ps = PowerSeriesRing::init(&IntegerRing::init(), "x", 4);
+
+    let p = zx.new(vec![1, 2, 3, 4, 5, 6]);
+    let f = ps.new(&p);
+    assert_eq!(f.coefficients(), &[1, 2, 3, 4]);
+    assert_eq!(f.polynomial(&zx), zx.new(vec![1, 2, 3, 4]));
+
+    // Polynomials evaluate at power series by composition.
+    let g = &ps.gen() + &ps.gen();
+    assert_eq!(p.evaluate(&g).coefficients(), &[1, 4, 12, 32]);
+    assert_eq!(p.evaluate(&g), ps.new(&p).compose(&g));
+}
+
+fn small_series(r: &PowerSeriesRing<RationalField>, v: &[i8]) -> PowerSeries<RationalField> {
+    let coeffs = v.iter().take(5).map(|&c| Rational::from((c % 4) as i64));
+    r.new(std::iter::once(Rational::from(0)).chain(coeffs).collect::<Vec<_>>())
+}
+
+quickcheck! {
+    fn exp_log_inverse(a: Vec<i8>, b: Vec<i8>) -> bool {
+        let r = qx(8);
+        let (f, g) = (small_series(&r, &a), small_series(&r, &b));
+        let u = &r.one() + &f;
+        f.exp().log() == f
+            && (&f + &g).exp() == &f.exp() * &g.exp()
+            && &u * &u.inverse() == r.one()
+            && u.sqrt().compose(&g) == u.compose(&g).sqrt()
+    }
+}
@@ -0,0 +1,102 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+mod common;
+use common::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+type Zz2 = ProductRing<IntegerRing, IntegerRing>;
+
+fn zz2() -> Zz2 {
+    let zz = IntegerRing::init();
+    ProductParent::init(&zz, &zz).as_ring()
+}
+
+#[test]
+fn componentwise_operations() {
+    let p = zz2();
+    assert!(p.is_abstract_group_abelian(Additive));
+    assert!(p.is_abstract_ring(Additive, Multiplicative));
+    assert_eq!(p.first(), &IntegerRing);
+
+    let (a, b) = (p.new((2, -3)), p.new((5, 7)));
+    assert_eq!(a.op(Additive, &b), p.new((7, 4)));
+    assert_eq!(a.op(Multiplicative, &b), p.new((10, -21)));
+    assert_eq!(TwoSidedInverse::<Additive>::two_sided_inverse(&a), p.new((-2, 3)));
+    assert_eq!(p.zero(), p.new((0, 0)));
+    assert_eq!(p.one(), p.new((1, 1)));
+    assert!(IsIdentity::<Additive>::is_identity(&p.new((0, 0))));
+    assert!(!IsIdentity::<Multiplicative>::is_identity(&p.new((1, 0))));
+    assert_eq!(a.parent(), p);
+    assert_eq!((a.first(), a.second()), (&2, &-3));
+    assert_eq!(p.power(Multiplicative, &a, 3).into_pair(), (8, -27));
+}
+
+#[test]
+fn mixed_factors() {
+    let zz = IntegerRing::init();
+    let z4 = QuotientRing::init(&zz, &4);
+    let p = ProductParent::init(&z4, &RationalField::init()).as_ring();
+    assert!(p.is_abstract_ring(Additive, Multiplicative));
+
+    let x = p.new((z4.new(3), Rational::new(1, 2)));
+    let y = x.op(Multiplicative, &x);
+    assert_eq!(y, p.new((z4.new(1), Rational::new(1, 4))));
+    assert_eq!(AbstractMonoid::<Additive>::power(&p, Additive, &x, 4), p.new((z4.zero(), Rational::from(2))));
+    assert_eq!(x.op(Additive, &TwoSidedInverse::<Additive>::two_sided_inverse(&x)), p.zero());
+
+    // Products nest.
+    let pp = ProductParent::init(&p, &zz).as_ring();
+    assert_eq!(pp.one(), pp.new((p.one(), 1)));
+}
+
+#[test]
+fn product_of_fields_is_not_a_field() {
+    let qq = RationalField::init();
+    let p = ProductParent::init(&qq, &qq).as_ring();
+    assert!(probe(&qq).is_field());
+    assert!(!probe(&p).is_field());
+    assert!(!probe(&ProductParent::init(&qq, &qq)).is_field());
+    assert!(p.is_abstract_ring(Additive, Multiplicative));
+    let (a, b) = (p.new((Rational::from(1), Rational::from(0))), p.new((Rational::from(0), Rational::from(1))));
+    assert_eq!(a.op(Multiplicative, &b), p.zero());
+}
+
+#[test]
+fn product_of_groups() {
+    let (c3, c4) = (CyclicGroup::init(3), CyclicGroup::init(4));
+    let p = ProductParent::init(&c3, &c4);
+    assert!(p.is_abstract_group(Multiplicative));
+    assert!(p.is_abstract_group_abelian(Multiplicative));
+
+    // C3 × C4 is cyclic of order 12, generated by the pair of generators.
+    let g = p.new((c3.gen(), c4.gen()));
+    assert_eq!(p.power(Multiplicative, &g, 12), p.identity());
+    assert!((1..12).all(|k| p.power(Multiplicative, &g, k) != p.identity()));
+    assert_eq!(g.op(Multiplicative, &g.two_sided_inverse()), p.identity());
+
+    // A nonabelian factor makes the product nonabelian, but still a group.
+    let s3 = SymmetricGroup::init(3);
+    let q = ProductParent::init(&s3, &c4);
+    assert!(q.is_abstract_group(Multiplicative));
+    let (a, b) = (q.new((s3.new("(0,1)"), c4.gen())), q.new((s3.new("(0,1,2)"), c4.identity())));
+    assert_ne!(a.op(Multiplicative, &b), b.op(Multiplicative, &a));
+    assert_eq!(a.op(Multiplicative, &a.two_sided_inverse()), q.identity());
+}
+
+quickcheck! {
+    fn ring_laws(a: (i16, i16), b: (i16, i16), c: (i16, i16)) -> bool {
+        let p = zz2();
+        let e = |x: (i16, i16)| p.new((x.0 as i64, x.1 as i64));
+        let (a, b, c) = (e(a), e(b), e(c));
+        let distributive = a.op(Multiplicative, &b.op(Additive, &c))
+            == a.op(Multiplicative, &b).op(Additive, &a.op(Multiplicative, &c));
+        <Zz2 as Associative<Multiplicative>>::prop_is_associative((a, b, c))
+            && <Zz2 as Commutative<Additive>>::prop_is_commutative((a, b))
+            && <Zz2 as Divisible<Additive>>::prop_inv_is_latin_square((a, c))
+            && Identity::<Additive>::prop_operating_identity_element_is_noop(&p, (b,))
+            && distributive
+    }
+}
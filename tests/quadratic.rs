@@ -0,0 +1,133 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+const EUCLIDEAN: [i64; 21] = [
+    -11, -8, -7, -4, -3, 5, 8, 12, 13, 17, 21, 24, 28, 29, 33, 37, 41, 44, 57, 73, 76,
+];
+
+fn gaussian() -> EuclideanQuadraticRing {
+    QuadraticIntegerRing::init(-4, "i").as_euclidean().unwrap()
+}
+
+#[test]
+fn gaussian_integers() {
+    let zi = gaussian();
+    assert!(zi.is_ring());
+    let i = zi.gen();
+    assert_eq!(&i * &i, -zi.one());
+
+    let z = zi.new((3, 2));
+    assert_eq!(z.norm(), 13);
+    assert_eq!(z.trace(), 6);
+    assert_eq!(z.conjugate(), zi.new((3, -2)));
+    assert_eq!(&z * &z.conjugate(), zi.new(13));
+    assert_eq!(format!("{}", z.conjugate()), "3 - 2*i");
+
+    // The canonical associate of a Gaussian integer lies in the right half plane.
+    assert_eq!(zi.normalize(&zi.new((-2, 3))), z);
+    assert_eq!(zi.gcd(&zi.new(5), &zi.new((1, 3))), zi.new((2, 1)));
+    let (a, b) = (zi.new((11, 3)), zi.new((1, 8)));
+    let (g, s, t) = zi.xgcd(&a, &b);
+    assert_eq!(&(&s * &a) + &(&t * &b), g);
+
+    assert_eq!(zi.is_irreducible(&zi.new((2, 1))), Some(true));
+    assert_eq!(zi.is_irreducible(&zi.new((1, 1))), Some(true));
+    assert_eq!(zi.is_irreducible(&zi.new(3)), Some(true));
+    assert_eq!(zi.is_irreducible(&zi.new(5)), Some(false));
+    assert_eq!(zi.is_irreducible(&zi.new((0, 7))), Some(true));
+    assert!(zi.is_unit(&i));
+}
+
+#[test]
+fn other_discriminants() {
+    let r = QuadraticIntegerRing::init(-8, "s").as_euclidean().unwrap();
+    let s = r.gen();
+    assert_eq!(&s * &s, r.new(-2));
+    assert_eq!(r.is_irreducible(&r.new((1, 1))), Some(true));
+    assert_eq!(r.fundamental_unit(), None);
+
+    // The golden ratio satisfies w^2 = w + 1 and is a unit of norm -1.
+    let z = QuadraticIntegerRing::init(5, "w").as_euclidean().unwrap();
+    let w = z.gen();
+    assert_eq!(&w * &w, &w + &z.one());
+    assert_eq!(w.norm(), -1);
+    assert_eq!(w.conjugate(), z.new((1, -1)));
+    assert_eq!(z.fundamental_unit(), Some(w.clone()));
+    assert_eq!(z.normalize(&z.power(Multiplicative, &w, 7)), z.one());
+    assert_eq!(z.is_irreducible(&z.new(2)), Some(true));
+    assert_eq!(z.is_irreducible(&z.new(11)), Some(false));
+
+    let r2 = QuadraticIntegerRing::init(8, "r").as_euclidean().unwrap();
+    assert_eq!(r2.fundamental_unit(), Some(r2.new((1, 1))));
+    let r73 = QuadraticIntegerRing::init(73, "w").as_euclidean().unwrap();
+    assert_eq!(r73.fundamental_unit().unwrap().norm().abs(), 1);
+
+    // Z[√-5] is not a unique factorization domain.
+    let r5 = QuadraticIntegerRing::init(-20, "s");
+    assert!(r5.as_euclidean().is_none());
+    let s = r5.gen();
+    assert_eq!(&r5.new(2) * &r5.new(3), &(&r5.one() + &s) * &(&r5.one() - &s));
+}
+
+#[test]
+#[should_panic]
+fn non_fundamental_discriminant() {
+    QuadraticIntegerRing::init(-16, "i");
+}
+
+#[test]
+fn gaussian_quotients() {
+    let zi = gaussian();
+    let f5 = QuotientRing::init(&zi, &zi.new((2, 1))).as_field().unwrap();
+    assert_eq!(f5.new(zi.gen()), f5.new(zi.new(-2)));
+    assert_eq!(f5.new(zi.new((4, 7))).lift(), &zi.new(0));
+    let x = f5.new(zi.new(3));
+    assert_eq!(&x * &(&x).inv(), f5.one());
+
+    // Z[i]/(3) is the field with nine elements.
+    let f9 = QuotientRing::init(&zi, &zi.new(3)).as_field().unwrap();
+    let nonzero = f9.new(zi.new((1, 1)));
+    assert_eq!(f9.power(Multiplicative, &nonzero, 8), f9.one());
+    assert_ne!(f9.power(Multiplicative, &nonzero, 4), f9.one());
+
+    let q = QuotientRing::init(&zi, &zi.new(2));
+    assert_eq!(q.is_maximal(), Some(false));
+    let one_plus_i = q.new(zi.new((1, 1)));
+    assert!((&one_plus_i * &one_plus_i).is_zero());
+}
+
+quickcheck! {
+    fn euclidean_division(k: usize, a: (i16, i16), b: (i16, i16)) -> bool {
+        let d = EUCLIDEAN[k % EUCLIDEAN.len()];
+        let r = QuadraticIntegerRing::init(d, "w").as_euclidean().unwrap();
+        let (a, b) = (r.new((a.0 as i64, a.1 as i64)), r.new((b.0 as i64, b.1 as i64)));
+        if b.is_zero() {
+            return true;
+        }
+        let (q, rem) = r.div_rem(&a, &b);
+        let g = r.gcd(&a, &b);
+        &(&q * &b) + &rem == a
+            && rem.norm().abs() < b.norm().abs()
+            && r.divides(&g, &a)
+            && r.divides(&g, &b)
+            && r.normalize(&g) == g
+    }
+
+    fn canonical_forms(k: usize, x: (i16, i16), m: (i8, i8), y: (i8, i8)) -> bool {
+        let d = EUCLIDEAN[k % EUCLIDEAN.len()];
+        let r = QuadraticIntegerRing::init(d, "w").as_euclidean().unwrap();
+        let x = r.new((x.0 as i64, x.1 as i64));
+        let m = r.new((m.0 as i64, m.1 as i64));
+        let y = r.new((y.0 as i64, y.1 as i64));
+        if m.is_zero() {
+            return true;
+        }
+        let unit = r.fundamental_unit().unwrap_or(r.new(-1));
+        r.reduce(&x, &m) == r.reduce(&(&x + &(&m * &y)), &m)
+            && r.reduce(&r.reduce(&x, &m), &m) == r.reduce(&x, &m)
+            && r.normalize(&m) == r.normalize(&(&m * &unit))
+    }
+}
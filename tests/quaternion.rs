@@ -0,0 +1,165 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+mod common;
+use common::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+type Q = RationalField;
+
+fn hamilton() -> QuaternionAlgebra<Q> {
+    let qq = RationalField::init();
+    QuaternionAlgebra::init(&qq, &Rational::from(-1), &Rational::from(-1))
+}
+
+fn quat(h: &QuaternionAlgebra<Q>, x: [i64; 4]) -> Quaternion<Q> {
+    h.new(x.map(Rational::from))
+}
+
+fn octonions() -> OctonionAlgebra<Q> {
+    CayleyDickson::init(&hamilton(), &Rational::from(-1))
+}
+
+fn oct(o: &OctonionAlgebra<Q>, x: [i64; 8]) -> Octonion<Q> {
+    let h = o.base();
+    o.new((quat(h, [x[0], x[1], x[2], x[3]]), quat(h, [x[4], x[5], x[6], x[7]])))
+}
+
+#[test]
+fn hamilton_quaternions() {
+    let h = hamilton();
+    assert!(h.is_ncring());
+    assert!(!probe(&h).is_divisible());
+    let d = h.as_division_algebra().unwrap();
+    assert!(d.is_abstract_group(Multiplicative));
+    assert!(probe(&d).is_divisible());
+    let [one, i, j, k] = h.basis();
+    assert_eq!(one, h.one());
+    assert_eq!(&i * &j, k);
+    assert_eq!(&j * &i, -&k);
+    assert_eq!(&j * &k, i);
+    assert_eq!(&k * &i, j);
+    assert_eq!(&k * &k, -&one);
+
+    let x = quat(&h, [1, 2, 3, 4]);
+    assert_eq!(x.conjugate(), quat(&h, [1, -2, -3, -4]));
+    assert_eq!(x.norm(), Rational::from(30));
+    assert_eq!(x.trace(), Rational::from(2));
+    assert_eq!(&x * &x.conjugate(), h.embed_base(&Rational::from(30)));
+    let y = x.inverse().unwrap();
+    assert_eq!(&x * &y, one);
+    assert_eq!(&y * &x, one);
+    assert_eq!((&x).inv(), y);
+    assert_eq!(format!("{}", x), "1 + 2*i + 3*j + 4*k");
+    assert_eq!(h.zero().inverse(), None);
+}
+
+#[test]
+fn split_quaternions() {
+    // (1, 1) is isomorphic to the 2 × 2 matrices, so it has zero divisors.
+    let qq = RationalField::init();
+    let m = QuaternionAlgebra::init(&qq, &Rational::from(1), &Rational::from(1));
+    let [one, i, j, _] = m.basis();
+    let e = &one + &i;
+    let f = &one - &i;
+    assert!(e.norm().is_zero());
+    assert!((&e * &f).is_zero());
+    assert_eq!(e.inverse(), None);
+    assert_eq!(&i * &i, one);
+    assert_eq!(&j * &i, -&(&i * &j));
+    assert!(!m.is_division_algebra());
+    assert!(m.as_division_algebra().is_none());
+    assert!(!probe(&m).is_divisible());
+}
+
+#[test]
+fn division_quaternion_algebras() {
+    let qq = RationalField::init();
+    let alg = |a: i64, b: i64| QuaternionAlgebra::init(&qq, &Rational::from(a), &Rational::from(b));
+    // Ramified at infinity, at 3, at 5 and at 2 and 3 respectively.
+    assert!(alg(-1, -1).is_division_algebra());
+    assert!(alg(-1, 3).is_division_algebra());
+    assert!(alg(2, 5).is_division_algebra());
+    assert!(alg(-1, -3).is_division_algebra());
+    // Split: a square parameter, the Steinberg relation (a, 1 - a) = 1, and the
+    // sum of two squares 5 = 1 + 4 being a norm from Q(i).
+    assert!(!alg(4, -7).is_division_algebra());
+    assert!(!alg(-2, 3).is_division_algebra());
+    assert!(!alg(-1, 5).is_division_algebra());
+    let half = QuaternionAlgebra::init(&qq, &Rational::new(-1, 2), &Rational::new(-3, 4));
+    assert!(half.is_division_algebra());
+}
+
+#[test]
+fn octonions_are_a_loop() {
+    let o = octonions();
+    assert!(!probe(&o).is_divisible());
+    let d = o.as_division_algebra().unwrap();
+    assert!(d.is_abstract_loop(Multiplicative));
+    assert!(probe(&d).is_divisible());
+    assert!(o.is_abstract_group_abelian(Additive));
+    let split = CayleyDickson::init(&hamilton(), &Rational::from(1));
+    assert!(split.as_division_algebra().is_none());
+
+    let l = o.gen();
+    assert_eq!(&l * &l, -o.one());
+    let [_, i, j, _] = o.base().basis();
+    let (i, j) = (o.embed(&i), o.embed(&j));
+    assert_eq!(&(&i * &j) * &l, -&(&i * &(&j * &l)));
+
+    let x = oct(&o, [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(x.norm(), Rational::from(204));
+    assert_eq!(&x * &(&x).inv(), o.one());
+    assert_eq!(&(&x).inv() * &x, o.one());
+
+    // Sedenions by doubling again.
+    let s = CayleyDickson::init(&o, &Rational::from(-1));
+    let u = s.new((x.clone(), l.clone()));
+    assert_eq!(u.norm(), Rational::from(205));
+    assert_eq!(&u * &u.conjugate(), s.embed_base(&Rational::from(205)));
+
+    // Sedenions have zero divisors: (e_3 + e_10)(e_6 - e_15) = 0.
+    assert!(!probe(&s).is_divisible());
+    let e = |n: usize| {
+        let mut c = [0; 8];
+        c[n % 8] = 1;
+        if n < 8 { s.embed(&oct(&o, c)) } else { s.new((o.zero(), oct(&o, c))) }
+    };
+    let (x, y) = (&e(3) + &e(10), &e(6) - &e(15));
+    assert!(!x.is_zero() && !y.is_zero());
+    assert!((&x * &y).is_zero());
+    assert!(x.norm() != Rational::from(0));
+}
+
+quickcheck! {
+    fn quaternion_laws(x: (i8, i8, i8, i8), y: (i8, i8, i8, i8), z: (i8, i8, i8, i8)) -> bool {
+        let qq = RationalField::init();
+        let h = QuaternionAlgebra::init(&qq, &Rational::from(-2), &Rational::from(3));
+        let e = |x: (i8, i8, i8, i8)| quat(&h, [x.0, x.1, x.2, x.3].map(|c| c as i64));
+        let (x, y, z) = (e(x), e(y), e(z));
+        &(&x * &y) * &z == &x * &(&y * &z)
+            && &x * &(&y + &z) == &(&x * &y) + &(&x * &z)
+            && (&x * &y).norm() == x.norm() * y.norm()
+            && (&x * &y).conjugate() == &y.conjugate() * &x.conjugate()
+            && (x.norm().is_zero() || &(&y * &x) / &x == y)
+    }
+
+    fn octonion_laws(x: Vec<i8>, y: Vec<i8>) -> bool {
+        let o = octonions();
+        let e = |v: &Vec<i8>| {
+            let mut c = [0; 8];
+            for (c, &v) in c.iter_mut().zip(v) {
+                *c = v as i64;
+            }
+            oct(&o, c)
+        };
+        let (x, y) = (e(&x), e(&y));
+        // Octonions are alternative and their norm is multiplicative.
+        &(&x * &x) * &y == &x * &(&x * &y)
+            && &(&y * &x) * &x == &y * &(&x * &x)
+            && (&x * &y).norm() == x.norm() * y.norm()
+            && (&x * &y).conjugate() == &y.conjugate() * &x.conjugate()
+    }
+}
@@ -0,0 +1,141 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn qx() -> GenericPolyRing<RationalField> {
+    GenericPolyRing::init(&RationalField::init(), "x")
+}
+
+fn qpoly(coeffs: &[(i64, i64)]) -> GenericPoly<RationalField> {
+    qx().new(coeffs.iter().map(|&(n, d)| Rational::new(n, d)).collect())
+}
+
+#[test]
+fn euclidean_domains() {
+    let zz = IntegerRing::init();
+    assert_eq!(zz.div_rem(&-7, &3), (-3, 2));
+    assert_eq!(zz.gcd(&-12, &18), 6);
+    assert_eq!(zz.lcm(&4, &-6), 12);
+    let (g, s, t) = zz.xgcd(&240, &46);
+    assert_eq!((g, 240 * s + 46 * t), (2, 2));
+    assert!(zz.is_prime(&2305843009213693951));
+    assert!(!zz.is_prime(&3215031751));
+    assert_eq!(zz.is_irreducible(&-13), Some(true));
+
+    // gcd(x^2 - 1, x^2 + 2x + 1) = x + 1
+    let r = qx();
+    let a = qpoly(&[(-1, 1), (0, 1), (1, 1)]);
+    let b = qpoly(&[(2, 1), (4, 1), (2, 1)]);
+    assert_eq!(r.gcd(&a, &b), qpoly(&[(1, 1), (1, 1)]));
+    let (g, s, t) = r.xgcd(&a, &b);
+    assert_eq!(&(&s * &a) + &(&t * &b), g);
+    let (q, rem) = r.div_rem(&b, &qpoly(&[(0, 1), (2, 1)]));
+    assert_eq!((q, rem), (qpoly(&[(2, 1), (1, 1)]), qpoly(&[(2, 1)])));
+}
+
+#[test]
+fn integer_factorization() {
+    let zz = IntegerRing::init();
+    assert_eq!(zz.factor(&1), vec![]);
+    assert_eq!(zz.factor(&-1), vec![]);
+    assert_eq!(zz.factor(&-360), vec![(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(zz.factor(&i64::MIN), vec![(2, 63)]);
+    assert_eq!(zz.factor(&i64::MAX), vec![(7, 2), (73, 1), (127, 1), (337, 1), (92737, 1), (649657, 1)]);
+    assert_eq!(zz.factor(&3215031751), vec![(151, 1), (751, 1), (28351, 1)]);
+
+    // Semiprimes with two large factors need Pollard's rho to split.
+    assert_eq!(zz.factor(&(1000000007 * 998244353)), vec![(998244353, 1), (1000000007, 1)]);
+    assert_eq!(zz.factor(&-(2147483647 * 2147483629)), vec![(2147483629, 1), (2147483647, 1)]);
+    assert_eq!(zz.factor(&(3037000493 * 3037000453)), vec![(3037000453, 1), (3037000493, 1)]);
+}
+
+#[test]
+fn integers_mod_n() {
+    let zz = IntegerRing::init();
+    let r = QuotientRing::init(&zz, &-12);
+    assert!(r.is_ring());
+    assert_eq!(r.modulus(), &12);
+    assert_eq!(r.is_maximal(), Some(false));
+    assert!(r.as_field().is_none());
+
+    let a = r.new(-1);
+    assert_eq!(a.lift(), &11);
+    assert_eq!(&a * &a, r.one());
+    assert_eq!(r.new(5).inverse(), Some(r.new(5)));
+    assert_eq!(r.new(4).inverse(), None);
+    assert_eq!(&r.new(3) * &r.new(4), r.zero());
+    assert_eq!(format!("{}", &r.new(7) + &r.new(8)), "3");
+}
+
+#[test]
+fn prime_fields() {
+    let zz = IntegerRing::init();
+    let f = QuotientRing::init(&zz, &7).as_field().unwrap();
+    assert!(f.is_field());
+    for a in 1..7 {
+        let x = f.new(a);
+        assert_eq!(&x * &(&x).inv(), f.one());
+        assert_eq!(f.power(Multiplicative, &x, 6), f.one());
+    }
+    assert_eq!(&f.new(3) / &f.new(5), f.new(2));
+
+    // No overflow for primes close to 2^63.
+    let p = 9223372036854775783;
+    let big = QuotientRing::init(&zz, &p).as_field().unwrap();
+    let x = big.new(p - 1);
+    assert_eq!(&x * &x, big.one());
+    assert_eq!(&x + &x, big.new(p - 2));
+    assert_eq!(big.power(Multiplicative, &big.new(2), (p - 1) as u64), big.one());
+}
+
+#[test]
+fn polynomial_quotients() {
+    let r = qx();
+
+    // Q[x]/(x^2 + 1) is the field of Gaussian rationals, although irreducibility
+    // of quadratics is not decided generically.
+    let modulus = qpoly(&[(1, 1), (0, 1), (1, 1)]);
+    let q = QuotientRing::init(&r, &modulus);
+    assert_eq!(q.is_maximal(), None);
+    assert!(q.as_field().is_none());
+    let i = q.new(r.gen());
+    assert_eq!(&i * &i, -q.one());
+
+    let k = q.as_field_unchecked();
+    let z = k.new(qpoly(&[(1, 1), (1, 1)]));
+    assert_eq!(z.inv().lift(), &qpoly(&[(1, 2), (-1, 2)]));
+
+    // Reduction modulo x - 2 is evaluation at 2.
+    let q2 = QuotientRing::init(&r, &qpoly(&[(-4, 1), (2, 1)])).as_field().unwrap();
+    assert_eq!(q2.modulus(), &qpoly(&[(-2, 1), (1, 1)]));
+    let f = qpoly(&[(1, 1), (3, 1), (0, 1), (1, 1)]);
+    assert_eq!(q2.new(f.clone()).lift(), &qpoly(&[(15, 1)]));
+
+    // Polynomials evaluate in the quotient through the algebra structure.
+    let ff = GenericPolyRing::init(&r, "y").new(vec![r.zero(), r.zero(), r.one()]);
+    assert_eq!(ff.evaluate(&i), -q.one());
+}
+
+quickcheck! {
+    fn residues_are_homomorphic(a: i64, b: i64, n: u32) -> bool {
+        let zz = IntegerRing::init();
+        let n = n as i64 + 1;
+        let r = QuotientRing::init(&zz, &n);
+        let (x, y) = (r.new(a), r.new(b));
+        let sum = (a as i128 + b as i128).rem_euclid(n as i128) as i64;
+        let prod = (a as i128 * b as i128).rem_euclid(n as i128) as i64;
+        (&x + &y).lift() == &sum
+            && (&x * &y).lift() == &prod
+            && (&x - &x).is_zero()
+            && x.inverse().is_none_or(|z| &z * &x == r.one())
+    }
+
+    fn xgcd_identity(a: i32, b: i32) -> bool {
+        let zz = IntegerRing::init();
+        let (a, b) = (a as i64, b as i64);
+        let (g, s, t) = zz.xgcd(&a, &b);
+        g >= 0 && s * a + t * b == g && zz.divides(&g, &a) && zz.divides(&g, &b)
+    }
+}
@@ -0,0 +1,165 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+
+/// Check the group axioms exhaustively and return `true` if the group is
+/// commutative.
+fn check_group<G>(g: &G) -> bool
+where
+    G: Finite + AbstractGroup<Multiplicative>,
+    <G as Parent>::Element: Eq + std::hash::Hash,
+{
+    let elems = g.elements();
+    assert_eq!(elems.len(), g.cardinality());
+    assert_eq!(elems.iter().collect::<HashSet<_>>().len(), elems.len());
+    assert_eq!(elems.iter().filter(|x| x.is_identity()).count(), 1);
+    for a in elems.iter() {
+        assert!(g.prop_operating_identity_element_is_noop((a.clone(),)));
+        assert!(a.operate(&a.two_sided_inverse()).is_identity());
+        for b in elems.iter() {
+            assert!(elems.contains(&a.operate(b)));
+            assert!(G::prop_inv_is_latin_square((a.clone(), b.clone())));
+            for c in elems.iter() {
+                assert!(G::prop_is_associative((a.clone(), b.clone(), c.clone())));
+            }
+        }
+    }
+    elems.iter().all(|a| elems.iter().all(|b| a.operate(b) == b.operate(a)))
+}
+
+#[test]
+fn cyclic_and_klein() {
+    for n in 1..=12 {
+        let c = CyclicGroup::init(n);
+        assert!(c.is_abstract_group_abelian(Multiplicative));
+        assert!(check_group(&c));
+        let g = c.gen();
+        assert_eq!(g.order(), n);
+        for x in c.elements() {
+            let order = (1..=n).find(|&k| c.power(Multiplicative, &x, k).is_identity());
+            assert_eq!(Some(x.order()), order);
+            assert_eq!(c.power(Multiplicative, &g, x.exponent()), x);
+        }
+    }
+    let c6 = CyclicGroup::init(6);
+    assert_eq!(c6.new(-1), c6.new(5));
+    assert_eq!(c6.new(4).order(), 3);
+    assert_eq!(format!("{}", c6.new(0)), "1");
+    assert_eq!(format!("{}", c6.new(7)), "g");
+    assert_eq!(format!("{}", c6.new(4)), "g^4");
+
+    let v = KleinFourGroup::init();
+    assert!(v.is_abstract_group_abelian(Multiplicative));
+    assert!(check_group(&v));
+    let (a, b) = (v.new((1, 0)), v.new((0, 1)));
+    assert_eq!(v.gens(), vec![a, b]);
+    assert_eq!(format!("{}", a.operate(&b)), "a*b");
+    assert!(v.elements().iter().all(|x| x.operate(x).is_identity()));
+    assert_eq!(v.elements().iter().filter(|x| x.order() == 2).count(), 3);
+}
+
+#[test]
+fn dihedral_groups() {
+    for n in 1..=8 {
+        let d = DihedralGroup::init(n);
+        assert!(d.is_abstract_group(Multiplicative));
+        assert_eq!(check_group(&d), n <= 2);
+        assert_eq!(d.cardinality() as u64, d.order());
+        // Check the action on the vertices of the polygon is faithful and
+        // compatible with the product.
+        let elems = d.elements();
+        let images: HashSet<Vec<u64>> = elems.iter()
+            .map(|x| (0..n).map(|i| x.apply(i)).collect())
+            .collect();
+        assert_eq!(images.len() as u64, if n <= 2 { n } else { 2 * n });
+        for x in elems.iter() {
+            for y in elems.iter() {
+                for i in 0..n {
+                    assert_eq!(x.operate(y).apply(i), x.apply(y.apply(i)));
+                }
+            }
+        }
+    }
+
+    let d4 = DihedralGroup::init(4);
+    let (r, s) = (d4.rotation(), d4.reflection());
+    assert_eq!(d4.order(), 8);
+    assert_eq!(r.order(), 4);
+    assert_eq!(s.order(), 2);
+    assert_eq!(s.operate(&r), r.two_sided_inverse().operate(&s));
+    assert!(d4.power(Multiplicative, &r.operate(&s), 2).is_identity());
+    assert_eq!(format!("{}", d4.new((3, true))), "r^3*s");
+    assert_eq!(format!("{}", s), "s");
+    assert_eq!(format!("{}", d4.identity()), "1");
+    assert_eq!(d4.elements().iter().filter(|x| x.order() == 2).count(), 5);
+}
+
+#[test]
+fn quaternion_group() {
+    let q8 = QuaternionGroup::init();
+    assert!(q8.is_abstract_group(Multiplicative));
+    assert!(!check_group(&q8));
+    let (i, j, k) = (q8.new("i"), q8.new("j"), q8.new("k"));
+    let m = q8.new("-1");
+    assert_eq!(i.operate(&i), m);
+    assert_eq!(j.operate(&j), m);
+    assert_eq!(k.operate(&k), m);
+    assert_eq!(i.operate(&j).operate(&k), m);
+    assert_eq!(i.operate(&j), k);
+    assert_eq!(j.operate(&i), q8.new("-k"));
+    assert_eq!(k.operate(&i), j);
+    assert_eq!(i.two_sided_inverse(), q8.new("-i"));
+    assert_eq!(format!("{}", j.operate(&i)), "-k");
+
+    // Q8 has a unique element of order two, unlike D4.
+    let orders: Vec<u64> = q8.elements().iter().map(|x| x.order()).collect();
+    assert_eq!(orders.iter().filter(|&&o| o == 2).count(), 1);
+    assert_eq!(orders.iter().filter(|&&o| o == 4).count(), 6);
+    for x in q8.elements() {
+        assert!(q8.power(Multiplicative, &x, x.order()).is_identity());
+    }
+}
+
+#[test]
+fn alternating_groups() {
+    for (n, order) in [(0, 1), (1, 1), (2, 1), (3, 3), (4, 12), (5, 60)] {
+        let a = AlternatingGroup::init(n);
+        assert_eq!(a.order(), order);
+        assert_eq!(a.cardinality() as u128, order);
+        assert!(a.elements().iter().all(|x| x.permutation().is_even()));
+        let gens: Vec<Permutation> = a.gens().iter().map(|x| x.permutation().clone()).collect();
+        assert_eq!(PermutationGroup::init(&a.ambient(), &gens).order(), order);
+    }
+    let a4 = AlternatingGroup::init(4);
+    assert!(a4.is_abstract_group(Multiplicative));
+    assert!(!check_group(&a4));
+    let mut counts = [0; 4];
+    for x in a4.elements() {
+        counts[x.order() as usize - 1] += 1;
+    }
+    assert_eq!(counts, [1, 3, 8, 0]);
+
+    let x = a4.new("(0,1)(2,3)");
+    assert_eq!(format!("{}", x), "(0,1)(2,3)");
+    assert_eq!(format!("{:?}", a4.new("(0,1,2)")), "EvenPermutation((0,1,2))");
+    assert_eq!(x.apply(2), 3);
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let a5 = AlternatingGroup::init(5);
+    for _ in 0..20 {
+        assert!(a5.random_element(&mut rng).permutation().is_even());
+    }
+
+    // The symmetric group is enumerable through the same trait.
+    assert!(!check_group(&SymmetricGroup::init(3)));
+    assert_eq!(Finite::cardinality(&SymmetricGroup::init(4)), 24);
+}
+
+#[test]
+#[should_panic]
+fn odd_permutation() {
+    AlternatingGroup::init(4).new("(0,1)");
+}
@@ -0,0 +1,79 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+#[macro_use]
+extern crate quickcheck;
+
+fn zx() -> SparsePolyRing<IntegerRing> {
+    SparsePolyRing::init(&IntegerRing::init(), "x")
+}
+
+#[test]
+fn huge_degree() {
+    let r = zx();
+    let x = r.gen();
+    let e = 1u64 << 61;
+    let f = &(&r.monomial(1, e) + &x) + &r.one();
+    assert!(r.is_ring());
+    assert_eq!(f.num_terms(), 3);
+    assert_eq!(f.udegree(), Some(e));
+    assert_eq!(f.degree(), e as i64);
+    assert_eq!(f.get_coeff(e as usize), 1);
+    assert_eq!(f.get_coeff(2), 0);
+
+    // (x^e + x + 1)^2 = x^(2e) + 2x^(e+1) + 2x^e + x^2 + 2x + 1
+    let g = (&f).pow(2);
+    assert_eq!(g, r.new(vec![(2 * e, 1), (e + 1, 2), (e, 2), (2, 1), (1, 2), (0, 1)]));
+
+    // 3 has order 6 mod 7 and 2^61 = 2 mod 6, so 3^(2^61) = 9 = 2 mod 7.
+    assert_eq!(f.evaluate_mod(&3, &7), 6);
+    assert_eq!(format!("{}", f), format!("1*x^{} + 1*x + 1", e));
+}
+
+#[test]
+fn cancellation_in_products() {
+    let r = zx();
+    let x = r.gen();
+    let f = &x - &r.one();
+    let g = r.new(vec![(0, 1), (1, 1), (2, 1), (3, 1)]);
+    assert_eq!(&f * &g, r.new(vec![(4, 1), (0, -1)]));
+    assert!((&f * &r.zero()).is_zero());
+}
+
+#[test]
+fn dense_conversion() {
+    let zz = IntegerRing::init();
+    let dense = GenericPolyRing::init(&zz, "x");
+    let r = zx();
+
+    let f = dense.new(vec![1, 0, 0, -4, 0, 7]);
+    let s = r.new(&f);
+    assert_eq!(s.terms(), &[(0, 1), (3, -4), (5, 7)]);
+    assert_eq!(s.to_dense(&dense), f);
+    assert_eq!(s.get_coeffs(), f.get_coeffs());
+    assert_eq!(s.evaluate(&2), f.evaluate(&2));
+}
+
+quickcheck! {
+    fn multiplication_agrees_with_dense(a: Vec<(u8, i8)>, b: Vec<(u8, i8)>) -> bool {
+        let zz = IntegerRing::init();
+        let dense = GenericPolyRing::init(&zz, "x");
+        let r = zx();
+        let f = r.new(a.into_iter().map(|(e, c)| (u64::from(e), i64::from(c))).collect::<Vec<_>>());
+        let g = r.new(b.into_iter().map(|(e, c)| (u64::from(e), i64::from(c))).collect::<Vec<_>>());
+        let h = &f * &g;
+        h.to_dense(&dense) == &f.to_dense(&dense) * &g.to_dense(&dense)
+            && (&f + &g).to_dense(&dense) == &f.to_dense(&dense) + &g.to_dense(&dense)
+    }
+
+    fn pow_agrees_with_repeated_multiplication(a: Vec<(u8, i8)>, n: u8) -> bool {
+        let r = zx();
+        let f = r.new(a.into_iter().take(4).map(|(e, c)| (u64::from(e), i64::from(c) % 3)).collect::<Vec<_>>());
+        let n = u64::from(n % 5);
+        let mut g = r.one();
+        for _ in 0..n {
+            g *= &f;
+        }
+        (&f).pow(n) == g
+    }
+}
@@ -0,0 +1,145 @@
+use inertia_algebra::*;
+use inertia_algebra::ops::*;
+
+use std::collections::HashSet;
+
+#[test]
+fn subgroups_and_cosets() {
+    let s3 = SymmetricGroup::init(3);
+    let h = Subgroup::init(Multiplicative, &s3, &[s3.new("(0,1)")]);
+    assert_eq!(h.order(), 2);
+    assert_eq!(h.index(), 3);
+    assert!(h.contains(&s3.identity()));
+    assert!(!h.contains(&s3.new("(0,1,2)")));
+    assert!(!h.is_normal());
+    assert!(h.is_abelian());
+
+    let left = h.left_cosets();
+    let right = h.right_cosets();
+    assert_eq!(left.len(), 3);
+    assert_eq!(right.len(), 3);
+    assert_eq!(left[0], h.elements());
+    let as_sets = |cs: &[Vec<Permutation>]| -> HashSet<Vec<Permutation>> {
+        cs.iter().map(|c| { let mut c = c.clone(); c.sort(); c }).collect()
+    };
+    assert_ne!(as_sets(&left), as_sets(&right));
+    for c in left.iter() {
+        assert!(c.iter().all(|x| h.same_left_coset(&c[0], x)));
+    }
+    for c in right.iter() {
+        assert!(c.iter().all(|x| h.same_right_coset(&c[0], x)));
+    }
+    let all: HashSet<Permutation> = left.iter().flatten().cloned().collect();
+    assert_eq!(all.len(), 6);
+    assert_eq!(h.left_transversal()[0], s3.identity());
+
+    let x = s3.new("(0,2)");
+    let k = h.conjugate(&x);
+    assert!(k.contains(&s3.new("(1,2)")));
+    assert_eq!(h.normal_closure(), Subgroup::whole(Multiplicative, &s3));
+
+    let a3 = Subgroup::init(Multiplicative, &s3, &[s3.new("(0,1,2)")]);
+    assert!(a3.is_normal());
+    assert!(Subgroup::trivial(Multiplicative, &s3).is_subgroup_of(&a3));
+    assert!(!h.is_subgroup_of(&a3));
+}
+
+#[test]
+fn normal_subgroups_of_s4() {
+    let s4 = SymmetricGroup::init(4);
+    let v4 = Subgroup::init(Multiplicative, &s4, &[s4.new("(0,1)(2,3)"), s4.new("(0,2)(1,3)")]);
+    let a4 = Subgroup::init(Multiplicative, &s4, &[s4.new("(0,1,2)"), s4.new("(1,2,3)")]);
+    let c3 = Subgroup::init(Multiplicative, &s4, &[s4.new("(0,1,2)")]);
+    assert_eq!(v4.order(), 4);
+    assert_eq!(a4.order(), 12);
+    assert!(v4.is_normal() && a4.is_normal());
+    assert!(!c3.is_normal());
+    assert_eq!(c3.normal_closure(), a4);
+    assert!(v4.is_subgroup_of(&a4));
+    for h in [&v4, &a4, &c3] {
+        assert_eq!(h.order() * h.index(), 24);
+    }
+
+    let q = v4.quotient();
+    assert!(q.is_abstract_group(Multiplicative));
+    assert_eq!(q.order(), 6);
+    assert_eq!(q.cardinality(), 6);
+    check_quotient(&q);
+    let orders: Vec<usize> = q.elements().iter().map(|x| {
+        (1..=6).find(|&k| q.power(Multiplicative, x, k).is_identity()).unwrap() as usize
+    }).collect();
+    assert_eq!(orders.iter().filter(|&&o| o == 2).count(), 3);
+    assert_eq!(orders.iter().filter(|&&o| o == 3).count(), 2);
+
+    let sign = a4.quotient();
+    assert_eq!(sign.order(), 2);
+    check_quotient(&sign);
+    assert!(sign.new(s4.new("(0,1,2,3)")) != sign.identity());
+    assert!(sign.new(s4.new("(0,1)(2,3)")).is_identity());
+    assert_eq!(format!("{}", sign.identity()), "[()]");
+    let t = sign.project(&s4.new("(0,1)"));
+    assert!(t.contains(&s4.new("(2,3)")));
+    assert!(t.elements().iter().all(|x| !x.is_even()));
+    assert_eq!(t.lift().sign(), -1);
+}
+
+#[test]
+fn quotients_of_small_groups() {
+    let d4 = DihedralGroup::init(4);
+    let r = d4.rotation();
+    let center = Subgroup::init(Multiplicative, &d4, &[d4.power(Multiplicative, &r, 2)]);
+    assert!(center.is_normal());
+    let q = center.quotient();
+    assert_eq!(q.order(), 4);
+    check_quotient(&q);
+    assert!(q.elements().iter().all(|x| x.operate(x).is_identity()));
+
+    let c12 = CyclicGroup::init(12);
+    let n = Subgroup::init(Multiplicative, &c12, &[c12.new(4)]);
+    let q = n.quotient();
+    assert!(q.is_abstract_group_abelian(Multiplicative));
+    assert_eq!(q.order(), 4);
+    assert_eq!(q.project(&c12.new(5)), q.project(&c12.new(1)));
+    assert_eq!(q.representatives(), &[c12.new(0), c12.new(1), c12.new(2), c12.new(3)]);
+    check_quotient(&q);
+
+    let q8 = QuaternionGroup::init();
+    let z = Subgroup::init(Multiplicative, &q8, &[q8.new("-1")]);
+    let i = Subgroup::init(Multiplicative, &q8, &[q8.new("i")]);
+    assert!(z.is_normal() && i.is_normal());
+    let q = z.quotient();
+    check_quotient(&q);
+    assert!(q.elements().iter().all(|x| x.operate(x).is_identity()));
+    assert_eq!(Subgroup::whole(Multiplicative, &q8).quotient().order(), 1);
+}
+
+#[test]
+#[should_panic]
+fn quotient_by_non_normal() {
+    let s3 = SymmetricGroup::init(3);
+    Subgroup::init(Multiplicative, &s3, &[s3.new("(0,1)")]).quotient();
+}
+
+fn check_quotient<G>(q: &QuotientGroup<G, Multiplicative>)
+where
+    G: AbstractGroup<Multiplicative> + Finite,
+    <G as Parent>::Element: Eq + std::hash::Hash,
+{
+    let elems = q.elements();
+    for a in elems.iter() {
+        assert!(q.prop_operating_identity_element_is_noop((a.clone(),)));
+        for b in elems.iter() {
+            assert!(QuotientGroup::prop_inv_is_latin_square((a.clone(), b.clone())));
+            for c in elems.iter() {
+                assert!(QuotientGroup::prop_is_associative((a.clone(), b.clone(), c.clone())));
+            }
+        }
+    }
+    // The projection is a homomorphism.
+    let g = q.group().elements();
+    for x in g.iter() {
+        for y in g.iter() {
+            assert!(q.project(&x.operate(y)) == q.project(x).operate(&q.project(y)));
+        }
+    }
+}